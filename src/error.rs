@@ -0,0 +1,112 @@
+use std::fmt;
+
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_request::RpcError,
+};
+
+use crate::ore_utils::AccountDecodeError;
+
+/// Errors returned by tasks, grouped by what the app can do about them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppError {
+    /// The rpc couldn't be reached or the request timed out.
+    Network(String),
+    /// The rpc rejected the request for sending too many.
+    RateLimited(String),
+    /// The rpc answered with an error.
+    Rpc(String),
+    Decode(AccountDecodeError),
+    /// No wallet, not enough sol or a signing failure.
+    Wallet(String),
+    /// The transaction was executed and failed.
+    Program(String),
+    UserInput(String),
+}
+
+impl AppError {
+    /// Whether trying the same thing again later can succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            AppError::Network(_) | AppError::RateLimited(_) | AppError::Rpc(_) => true,
+            AppError::Decode(e) => matches!(e, AccountDecodeError::Rpc { .. }),
+            AppError::Wallet(_) | AppError::Program(_) | AppError::UserInput(_) => false,
+        }
+    }
+
+    /// Whether the endpoint itself failed, another rpc may answer instead.
+    pub fn is_endpoint_failure(&self) -> bool {
+        matches!(self, AppError::Network(_) | AppError::RateLimited(_))
+    }
+
+    pub fn class(&self) -> &'static str {
+        match self {
+            AppError::Network(_) => "Network",
+            AppError::RateLimited(_) => "Rate Limited",
+            AppError::Rpc(_) => "RPC",
+            AppError::Decode(_) => "Decode",
+            AppError::Wallet(_) => "Wallet",
+            AppError::Program(_) => "Program",
+            AppError::UserInput(_) => "Input",
+        }
+    }
+
+    /// Short text for the ui, the class plus a hint when retrying helps.
+    pub fn ui_message(&self) -> String {
+        if self.is_retryable() {
+            format!("{} error: {}. Please retry.", self.class(), self)
+        } else {
+            format!("{} error: {}", self.class(), self)
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Network(e)
+            | AppError::RateLimited(e)
+            | AppError::Rpc(e)
+            | AppError::Wallet(e)
+            | AppError::Program(e)
+            | AppError::UserInput(e) => write!(f, "{}", e),
+            AppError::Decode(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<AccountDecodeError> for AppError {
+    fn from(e: AccountDecodeError) -> Self {
+        AppError::Decode(e)
+    }
+}
+
+impl From<ClientError> for AppError {
+    fn from(e: ClientError) -> Self {
+        let message = e.to_string();
+        match e.kind() {
+            ClientErrorKind::Io(_) => AppError::Network(message),
+            ClientErrorKind::Reqwest(err) => {
+                if err.status().map(|status| status.as_u16()) == Some(429) {
+                    AppError::RateLimited(message)
+                } else if err.is_timeout() || err.is_connect() || err.is_request() {
+                    AppError::Network(message)
+                } else {
+                    AppError::Rpc(message)
+                }
+            },
+            ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) if *code == 429 => {
+                AppError::RateLimited(message)
+            },
+            ClientErrorKind::TransactionError(_) => AppError::Program(message),
+            ClientErrorKind::SigningError(_) => AppError::Wallet(message),
+            _ => {
+                if message.contains("429") || message.to_lowercase().contains("too many requests") {
+                    AppError::RateLimited(message)
+                } else {
+                    AppError::Rpc(message)
+                }
+            },
+        }
+    }
+}
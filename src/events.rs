@@ -1,28 +1,29 @@
 use async_compat::Compat;
-use async_std::task::sleep;
+use async_std::{future::timeout, task::sleep};
 use bevy::{
     prelude::*,
-    tasks::{AsyncComputeTaskPool, IoTaskPool},
+    tasks::{futures_lite::StreamExt, AsyncComputeTaskPool, IoTaskPool},
 };
-use bip39::{Language, Mnemonic, MnemonicType, Seed};
+use bip39::{Language, Mnemonic, MnemonicType};
 use chrono::DateTime;
-use cocoon::Cocoon;
 use crossbeam_channel::{bounded, unbounded};
 use drillx::{Solution};
 use ore_api::state::Proof;
-use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
+use solana_client::{nonblocking::{pubsub_client::PubsubClient, rpc_client::RpcClient}, rpc_config::RpcSendTransactionConfig};
 use solana_transaction_status::UiTransactionEncoding;
 use spl_associated_token_account::get_associated_token_address;
+use zeroize::Zeroizing;
 
 use crate::{
+    error::AppError,
     ore_utils::{
-        find_hash_par, get_auth_ix, get_claim_ix, get_clock_account, get_cutoff, get_mine_ix, get_ore_epoch_duration, get_ore_mint, get_proof, get_proof_and_treasury_with_busses, get_register_ix, get_reset_ix, get_stake_ix, get_treasury, proof_pubkey, treasury_tokens_pubkey, ORE_TOKEN_DECIMALS
+        find_hash_par, get_auth_ix, get_claim_ix, get_clock_account, get_cutoff, get_mine_ix, get_ore_epoch_duration, get_ore_mint, get_proof, get_proof_and_treasury_with_busses, get_register_ix, get_reset_ix, get_stake_ix, get_treasury, proof_pubkey, treasury_tokens_pubkey, AccountDecodeError, ORE_TOKEN_DECIMALS
     }, tasks::{
-        SigCheckResults, TaskGenerateHash, TaskProcessTx, TaskProcessTxData, TaskRegisterWallet, TaskSigChecks, TaskUpdateAppWalletSolBalance, TaskUpdateAppWalletSolBalanceData
+        ConnectionTestResult, SavedWallet, SigCheckResults, TaskGenerateHash, TaskProcessTx, TaskProcessTxData, TaskRegisterWallet, TaskSigChecks, TaskChangePassword, TaskExportWallet, TaskRevealSeed, TaskSaveWallet, TaskTestConnection, TaskUnlockVault, TaskUpdateAppWalletSolBalance, TaskUpdateAppWalletSolBalanceData, TaskVanitySearch
     }, ui::{
-        components::{ButtonAutoScroll, DashboardProofUpdatesLogsList, DashboardProofUpdatesLogsListItem, MiningScreenTxResultList, MovingScrollPanel, ScrollingList, ScrollingListNode, TextGeneratedKeypair, TextInput, TextMnemonicLine1, TextMnemonicLine2, TextMnemonicLine3, TextPasswordInput, ToggleAutoMine, ToggleAutoMineParent},
-        spawn_utils::{spawn_new_list_item, UiListItem}, styles::{FONT_REGULAR, FONT_SIZE_MEDIUM, MINE_TOGGLE_OFF, MINE_TOGGLE_ON, TOGGLE_OFF, TOGGLE_ON},
-    }, utils::{find_best_bus, get_unix_timestamp, shorten_string}, AppConfig, AppScreenState, AppWallet, BussesResource, EntityTaskFetchUiData, EntityTaskHandler, HashStatus, HashrateResource, MinerStatusResource, MiningDataChannelMessage, MiningDataChannelResource, MiningProofsResource, NavItemScreen, OreAppState, ProofAccountResource, RpcConnection, TreasuryAccountResource, TxProcessor, TxStatus
+        components::{ButtonAutoScroll, DashboardProofUpdatesLogsList, DashboardProofUpdatesLogsListItem, GeneratedSeedPhrase, MiningScreenTxResultList, MovingScrollPanel, ScrollingList, ScrollingListNode, TextGeneratedKeypair, TextInput, TextMnemonicLine1, TextMnemonicLine2, TextMnemonicLine3, TextPasswordInput, TextSeedVerifyInput, ToastArea, ToggleAutoMine, ToggleAutoMineParent},
+        spawn_utils::{spawn_account_link_icon, spawn_new_list_item, spawn_toast, UiListItem}, styles::{FONT_REGULAR, FONT_SIZE_MEDIUM, MINE_TOGGLE_OFF, MINE_TOGGLE_ON, TOGGLE_OFF, TOGGLE_ON},
+    }, seed_phrase::{derive_keypair, normalize_phrase, parse_mnemonic, pick_verify_indices, DerivationScheme, SeedBackup}, vault::{copy_to_backup, BackupBundle, Kdf, UnlockAttempts, VaultError, WalletVault, WalletVaultResource, EXPORT_DIR, VAULT_PATH}, subscriptions::{AccountSubscriptions, WsStatusResource}, utils::{get_unix_timestamp, select_bus, shorten_string}, vanity::{find_vanity_par, VanityPattern, VanitySearchMessage, VanitySearchResource, VanitySource}, AppConfig, AppScreenState, AppWallet, AutoLockResource, BussesResource, Cluster, EntityTaskFetchUiData, EntityTaskHandler, HashStatus, HashrateResource, MinerStatusResource, MiningDataChannelMessage, MiningDataChannelResource, MiningProofsResource, NavItemScreen, OreAppState, ProofAccountResource, RpcConnection, ToastEntry, ToastHistoryResource, TreasuryAccountResource, TxProcessor, TxStatus, TOAST_HISTORY_LEN
 };
 
 use std::{
@@ -30,7 +31,7 @@ use std::{
 };
 
 use solana_sdk::{
-    bs58, commitment_config::CommitmentLevel, compute_budget::ComputeBudgetInstruction, derivation_path::DerivationPath, keccak::{hashv, Hash as KeccakHash}, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::{read_keypair_file, Keypair, Signer}, signer::SeedDerivable, transaction::Transaction
+    bs58, commitment_config::{CommitmentConfig, CommitmentLevel}, compute_budget::ComputeBudgetInstruction, keccak::{hashv, Hash as KeccakHash}, native_token::LAMPORTS_PER_SOL, program_pack::Pack, pubkey::Pubkey, signature::{read_keypair_file, write_keypair_file, Keypair, Signer}, transaction::Transaction
 };
 
 // Events
@@ -46,6 +47,25 @@ pub struct EventLoadKeypairFile(pub PathBuf);
 #[derive(Event)]
 pub struct EventSaveWallet;
 
+#[derive(Event)]
+pub struct EventImportMnemonic {
+    pub phrase: String,
+    pub passphrase: String,
+    pub scheme: DerivationScheme,
+    pub account: u32,
+}
+
+#[derive(Event)]
+pub struct EventStartVanitySearch {
+    pub prefix: String,
+    pub suffix: String,
+    pub ignore_case: bool,
+    pub source: VanitySource,
+}
+
+#[derive(Event)]
+pub struct EventStopVanitySearch;
+
 #[derive(Event)]
 pub struct EventMineForHash;
 
@@ -97,12 +117,122 @@ pub struct EventProcessTx {
 #[derive(Event)]
 pub struct EventLock;
 
+/// Track a pubkey without its keypair, the string is parsed by the handler.
+#[derive(Event)]
+pub struct EventWatchPubkey(pub String);
+
 #[derive(Event)]
 pub struct EventUnlock;
 
+#[derive(Event)]
+pub struct EventAddWallet {
+    pub name: String,
+    pub keypair: Keypair,
+    pub seed: Option<SeedBackup>,
+}
+
+#[derive(Event)]
+pub struct EventRenameWallet {
+    pub index: usize,
+    pub name: String,
+}
+
+#[derive(Event)]
+pub struct EventRemoveWallet(pub usize);
+
+#[derive(Event)]
+pub struct EventSwitchWallet(pub usize);
+
+#[derive(Event)]
+pub struct EventChangePassword {
+    pub current: String,
+    pub new: String,
+    pub confirm: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WalletExport {
+    /// The active keypair as a `solana-keygen` json file.
+    Keypair,
+    /// `save.data` and `config.toml`, see `BackupBundle`.
+    Backup,
+}
+
+#[derive(Event)]
+pub struct EventExportWallet {
+    pub export: WalletExport,
+    pub password: String,
+}
+
+#[derive(Event)]
+pub struct EventRevealSeed {
+    pub index: usize,
+    pub password: String,
+}
+
 #[derive(Event)]
 pub struct EventSaveConfig(pub AppConfig);
 
+#[derive(Event)]
+pub struct EventTestConnection {
+    pub rpc_url: String,
+    pub ws_url: String,
+    pub cluster: Cluster,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ToastSeverity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    pub fn color(&self) -> Color {
+        match self {
+            ToastSeverity::Info => Color::WHITE,
+            ToastSeverity::Success => Color::GREEN,
+            ToastSeverity::Warning => Color::ORANGE,
+            ToastSeverity::Error => Color::RED,
+        }
+    }
+
+    /// Errors stay up longer so there is time to read them.
+    pub fn duration(&self) -> Duration {
+        match self {
+            ToastSeverity::Info | ToastSeverity::Success => Duration::from_secs(4),
+            ToastSeverity::Warning => Duration::from_secs(6),
+            ToastSeverity::Error => Duration::from_secs(8),
+        }
+    }
+}
+
+/// A message for the user, shown as a toast and kept in the history drawer.
+#[derive(Event)]
+pub struct EventToast {
+    pub severity: ToastSeverity,
+    pub message: String,
+}
+
+impl EventToast {
+    pub fn info(message: impl Into<String>) -> Self {
+        Self { severity: ToastSeverity::Info, message: message.into() }
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self { severity: ToastSeverity::Success, message: message.into() }
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self { severity: ToastSeverity::Warning, message: message.into() }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self { severity: ToastSeverity::Error, message: message.into() }
+    }
+}
+
 pub fn handle_event_start_stop_mining_clicked(
     mut ev_start_stop_mining: EventReader<EventStartStopMining>,
     mut event_writer: EventWriter<EventMineForHash>,
@@ -112,7 +242,7 @@ pub fn handle_event_start_stop_mining_clicked(
     mut miner_status: ResMut<MinerStatusResource>,
     rpc_connection: Res<RpcConnection>,
     proof_account: Res<ProofAccountResource>,
-    asset_server: Res<AssetServer>,
+    asset_server: Option<Res<AssetServer>>,
     mut query: Query<(&mut UiImage, &mut ToggleAutoMine)>,
 ) {
     for _ev in ev_start_stop_mining.read() {
@@ -121,21 +251,29 @@ pub fn handle_event_start_stop_mining_clicked(
             "PROCESSING" => {
                 // stop mining
                 miner_status.miner_status = "STOPPED".to_string();
-                let (mut btn, mut toggle) = query.single_mut();
-                toggle.0 = false;
-                *btn = UiImage::new(asset_server.load(MINE_TOGGLE_OFF));
+                if let Ok((mut btn, mut toggle)) = query.get_single_mut() {
+                    toggle.0 = false;
+                    if let Some(asset_server) = &asset_server {
+                        *btn = UiImage::new(asset_server.load(MINE_TOGGLE_OFF));
+                    }
+                }
                 event_writer_cancel_mining.send(EventCancelMining);
             
             },
             "STOPPED" => {
                 // start mining
-                if proof_account.challenge == "Not Found" {
+                if proof_account.challenge == "unavailable" {
+                    error!("Proof account is unavailable, not starting the miner");
+                } else if proof_account.challenge == "Not Found" {
                     event_writer_register.send(EventRegisterWallet);
                 } else {
                     event_writer.send(EventMineForHash);
-                    let (mut btn, mut toggle) = query.single_mut();
-                    toggle.0 = true;
-                    *btn = UiImage::new(asset_server.load(MINE_TOGGLE_ON));
+                    if let Ok((mut btn, mut toggle)) = query.get_single_mut() {
+                        toggle.0 = true;
+                        if let Some(asset_server) = &asset_server {
+                            *btn = UiImage::new(asset_server.load(MINE_TOGGLE_ON));
+                        }
+                    }
                 }
             },
             _ => {
@@ -199,10 +337,9 @@ pub fn handle_event_mine_for_hash(
             let task = pool.spawn(Compat::new(async move {
                 // TODO: use proof resource cached proof. May need LatestHash Resource to ensure a new proof if loaded before mining.
                 //  get proof account data
-                let proof = if let Ok(result) = get_proof(&client, wallet.pubkey()).await {
-                    result
-                } else {
-                    return Err("Failed to get proof account. Please Retry.".to_string());
+                let proof = match get_proof(client.as_ref(), wallet.pubkey()).await {
+                    Ok(proof) => proof,
+                    Err(e) => return Err(AppError::from(e)),
                 };
 
                 // ensure proof account is hash is not the same as the last generated one.
@@ -259,6 +396,7 @@ pub fn handle_event_submit_hash_tx(
     mut busses_res: ResMut<BussesResource>,
     mut next_state: ResMut<NextState<AppScreenState>>,
     mut hashrate_res: ResMut<HashrateResource>,
+    app_state: Res<OreAppState>,
 ) {
     for ev in ev_submit_hash_tx.read() {
         let wallet = if let Some(wallet) =  &app_wallet.wallet {
@@ -277,9 +415,19 @@ pub fn handle_event_submit_hash_tx(
                 continue;
             };
 
-            let bus = find_best_bus(&busses_res.busses);
+            // a bus below the base reward rate can't pay out a full hash
+            let min_rewards = (treasury.base_reward_rate * 10f64.powf(ORE_TOKEN_DECIMALS as f64)) as u64;
+            let bus = select_bus(
+                app_state.config.bus_selector,
+                &busses_res.busses,
+                busses_res.current_bus_id,
+                &busses_res.last_hit_at,
+                min_rewards,
+            );
 
             busses_res.current_bus_id = bus;
+            // busses that failed to decode are skipped, so the index isn't always the bus id
+            let bus = busses_res.busses.get(bus).map(|b| b.id as usize).unwrap_or(bus);
 
             let solution;
             let difficulty;
@@ -330,28 +478,50 @@ pub fn handle_event_submit_hash_tx(
                 
                 // let cu_price_ix =
                 //     ComputeBudgetInstruction::set_compute_unit_price(self.priority_fee);
-                let ix_mine = get_mine_ix(signer.pubkey(), solution, bus);
-                ixs.push(ix_mine);
-
-                let mut attempts = 3;
-                while attempts > 0 {
-                    if let Ok((hash, _slot)) = client.get_latest_blockhash_with_commitment(client.commitment()).await {
-                        let mut tx = Transaction::new_with_payer(&ixs, Some(&signer.pubkey()));
-
-                        tx.sign(&[&signer], hash);
-                        
+                let ix_mine = match get_mine_ix(signer.pubkey(), solution, bus) {
+                    Ok(ix_mine) => ix_mine,
+                    Err(e) => {
+                        error!("Failed to build mine instruction: {}", e);
                         let process_data = TaskProcessTxData {
                             tx_type: "Mine".to_string(),
                             signature: None,
-                            signed_tx: Some(tx),
+                            signed_tx: None,
                             hash_time: Some((hash_time, difficulty)),
                         };
+                        return Err((process_data, e));
+                    },
+                };
+                ixs.push(ix_mine);
 
-                        return Ok(process_data);
-                    } else {
-                        error!("Failed to get latest blockhash. retrying...");
-                        sleep(Duration::from_millis(1000)).await;
-                        attempts = attempts - 1;
+                let mut attempts = 3;
+                let mut last_error = AppError::Rpc("Failed to get latest blockhash".to_string());
+                while attempts > 0 {
+                    match client.get_latest_blockhash().await {
+                        Ok(hash) => {
+                            let mut tx = Transaction::new_with_payer(&ixs, Some(&signer.pubkey()));
+
+                            tx.sign(&[&signer], hash);
+
+                            let process_data = TaskProcessTxData {
+                                tx_type: "Mine".to_string(),
+                                signature: None,
+                                signed_tx: Some(tx),
+                                hash_time: Some((hash_time, difficulty)),
+                            };
+
+                            return Ok(process_data);
+                        },
+                        Err(e) => {
+                            error!("Failed to get latest blockhash: {}", e);
+                            if !e.is_retryable() {
+                                last_error = e;
+                                break;
+                            }
+                            let backoff = if let AppError::RateLimited(_) = e { 3000 } else { 1000 };
+                            last_error = e;
+                            sleep(Duration::from_millis(backoff)).await;
+                            attempts = attempts - 1;
+                        },
                     }
                 }
 
@@ -363,7 +533,7 @@ pub fn handle_event_submit_hash_tx(
                 };
                 return Err((
                     process_data,
-                    "Failed to get latest blockhash".to_string()
+                    last_error
                 ));
 
             }));
@@ -382,7 +552,7 @@ pub fn handle_event_tx_result(
     mut commands: Commands,
     mut ev_tx_result: EventReader<EventTxResult>,
     mut event_writer: EventWriter<EventMineForHash>,
-    asset_server: Res<AssetServer>,
+    asset_server: Option<Res<AssetServer>>,
     mut query: Query<(Entity, &mut ScrollingList, &mut Style, &Parent, &Node), With<MiningScreenTxResultList>>,
     query_node: Query<&Node>,
     query_auto_scroll: Query<&ButtonAutoScroll>,
@@ -395,7 +565,8 @@ pub fn handle_event_tx_result(
         } else {
             ("N/A".to_string(), "".to_string())
         };
-        if let Ok((scroll_panel_entity, mut scrolling_list, mut style, parent, list_node)) = query.get_single_mut() {
+        // the result list is only there with the mining screen spawned
+        if let (Some(asset_server), Ok((scroll_panel_entity, mut scrolling_list, mut style, parent, list_node))) = (&asset_server, query.get_single_mut()) {
             let status = format!(
                 "{}  {}",
                 ev.tx_status.status.clone(),
@@ -419,13 +590,13 @@ pub fn handle_event_tx_result(
                 status,
             };
             let use_light_background = local.clone();
-            spawn_new_list_item(&mut commands, &asset_server, scroll_panel_entity, item_data, use_light_background);
+            spawn_new_list_item(&mut commands, asset_server, scroll_panel_entity, item_data, use_light_background);
 
             *local = !*local;
 
-            let auto_scroll = query_auto_scroll.single();
+            let auto_scroll = query_auto_scroll.get_single().map(|auto_scroll| auto_scroll.0).unwrap_or(false);
 
-            if auto_scroll.0 {
+            if auto_scroll {
                 let items_height = list_node.size().y + 20.0;
                 if let Ok(query_node_parent) = query_node.get(parent.get()) {
                     let container_height = query_node_parent.size().y;
@@ -440,9 +611,8 @@ pub fn handle_event_tx_result(
             }
         }
 
-        let toggle = query_toggle.single();
-        if toggle.0 {
-            if ev.tx_type == "Mine" {
+        if let Ok(toggle) = query_toggle.get_single() {
+            if toggle.0 && ev.tx_type == "Mine" {
                 event_writer.send(EventMineForHash);
             }
         }
@@ -458,15 +628,14 @@ pub fn handle_event_fetch_ui_data_from_rpc(
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
     for _ev in event_reader.read() {
-        let wallet = if let Some(wallet) =  &app_wallet.wallet {
-            wallet.clone()
+        let pubkey = if let Some(pubkey) = app_wallet.pubkey() {
+            pubkey
         } else {
             next_state.set(AppScreenState::Unlock);
             error!("wallet is None, switching to wallet unlock screen");
             continue;
         }; 
         if let Ok(task_handler_entity) = query_task_handler.get_single() {
-            let pubkey = wallet.pubkey();
 
             let pool = IoTaskPool::get();
             let connection = if let Some(rpc) = &rpc_connection.rpc {
@@ -477,11 +646,7 @@ pub fn handle_event_fetch_ui_data_from_rpc(
             };
             let ore_mint = get_ore_mint();
             let task = pool.spawn(Compat::new(async move {
-                let balance = if let Ok(result) = connection.get_balance(&pubkey).await {
-                    result
-                } else {
-                    return Err("Failed to get balance. Please retry.".to_string());
-                };
+                let balance = connection.get_balance(&pubkey).await?;
                 let sol_balance = balance as f64 / LAMPORTS_PER_SOL as f64;
                 let token_account = get_associated_token_address(&pubkey, &ore_mint);
 
@@ -497,46 +662,45 @@ pub fn handle_event_fetch_ui_data_from_rpc(
                     };
 
                 // TODO: condense as many solana accounts into one rpc get_multiple_accounts call as possible
-                let (proof_account, treasury_account, treasury_config, busses) = get_proof_and_treasury_with_busses(&connection, pubkey).await;
+                let ore_accounts = get_proof_and_treasury_with_busses(connection.as_ref(), pubkey).await;
 
-                let proof_account_res_data;
-                if let Ok(proof_account) = proof_account {
-                    proof_account_res_data = ProofAccountResource {
+                let proof_account_res_data = match ore_accounts.proof {
+                    Ok(proof_account) => ProofAccountResource {
                         challenge: KeccakHash::new_from_array(proof_account.challenge).to_string(),
                         stake: proof_account.balance,
                         last_hash_at: proof_account.last_hash_at,
                         total_hashes: proof_account.total_hashes,
-                    };
-                } else {
-                    proof_account_res_data = ProofAccountResource {
-                        challenge: "Not Found".to_string(),
-                        stake: 0,
-                        last_hash_at: 0,
-                        total_hashes: 0,
-                    };
-                }
-
-                let treasury_ore_balance = if let Ok(token_balance) = connection.get_token_account_balance(&treasury_tokens_pubkey()).await {
-                    if let Some(ui_amount) = token_balance.ui_amount {
-                        ui_amount
-                    } else {
-                        return Err("Failed to get ui_amount from token_account. Fetch Ui Data.".to_string());
-                    }
+                    },
+                    Err(e) => {
+                        // only a missing proof means the wallet needs to register
+                        let challenge = if e.is_not_found() {
+                            "Not Found".to_string()
+                        } else {
+                            error!("{}", e);
+                            "unavailable".to_string()
+                        };
+                        ProofAccountResource {
+                            challenge,
+                            stake: 0,
+                            last_hash_at: 0,
+                            total_hashes: 0,
+                        }
+                    },
+                };
 
+                let token_balance = connection.get_token_account_balance(&treasury_tokens_pubkey()).await?;
+                let treasury_ore_balance = if let Some(ui_amount) = token_balance.ui_amount {
+                    ui_amount
                 } else {
-                    return Err("Failed to get token account balance. Fetch Ui Data.".to_string());
+                    return Err(AppError::Rpc("Failed to get ui_amount from treasury token account".to_string()));
                 };
 
                 let treasury_account_res_data;
-                if let Ok(treasury_account) = treasury_config {
+                if let Ok(treasury_account) = &ore_accounts.config {
                     let base_reward_rate =
                         (treasury_account.base_reward_rate as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
 
-                    let clock = if let Ok(clock) =  get_clock_account(&connection).await {
-                        clock
-                    } else {
-                        return Err("Failed to get clock account. fetch ui data.".to_string());
-                    };
+                    let clock = get_clock_account(connection.as_ref()).await?;
                     let threshold = treasury_account
                         .last_reset_at
                         .saturating_add(get_ore_epoch_duration());
@@ -556,25 +720,30 @@ pub fn handle_event_fetch_ui_data_from_rpc(
                     };
                 } else {
                     treasury_account_res_data = TreasuryAccountResource {
-                        balance: "Not Found".to_string(),
+                        balance: "unavailable".to_string(),
                         last_reset_at: 0,
                         need_epoch_reset: false,
                         base_reward_rate: 0.0,
                         min_difficulty: 0,
                     };
                 }
+                if let Err(e) = &ore_accounts.config {
+                    error!("{}", e);
+                }
+                if let Err(e) = &ore_accounts.treasury {
+                    error!("{}", e);
+                }
+
                 let mut busses_res_data = vec![];
-                if let Ok(busses) = busses {
-                    for bus in busses {
-                        if let Ok(bus) = bus {
-                            busses_res_data.push(bus);
-                        } else {
-                            error!("Got error result for bus.");
-                        }
+                for bus in ore_accounts.busses {
+                    match bus {
+                        Ok(bus) => busses_res_data.push(bus),
+                        Err(e) => error!("{}", e),
                     }
                 }
 
                 Ok(TaskUpdateAppWalletSolBalanceData {
+                    pubkey,
                     sol_balance,
                     ore_balance,
                     proof_account_data: proof_account_res_data,
@@ -618,11 +787,26 @@ pub fn handle_event_register_wallet(
                 continue;
             };
             let task = pool.spawn(Compat::new(async move {
-                let proof = get_proof(&client, wallet.pubkey()).await;
+                let proof = get_proof(client.as_ref(), wallet.pubkey()).await;
 
                 // TODO: Register is first button that pops up. Disappears when Proof Account resource has valid data.
                 // try to load proof account before showing the mining screen?
 
+                if let Err(e) = &proof {
+                    if !e.is_not_found() {
+                        let process_data = TaskProcessTxData {
+                            tx_type: "Register".to_string(),
+                            signature: None,
+                            signed_tx: None,
+                            hash_time: None,
+                        };
+                        return Err((
+                            process_data,
+                            AppError::from(e.clone())
+                        ));
+                    }
+                }
+
                 if let Ok(_) = proof {
                     let process_data = TaskProcessTxData {
                         tx_type: "Register".to_string(),
@@ -632,24 +816,25 @@ pub fn handle_event_register_wallet(
                     };
                     return Err((
                         process_data,
-                        "Account is already registered".to_string()
+                        AppError::UserInput("Account is already registered".to_string())
                     ));
                 } else {
                     let signer = wallet;
 
-                    let balance = if let Ok(balance) = client.get_balance(&signer.pubkey()).await {
-                        balance
-                    } else {
-                        let process_data = TaskProcessTxData {
-                            tx_type: "Register".to_string(),
-                            signature: None,
-                            signed_tx: None,
-                            hash_time: None,
-                        };
-                        return Err((
-                            process_data,
-                            "Failed to get sol balance.".to_string()
-                        ));
+                    let balance = match client.get_balance(&signer.pubkey()).await {
+                        Ok(balance) => balance,
+                        Err(e) => {
+                            let process_data = TaskProcessTxData {
+                                tx_type: "Register".to_string(),
+                                signature: None,
+                                signed_tx: None,
+                                hash_time: None,
+                            };
+                            return Err((
+                                process_data,
+                                e
+                            ));
+                        },
                     };
 
                     if balance <= 0 {
@@ -662,39 +847,41 @@ pub fn handle_event_register_wallet(
                         };
                         return Err((
                             process_data,
-                            "Insufficient sol balance".to_string()
+                            AppError::Wallet("Insufficient sol balance".to_string())
                         ));
                     }
 
                     let ix = get_register_ix(signer.pubkey());
-                    let latest_blockhash = client
-                        .get_latest_blockhash_with_commitment(client.commitment()).await;
+                    let latest_blockhash = client.get_latest_blockhash().await;
 
-                    if let Ok((hash, _slot)) = latest_blockhash {
-                        let mut tx = Transaction::new_with_payer(&[ix], Some(&signer.pubkey()));
+                    match latest_blockhash {
+                        Ok(hash) => {
+                            let mut tx = Transaction::new_with_payer(&[ix], Some(&signer.pubkey()));
 
-                        tx.sign(&[&signer], hash);
+                            tx.sign(&[&signer], hash);
 
-                        let process_data = TaskProcessTxData {
-                            tx_type: "Register".to_string(),
-                            signature: None,
-                            signed_tx: Some(tx),
-                            hash_time: None,
-                        };
+                            let process_data = TaskProcessTxData {
+                                tx_type: "Register".to_string(),
+                                signature: None,
+                                signed_tx: Some(tx),
+                                hash_time: None,
+                            };
 
-                        return Ok(process_data);
-                    } else {
-                        error!("Failed to get latest blockhash. handle_event_submit_hash_tx");
-                        let process_data = TaskProcessTxData {
-                            tx_type: "Register".to_string(),
-                            signature: None,
-                            signed_tx: None,
-                            hash_time: None,
-                        };
-                        return Err((
-                            process_data,
-                            "Failed to get latest blockhash".to_string()
-                        ));
+                            return Ok(process_data);
+                        },
+                        Err(e) => {
+                            error!("Failed to get latest blockhash. handle_event_submit_hash_tx: {}", e);
+                            let process_data = TaskProcessTxData {
+                                tx_type: "Register".to_string(),
+                                signature: None,
+                                signed_tx: None,
+                                hash_time: None,
+                            };
+                            return Err((
+                                process_data,
+                                e
+                            ));
+                        },
                     }
                 }
             }));
@@ -743,34 +930,36 @@ pub fn handle_event_claim_ore_rewards(
                 // TODO: use proof account data
                 if let Ok(Some(_ata)) = client.get_token_account(&token_account_pubkey).await {
                     let ix = get_claim_ix(wallet.pubkey(), token_account_pubkey, claim_amount);
-                    let latest_blockhash = client
-                        .get_latest_blockhash_with_commitment(client.commitment()).await;
+                    let latest_blockhash = client.get_latest_blockhash().await;
 
-                    if let Ok((hash, _slot)) = latest_blockhash {
-                        let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
+                    match latest_blockhash {
+                        Ok(hash) => {
+                            let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
 
-                        tx.sign(&[&wallet], hash);
-                        let process_data = TaskProcessTxData {
-                            tx_type: "Claim".to_string(),
-                            signature: None,
-                            signed_tx: Some(tx),
-                            hash_time: None,
-                        };
+                            tx.sign(&[&wallet], hash);
+                            let process_data = TaskProcessTxData {
+                                tx_type: "Claim".to_string(),
+                                signature: None,
+                                signed_tx: Some(tx),
+                                hash_time: None,
+                            };
 
-                        return Ok(process_data);
-                    } else {
-                        error!("Failed to get latest blockhash. handle_event_claim_ore_rewards");
-                        let process_data = TaskProcessTxData {
-                            tx_type: "Claim".to_string(),
-                            signature: None,
-                            signed_tx: None,
-                            hash_time: None,
-                        };
+                            return Ok(process_data);
+                        },
+                        Err(e) => {
+                            error!("Failed to get latest blockhash. handle_event_claim_ore_rewards: {}", e);
+                            let process_data = TaskProcessTxData {
+                                tx_type: "Claim".to_string(),
+                                signature: None,
+                                signed_tx: None,
+                                hash_time: None,
+                            };
 
-                        return Err((
-                            process_data,
-                            "Failed to get latest blockhash.".to_string()
-                        ));
+                            return Err((
+                                process_data,
+                                e
+                            ));
+                        },
                     }
                 } else {
                     let ix = spl_associated_token_account::instruction::create_associated_token_account(
@@ -780,35 +969,37 @@ pub fn handle_event_claim_ore_rewards(
                         &spl_token::id(),
                     );
 
-                    let latest_blockhash = client
-                        .get_latest_blockhash_with_commitment(client.commitment()).await;
+                    let latest_blockhash = client.get_latest_blockhash().await;
 
-                    if let Ok((hash, _slot)) = latest_blockhash {
-                        let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
+                    match latest_blockhash {
+                        Ok(hash) => {
+                            let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
 
-                        tx.sign(&[&wallet], hash);
+                            tx.sign(&[&wallet], hash);
 
-                        let process_data = TaskProcessTxData {
-                            tx_type: "CreateAta".to_string(),
-                            signature: None,
-                            signed_tx: Some(tx),
-                            hash_time: None,
-                        };
+                            let process_data = TaskProcessTxData {
+                                tx_type: "CreateAta".to_string(),
+                                signature: None,
+                                signed_tx: Some(tx),
+                                hash_time: None,
+                            };
 
-                        return Ok(process_data);
-                    } else {
-                        error!("Failed to get latest blockhash. handle_event_claim_ore_rewards");
-                        let process_data = TaskProcessTxData {
-                            tx_type: "CreateAta".to_string(),
-                            signature: None,
-                            signed_tx: None,
-                            hash_time: None,
-                        };
+                            return Ok(process_data);
+                        },
+                        Err(e) => {
+                            error!("Failed to get latest blockhash. handle_event_claim_ore_rewards: {}", e);
+                            let process_data = TaskProcessTxData {
+                                tx_type: "CreateAta".to_string(),
+                                signature: None,
+                                signed_tx: None,
+                                hash_time: None,
+                            };
 
-                        return Err((
-                            process_data,
-                            "Failed to get latest blockhash.".to_string()
-                        ));
+                            return Err((
+                                process_data,
+                                e
+                            ));
+                        },
                     }
                 }
             }));
@@ -855,34 +1046,36 @@ pub fn handle_event_stake_ore(
                 if let Ok(Some(ata)) = client.get_token_account(&token_account_pubkey).await {
                     if let Ok(stake_amount) = ata.token_amount.amount.parse::<u64>() {
                         let ix = get_stake_ix(wallet.pubkey(), token_account_pubkey, stake_amount);
-                        let latest_blockhash = client
-                            .get_latest_blockhash_with_commitment(client.commitment()).await;
+                        let latest_blockhash = client.get_latest_blockhash().await;
 
-                        if let Ok((hash, _slot)) = latest_blockhash {
-                            let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
-
-                            tx.sign(&[&wallet], hash);
-                            let process_data = TaskProcessTxData {
-                                tx_type: "Stake".to_string(),
-                                signature: None,
-                                signed_tx: Some(tx),
-                                hash_time: None,
-                            };
+                        match latest_blockhash {
+                            Ok(hash) => {
+                                let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
 
-                            return Ok(process_data);
-                        } else {
-                            error!("Failed to stake. handle_event_stake_ore.");
-                            let process_data = TaskProcessTxData {
-                                tx_type: "Stake".to_string(),
-                                signature: None,
-                                signed_tx: None,
-                                hash_time: None,
-                            };
+                                tx.sign(&[&wallet], hash);
+                                let process_data = TaskProcessTxData {
+                                    tx_type: "Stake".to_string(),
+                                    signature: None,
+                                    signed_tx: Some(tx),
+                                    hash_time: None,
+                                };
 
-                            return Err((
-                                process_data,
-                                "Failed to get latest blockhash.".to_string()
-                            ));
+                                return Ok(process_data);
+                            },
+                            Err(e) => {
+                                error!("Failed to get latest blockhash. handle_event_stake_ore: {}", e);
+                                let process_data = TaskProcessTxData {
+                                    tx_type: "Stake".to_string(),
+                                    signature: None,
+                                    signed_tx: None,
+                                    hash_time: None,
+                                };
+
+                                return Err((
+                                    process_data,
+                                    e
+                                ));
+                            },
                         }
 
                     } else {
@@ -896,7 +1089,10 @@ pub fn handle_event_stake_ore(
 
                         return Err((
                             process_data,
-                            "Failed to parse token account.".to_string(),
+                            AppError::Decode(AccountDecodeError::InvalidData {
+                                account: "ore token".to_string(),
+                                len: spl_token::state::Account::LEN,
+                            }),
                         ));
                     }
                 } else {
@@ -907,35 +1103,37 @@ pub fn handle_event_stake_ore(
                         &spl_token::id(),
                     );
 
-                    let latest_blockhash = client
-                        .get_latest_blockhash_with_commitment(client.commitment()).await;
+                    let latest_blockhash = client.get_latest_blockhash().await;
 
-                    if let Ok((hash, _slot)) = latest_blockhash {
-                        let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
+                    match latest_blockhash {
+                        Ok(hash) => {
+                            let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
 
-                        tx.sign(&[&wallet], hash);
+                            tx.sign(&[&wallet], hash);
 
-                        let process_data = TaskProcessTxData {
-                            tx_type: "CreateAta".to_string(),
-                            signature: None,
-                            signed_tx: Some(tx),
-                            hash_time: None,
-                        };
+                            let process_data = TaskProcessTxData {
+                                tx_type: "CreateAta".to_string(),
+                                signature: None,
+                                signed_tx: Some(tx),
+                                hash_time: None,
+                            };
 
-                        return Ok(process_data);
-                    } else {
-                        error!("Failed to get latest blockhash. handle_event_claim_ore_rewards");
-                        let process_data = TaskProcessTxData {
-                            tx_type: "CreateAta".to_string(),
-                            signature: None,
-                            signed_tx: None,
-                            hash_time: None,
-                        };
+                            return Ok(process_data);
+                        },
+                        Err(e) => {
+                            error!("Failed to get latest blockhash. handle_event_claim_ore_rewards: {}", e);
+                            let process_data = TaskProcessTxData {
+                                tx_type: "CreateAta".to_string(),
+                                signature: None,
+                                signed_tx: None,
+                                hash_time: None,
+                            };
 
-                        return Err((
-                            process_data,
-                            "Failed to get latest blockhash.".to_string(),
-                        ));
+                            return Err((
+                                process_data,
+                                e,
+                            ));
+                        },
                     }
                 }
             }));
@@ -952,53 +1150,432 @@ pub fn handle_event_stake_ore(
 pub fn handle_event_lock(
     mut commands: Commands,
     mut event_reader: EventReader<EventLock>,
+    mut app_wallet: ResMut<AppWallet>,
+    mut vault_res: ResMut<WalletVaultResource>,
+    mut miner_status: ResMut<MinerStatusResource>,
+    mining_channels_res: Res<MiningDataChannelResource>,
+    asset_server: Option<Res<AssetServer>>,
+    mut query_toggle: Query<(&mut UiImage, &mut ToggleAutoMine)>,
+    query_reveal_seed: Query<Entity, With<TaskRevealSeed>>,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
     for _ev in event_reader.read() {
-        commands.remove_resource::<AppWallet>();
-        next_state.set(AppScreenState::Unlock);
+        // a seed phrase shouldn't show up after the next unlock
+        for entity in query_reveal_seed.iter() {
+            commands.entity(entity).remove::<TaskRevealSeed>();
+        }
+        // the miner holds its own clone of the keypair until it stops
+        if miner_status.miner_status != "STOPPED" {
+            miner_status.miner_status = "STOPPED".to_string();
+            if let Some(sender) = mining_channels_res.sender.as_ref() {
+                let _ = sender.try_send(MiningDataChannelMessage::Stop);
+            }
+            if let Ok((mut btn, mut toggle)) = query_toggle.get_single_mut() {
+                toggle.0 = false;
+                if let Some(asset_server) = &asset_server {
+                    *btn = UiImage::new(asset_server.load(MINE_TOGGLE_OFF));
+                }
+            }
+        }
+        commands.remove_resource::<AccountSubscriptions>();
+        commands.insert_resource(WsStatusResource::default());
+        vault_res.lock();
+        // keep the resource around, the wallet gated systems read it every frame
+        app_wallet.wallet = None;
+        app_wallet.watch_only = None;
+        app_wallet.sol_balance = 0.0;
+        app_wallet.ore_balance = 0.0;
+        // watch-only mode works without a wallet file
+        if vault_res.path.exists() {
+            next_state.set(AppScreenState::Unlock);
+        } else {
+            next_state.set(AppScreenState::WalletSetup);
+        }
+    }
+}
+
+pub fn handle_event_watch_pubkey(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventWatchPubkey>,
+    mut app_wallet: ResMut<AppWallet>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for ev in event_reader.read() {
+        let pubkey = match Pubkey::from_str(ev.0.trim()) {
+            Ok(pubkey) => pubkey,
+            Err(_) => {
+                event_writer_toast.send(EventToast::error("Not a valid pubkey."));
+                continue;
+            },
+        };
+        // start fresh subscriptions and ui data for the watched account
+        commands.remove_resource::<AccountSubscriptions>();
+        commands.insert_resource(WsStatusResource::default());
+        commands.insert_resource(ProofAccountResource::default());
+        app_wallet.watch_only = Some(pubkey);
+        app_wallet.sol_balance = 0.0;
+        app_wallet.ore_balance = 0.0;
+        info!("Watching {}", pubkey);
+        event_writer_toast.send(EventToast::info(format!(
+            "Watching {}, signing is disabled.",
+            shorten_string(pubkey.to_string(), 10)
+        )));
+        next_state.set(AppScreenState::Mining);
     }
 }
 
 pub fn handle_event_unlock(
+    mut commands: Commands,
     mut event_reader: EventReader<EventUnlock>,
-    mut app_wallet: ResMut<AppWallet>,
+    vault_res: Res<WalletVaultResource>,
+    mut attempts: ResMut<UnlockAttempts>,
     query: Query<&TextInput, With<TextPasswordInput>>,
-    mut next_state: ResMut<NextState<AppScreenState>>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    query_pending: Query<(), With<TaskUnlockVault>>,
 ) {
+    let mut pending = !query_pending.is_empty();
     for _ev in event_reader.read() {
-        let text = query.get_single();
-        if let Ok(text_input) = text {
-            let password = text_input.text.clone();
-
-            // TODO: use const path?
-            let wallet_path = Path::new("save.data");
-
-            let cocoon = Cocoon::new(password.as_bytes());
-            let mut file = File::open(wallet_path).unwrap();
-            let encoded = cocoon.parse(&mut file);
-            if let Ok(encoded) = encoded {
-                let wallet = Keypair::from_bytes(&encoded);
-                if let Ok(wallet) = wallet {
-                    let wallet = Arc::new(wallet);
-                    app_wallet.wallet = Some(wallet);
-                    next_state.set(AppScreenState::Mining);
-                } else {
-                    error!("Failed to parse keypair from bytes. (events.rs: handle_event_unlock)");
-                }
+        // the unlock screen shows the countdown
+        if pending || attempts.retry_in(get_unix_timestamp()) > 0 {
+            continue;
+        }
+        let text_input = if let Ok(text_input) = query.get_single() {
+            text_input
+        } else {
+            error!("Failed to get_single on TextPasswordInput (events.rs: handle_event_unlock)");
+            continue;
+        };
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
+        } else {
+            error!("Failed to get task_handler_entity. handle_event_unlock.");
+            continue;
+        };
+
+        let path = vault_res.path.clone();
+        let kdf_iterations = vault_res.kdf_iterations;
+        let password = Zeroizing::new(text_input.text.clone());
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            WalletVaultResource::load_and_upgrade(&path, &password, kdf_iterations)
+        });
+        attempts.error = None;
+        pending = true;
+
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskUnlockVault { task });
+    }
+}
+
+pub fn handle_event_add_wallet(
+    mut event_reader: EventReader<EventAddWallet>,
+    mut vault_res: ResMut<WalletVaultResource>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for ev in event_reader.read() {
+        let added = vault_res.update(|vault| {
+            let name = if ev.name.trim().is_empty() {
+                vault.default_name()
             } else {
-                error!("Failed to decrypt file. (events.rs: handle_event_unlock)");
+                ev.name.clone()
+            };
+            vault.add(&name, &ev.keypair, ev.seed.clone())?;
+            Ok(name)
+        });
+        match added {
+            Ok(name) => {
+                event_writer_toast.send(EventToast::success(format!("Added wallet {}.", name)));
+            },
+            Err(e) => {
+                error!("Failed to add wallet: {}", e);
+                event_writer_toast.send(EventToast::error(e));
+            },
+        }
+    }
+}
+
+pub fn handle_event_rename_wallet(
+    mut event_reader: EventReader<EventRenameWallet>,
+    mut vault_res: ResMut<WalletVaultResource>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for ev in event_reader.read() {
+        if let Err(e) = vault_res.update(|vault| vault.rename(ev.index, &ev.name)) {
+            error!("Failed to rename wallet: {}", e);
+            event_writer_toast.send(EventToast::error(e));
+        }
+    }
+}
+
+pub fn handle_event_remove_wallet(
+    mut event_reader: EventReader<EventRemoveWallet>,
+    mut vault_res: ResMut<WalletVaultResource>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for ev in event_reader.read() {
+        match vault_res.update(|vault| vault.remove(ev.0)) {
+            Ok(removed) => {
+                event_writer_toast.send(EventToast::info(format!("Removed wallet {}.", removed.name)));
+            },
+            Err(e) => {
+                error!("Failed to remove wallet: {}", e);
+                event_writer_toast.send(EventToast::error(e));
+            },
+        }
+    }
+}
+
+pub fn handle_event_switch_wallet(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventSwitchWallet>,
+    mut vault_res: ResMut<WalletVaultResource>,
+    mut app_wallet: ResMut<AppWallet>,
+    mut proof_account: ResMut<ProofAccountResource>,
+    miner_status: Res<MinerStatusResource>,
+    mut event_writer_start_stop: EventWriter<EventStartStopMining>,
+    mut event_writer_fetch_ui_data: EventWriter<EventFetchUiDataFromRpc>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for ev in event_reader.read() {
+        let switched = vault_res.update(|vault| {
+            if vault.active == ev.0 {
+                return Err("Wallet is already active.".to_string());
             }
+            vault.switch(ev.0)?;
+            Ok(vault.active_wallet().clone())
+        });
+        let wallet = match switched {
+            Ok(wallet) => wallet,
+            Err(e) => {
+                error!("Failed to switch wallet: {}", e);
+                event_writer_toast.send(EventToast::error(e));
+                continue;
+            },
+        };
+
+        if miner_status.miner_status != "STOPPED" {
+            event_writer_start_stop.send(EventStartStopMining);
+        }
+        // restarted for the new pubkey when the mining screen is entered
+        commands.remove_resource::<AccountSubscriptions>();
+        commands.insert_resource(WsStatusResource::default());
+
+        *proof_account = ProofAccountResource::default();
+        app_wallet.wallet = Some(Arc::new(wallet.keypair()));
+        app_wallet.sol_balance = 0.0;
+        app_wallet.ore_balance = 0.0;
+        event_writer_fetch_ui_data.send(EventFetchUiDataFromRpc);
+        event_writer_toast.send(EventToast::success(format!("Switched to wallet {}.", wallet.name)));
+    }
+}
+
+pub fn handle_event_change_password(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventChangePassword>,
+    mut vault_res: ResMut<WalletVaultResource>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for ev in event_reader.read() {
+        if ev.new != ev.confirm {
+            event_writer_toast.send(EventToast::warning("New passwords don't match."));
+            continue;
+        }
+        if vault_res.password_change_pending {
+            continue;
+        }
+        if vault_res.vault.is_none() {
+            event_writer_toast.send(EventToast::error("Wallet vault is locked."));
+            continue;
+        }
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
         } else {
-            error!("Failed to get_single on TextPasswordInput (events.rs: handle_event_unlock)");
+            error!("Failed to get task_handler_entity. handle_event_change_password.");
+            continue;
+        };
+
+        let path = vault_res.path.clone();
+        let kdf_iterations = vault_res.kdf_iterations;
+        let current = Zeroizing::new(ev.current.clone());
+        let new = Zeroizing::new(ev.new.clone());
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            WalletVaultResource::change_password(&path, &current, &new, kdf_iterations)
+        });
+        vault_res.password_change_pending = true;
+
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskChangePassword { task });
+    }
+}
+
+pub fn handle_event_export_wallet(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventExportWallet>,
+    vault_res: Res<WalletVaultResource>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    query_pending: Query<(), With<TaskExportWallet>>,
+) {
+    let mut pending = !query_pending.is_empty();
+    for ev in event_reader.read() {
+        if pending {
+            continue;
+        }
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
+        } else {
+            error!("Failed to get task_handler_entity. handle_event_export_wallet.");
+            continue;
+        };
+
+        let vault_path = vault_res.path.clone();
+        let kdf_iterations = vault_res.kdf_iterations;
+        let password = Zeroizing::new(ev.password.clone());
+        let export = ev.export;
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            export_wallet(&vault_path, &password, export, kdf_iterations)
+        });
+        pending = true;
+
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskExportWallet { task });
+    }
+}
+
+/// Reads the vault again so the password is checked, then writes the export
+/// into `EXPORT_DIR` next to it.
+fn export_wallet(
+    vault_path: &Path,
+    password: &str,
+    export: WalletExport,
+    kdf_iterations: u32,
+) -> Result<PathBuf, EventToast> {
+    let vault = match WalletVault::load(vault_path, password) {
+        Ok(vault) => vault,
+        Err(VaultError::Decrypt) => return Err(EventToast::error("Wrong password.")),
+        Err(e) => {
+            error!("Failed to read vault: {}", e);
+            return Err(EventToast::error(e.to_string()));
+        },
+    };
+
+    let export_dir = vault_path.with_file_name(EXPORT_DIR);
+    if let Err(e) = std::fs::create_dir_all(&export_dir) {
+        error!("Failed to create {}: {}", export_dir.display(), e);
+        return Err(EventToast::error(format!("Failed to create {}: {}", export_dir.display(), e)));
+    }
+    let result = match export {
+        WalletExport::Keypair => {
+            let wallet = vault.active_wallet();
+            let path = export_dir.join(format!("{}.json", wallet.pubkey()));
+            write_keypair_file(&wallet.keypair(), &path)
+                .map(|_| path)
+                .map_err(|e| e.to_string())
+        },
+        WalletExport::Backup => {
+            let path = export_dir.join(format!(
+                "ore-backup-{}-{}.data",
+                vault.active_wallet().pubkey(),
+                get_unix_timestamp()
+            ));
+            std::fs::read(vault_path)
+                .map_err(|e| e.to_string())
+                .and_then(|vault_file| {
+                    let bundle = BackupBundle {
+                        vault_file,
+                        config: std::fs::read_to_string("config.toml").ok(),
+                    };
+                    bundle.save(&path, password, Kdf::pbkdf2(kdf_iterations))
+                })
+                .map(|_| path)
+        },
+    };
+    result.map_err(|e| {
+        error!("Failed to export wallet: {}", e);
+        EventToast::error(format!("Export failed: {}", e))
+    })
+}
+
+pub fn handle_event_reveal_seed(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventRevealSeed>,
+    vault_res: Res<WalletVaultResource>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    query_pending: Query<(), With<TaskRevealSeed>>,
+) {
+    let mut pending = !query_pending.is_empty();
+    for ev in event_reader.read() {
+        if pending {
+            continue;
         }
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
+        } else {
+            error!("Failed to get task_handler_entity. handle_event_reveal_seed.");
+            continue;
+        };
+
+        let vault_path = vault_res.path.clone();
+        let password = Zeroizing::new(ev.password.clone());
+        let index = ev.index;
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            reveal_seed(&vault_path, &password, index)
+        });
+        pending = true;
+
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskRevealSeed { task });
+    }
+}
+
+/// The seed phrase of wallet `index` as shown on the settings screen.
+fn reveal_seed(vault_path: &Path, password: &str, index: usize) -> Result<Zeroizing<String>, EventToast> {
+    // read the file again so the password is checked, not just the unlocked vault
+    let vault = match WalletVault::load(vault_path, password) {
+        Ok(vault) => vault,
+        Err(VaultError::Decrypt) => return Err(EventToast::error("Wrong password.")),
+        Err(e) => {
+            error!("Failed to read vault: {}", e);
+            return Err(EventToast::error(e.to_string()));
+        },
+    };
+    let wallet = vault
+        .wallets
+        .get(index)
+        .ok_or_else(|| EventToast::error("Wallet does not exist."))?;
+    match &wallet.seed {
+        Some(seed) => {
+            let mut value = format!(
+                "{}\nPath: {}",
+                seed.phrase,
+                seed.scheme.path_label(seed.account)
+            );
+            if !seed.passphrase.is_empty() {
+                value += &format!("\nPassphrase: {}", seed.passphrase);
+            }
+            Ok(Zeroizing::new(value))
+        },
+        None => Err(EventToast::info(format!(
+            "{} was imported from a key file and has no seed phrase.",
+            wallet.name
+        ))),
     }
 }
 
 pub fn handle_event_save_config(
+    mut commands: Commands,
     mut event_reader: EventReader<EventSaveConfig>,
     mut ore_app_state: ResMut<OreAppState>,
     mut miner_status: ResMut<MinerStatusResource>,
+    mut auto_lock: ResMut<AutoLockResource>,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
     for ev in event_reader.read() {
@@ -1011,30 +1588,114 @@ pub fn handle_event_save_config(
 
 
         let new_state;
-        let wallet_path = Path::new("save.data");
+        let wallet_path = Path::new(VAULT_PATH);
         if wallet_path.exists() {
             new_state = AppScreenState::Mining;
         } else {
             new_state = AppScreenState::WalletSetup;
         }
 
+        // reconnect everything with the new urls when the mining screen is entered
+        if ore_app_state.config.rpc_url != new_config.rpc_url
+            || ore_app_state.config.ws_url != new_config.ws_url
+            || ore_app_state.config.backup_rpc_urls != new_config.backup_rpc_urls
+        {
+            commands.remove_resource::<AccountSubscriptions>();
+            commands.insert_resource(WsStatusResource::default());
+        }
+
         miner_status.miner_threads = new_config.threads;
+        *auto_lock = AutoLockResource::new(new_config.auto_lock_minutes);
         ore_app_state.config = new_config;
         next_state.set(new_state);
     }
 }
 
+pub fn handle_event_test_connection(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventTestConnection>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+) {
+    for ev in event_reader.read() {
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
+        } else {
+            error!("Failed to get task_handler_entity. handle_event_test_connection.");
+            continue;
+        };
+
+        let rpc_url = ev.rpc_url.clone();
+        let ws_url = ev.ws_url.clone();
+        let cluster = ev.cluster;
+        let pool = IoTaskPool::get();
+        let task = pool.spawn(Compat::new(async move {
+            let client = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+
+            let rpc_start = Instant::now();
+            let version = client.get_version().await
+                .map_err(|e| format!("getVersion failed: {}", e))?;
+            let rpc_latency_ms = rpc_start.elapsed().as_millis() as u64;
+            let slot = client.get_slot().await
+                .map_err(|e| format!("getSlot failed: {}", e))?;
+            let genesis_hash = client.get_genesis_hash().await
+                .map_err(|e| format!("getGenesisHash failed: {}", e))?;
+            if let Some(expected_genesis_hash) = cluster.genesis_hash() {
+                if genesis_hash.to_string() != expected_genesis_hash {
+                    return Err(format!("rpc is not a {} node, genesis hash {}", cluster.to_string(), genesis_hash));
+                }
+            }
+            let program_id = cluster.program_id();
+            match client.get_account(&program_id).await {
+                Ok(account) if account.executable => {},
+                _ => return Err(format!("ore program {} is not deployed on this cluster", program_id)),
+            }
+
+            let ws_start = Instant::now();
+            let ps_client = PubsubClient::new(&ws_url).await
+                .map_err(|e| format!("websocket connect failed: {}", e))?;
+            let ws_result = match ps_client.slot_subscribe().await {
+                Ok((mut slot_notifications, unsub)) => {
+                    let first_slot = timeout(Duration::from_secs(10), slot_notifications.next()).await;
+                    drop(slot_notifications);
+                    unsub().await;
+                    match first_slot {
+                        Ok(Some(_)) => Ok(ws_start.elapsed().as_millis() as u64),
+                        Ok(None) => Err("websocket subscription closed".to_string()),
+                        Err(_) => Err("no websocket slot update within 10s".to_string()),
+                    }
+                },
+                Err(e) => Err(format!("websocket subscribe failed: {}", e)),
+            };
+            let _ = ps_client.shutdown().await;
+            let ws_latency_ms = ws_result?;
+
+            Ok(ConnectionTestResult {
+                solana_core: version.solana_core,
+                slot,
+                genesis_hash: genesis_hash.to_string(),
+                rpc_latency_ms,
+                ws_latency_ms,
+            })
+        }));
+
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskTestConnection { task });
+    }
+}
+
 pub fn handle_event_generate_wallet(
     mut event_reader: EventReader<EventGenerateWallet>,
     // mut text_query: Query<&mut Text, With<TextGeneratedPubkey>>,
     // mut ore_app_state: ResMut<OreAppState>,
     // mut next_state: ResMut<NextState<GameState>>,
     mut set: ParamSet<(
-        Query<(&mut Text, &mut TextGeneratedKeypair)>,
+        Query<(&mut Text, &mut TextGeneratedKeypair, &mut GeneratedSeedPhrase)>,
         Query<&mut Text, With<TextMnemonicLine1>>,
         Query<&mut Text, With<TextMnemonicLine2>>,
         Query<&mut Text, With<TextMnemonicLine3>>,
     )>,
+    mut query_verify_inputs: Query<&mut TextInput, With<TextSeedVerifyInput>>,
 ) {
     for _ev in event_reader.read() {
         let new_mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
@@ -1043,17 +1704,23 @@ pub fn handle_event_generate_wallet(
 
         let words: Vec<&str> = phrase.split(" ").collect();
 
-        let seed = Seed::new(&new_mnemonic, "");
-
-        let derivation_path = DerivationPath::from_absolute_path_str("m/44'/501'/0'/0'").unwrap();
-
-        let new_key = Keypair::from_seed_and_derivation_path(seed.as_bytes(), Some(derivation_path));
+        let new_key = derive_keypair(&new_mnemonic, "", DerivationScheme::Bip44Change, 0);
         if let Ok(new_key) = new_key {
             let new_key = Arc::new(new_key);
             let pubkey = new_key.pubkey().to_string();
-            for (mut text, mut text_keypair) in set.p0().iter_mut() {
+            for (mut text, mut text_keypair, mut seed_phrase) in set.p0().iter_mut() {
                 text.sections[0].value = pubkey.clone();
                 text_keypair.0 = new_key.clone();
+                // saving waits until the user types back some of the words
+                *seed_phrase = GeneratedSeedPhrase {
+                    backup: Some(SeedBackup::generated(phrase.clone())),
+                    verify_indices: pick_verify_indices(words.len()),
+                    verified: false,
+                    restore: None,
+                };
+            }
+            for mut text_input in query_verify_inputs.iter_mut() {
+                text_input.text.clear();
             }
             for mut text in set.p1().iter_mut() {
                 let mut value = String::new();
@@ -1085,13 +1752,71 @@ pub fn handle_event_generate_wallet(
     }
 }
 
+pub fn handle_event_import_mnemonic(
+    mut event_reader: EventReader<EventImportMnemonic>,
+    mut event_writer_toast: EventWriter<EventToast>,
+    mut set: ParamSet<(
+        Query<(&mut Text, &mut TextGeneratedKeypair, &mut GeneratedSeedPhrase)>,
+        Query<&mut Text, With<TextMnemonicLine1>>,
+        Query<&mut Text, With<TextMnemonicLine2>>,
+        Query<&mut Text, With<TextMnemonicLine3>>,
+    )>,
+) {
+    for ev in event_reader.read() {
+        let keypair = parse_mnemonic(&ev.phrase)
+            .and_then(|mnemonic| derive_keypair(&mnemonic, &ev.passphrase, ev.scheme, ev.account));
+        let keypair = match keypair {
+            Ok(keypair) => Arc::new(keypair),
+            Err(e) => {
+                error!("Failed to import seed phrase: {}", e);
+                event_writer_toast.send(EventToast::error(e));
+                continue;
+            },
+        };
+
+        let pubkey = keypair.pubkey().to_string();
+        let seed = SeedBackup {
+            phrase: normalize_phrase(&ev.phrase),
+            passphrase: ev.passphrase.clone(),
+            scheme: ev.scheme,
+            account: ev.account,
+        };
+        for (mut text, mut text_keypair, mut seed_phrase) in set.p0().iter_mut() {
+            text.sections[0].value = pubkey.clone();
+            text_keypair.0 = keypair.clone();
+            // typing the phrase in proves it was written down
+            *seed_phrase = GeneratedSeedPhrase {
+                backup: Some(seed.clone()),
+                verify_indices: vec![],
+                verified: true,
+                restore: None,
+            };
+        }
+        // the generated phrase no longer matches the key
+        for mut text in set.p1().iter_mut() {
+            text.sections[0].value = " ".to_string();
+        }
+        for mut text in set.p2().iter_mut() {
+            text.sections[0].value = " ".to_string();
+        }
+        for mut text in set.p3().iter_mut() {
+            text.sections[0].value = " ".to_string();
+        }
+        event_writer_toast.send(EventToast::success(format!(
+            "Imported {}. Add a password and save.",
+            shorten_string(pubkey, 10)
+        )));
+    }
+}
+
 pub fn handle_event_load_keypair_file(
     mut event_reader: EventReader<EventLoadKeypairFile>,
+    mut event_writer_toast: EventWriter<EventToast>,
     // mut text_query: Query<&mut Text, With<TextGeneratedPubkey>>,
     // mut ore_app_state: ResMut<OreAppState>,
     // mut next_state: ResMut<NextState<GameState>>,
     mut set: ParamSet<(
-        Query<(&mut Text, &mut TextGeneratedKeypair)>,
+        Query<(&mut Text, &mut TextGeneratedKeypair, &mut GeneratedSeedPhrase)>,
         Query<&mut Text, With<TextMnemonicLine1>>,
         Query<&mut Text, With<TextMnemonicLine2>>,
         Query<&mut Text, With<TextMnemonicLine3>>,
@@ -1099,12 +1824,45 @@ pub fn handle_event_load_keypair_file(
 ) {
     for ev in event_reader.read() {
         let path = &ev.0;
-        if let Ok(keypair) = read_keypair_file(path) {
+        if BackupBundle::is_backup(path) {
+            let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            for (mut text, _text_keypair, mut seed_phrase) in set.p0().iter_mut() {
+                text.sections[0].value = format!(
+                    "Backup {}. Enter the password it was exported with and click Save to restore it.",
+                    file_name
+                );
+                *seed_phrase = GeneratedSeedPhrase {
+                    backup: None,
+                    verify_indices: vec![],
+                    verified: true,
+                    restore: Some(path.clone()),
+                };
+            }
+            for mut text in set.p1().iter_mut() {
+                text.sections[0].value = String::new();
+            }
+            for mut text in set.p2().iter_mut() {
+                text.sections[0].value = String::new();
+            }
+            for mut text in set.p3().iter_mut() {
+                text.sections[0].value = String::new();
+            }
+            continue;
+        }
+        let keypair = read_keypair_file(path);
+        if let Ok(keypair) = keypair {
             let keypair = Arc::new(keypair);
             let pubkey = keypair.pubkey().to_string();
-            for (mut text, mut text_keypair) in set.p0().iter_mut() {
+            for (mut text, mut text_keypair, mut seed_phrase) in set.p0().iter_mut() {
                 text.sections[0].value = pubkey.clone();
                 text_keypair.0 = keypair.clone();
+                // a key file has no seed phrase to back up
+                *seed_phrase = GeneratedSeedPhrase {
+                    backup: None,
+                    verify_indices: vec![],
+                    verified: true,
+                    restore: None,
+                };
             }
             for mut text in set.p1().iter_mut() {
                 let value = String::new();
@@ -1118,51 +1876,173 @@ pub fn handle_event_load_keypair_file(
                 let value = String::new();
                 text.sections[0].value = value;
             }
-        } else {
+        } else if let Err(e) = keypair {
             error!("Error: Failed to load keypair file from path: {}", path.display());
+            event_writer_toast.send(EventToast::error(format!(
+                "Failed to load keypair file {}: {}",
+                path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(),
+                e
+            )));
+        }
+
+    }
+}
+
+pub fn handle_event_start_vanity_search(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventStartVanitySearch>,
+    mut event_writer_toast: EventWriter<EventToast>,
+    mut vanity_res: ResMut<VanitySearchResource>,
+    miner_status: Res<MinerStatusResource>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    query_pending: Query<(), With<TaskVanitySearch>>,
+) {
+    let mut pending = !query_pending.is_empty();
+    for ev in event_reader.read() {
+        if pending {
+            continue;
+        }
+        let pattern = match VanityPattern::new(&ev.prefix, &ev.suffix, ev.ignore_case) {
+            Ok(pattern) => pattern,
+            Err(e) => {
+                event_writer_toast.send(EventToast::warning(e));
+                continue;
+            }
+        };
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
+        } else {
+            continue;
+        };
+
+        if vanity_res.sender.is_none() {
+            let (sender, receiver) = bounded::<VanitySearchMessage>(1);
+            vanity_res.sender = Some(sender);
+            vanity_res.receiver = Some(receiver);
+        }
+        let receiver = vanity_res.receiver.as_ref().unwrap().clone();
+        let sender = vanity_res.sender.as_ref().unwrap().clone();
+        while let Ok(_) = receiver.try_recv() {
+            // clear out the stop left over from the last search
         }
 
+        let cpu_count = miner_status.sys_info.cpus().len() as u64;
+        let threads = miner_status.miner_threads.clamp(1, cpu_count.max(1));
+
+        vanity_res.start(&pattern);
+        let attempts = vanity_res.attempts.clone();
+        let source = ev.source;
+        info!(
+            "Vanity search for prefix '{}' suffix '{}' on {} threads, ~{:.0} keys expected",
+            pattern.prefix, pattern.suffix, threads, vanity_res.expected_attempts
+        );
+
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            find_vanity_par(pattern, source, threads, attempts, receiver, sender)
+        });
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskVanitySearch { task });
+        pending = true;
+    }
+}
+
+pub fn handle_event_stop_vanity_search(
+    mut event_reader: EventReader<EventStopVanitySearch>,
+    vanity_res: Res<VanitySearchResource>,
+) {
+    for _ev in event_reader.read() {
+        vanity_res.stop();
     }
 }
 
+/// The search threads would keep every core busy after leaving the screen.
+pub fn stop_vanity_search(vanity_res: Res<VanitySearchResource>) {
+    vanity_res.stop();
+}
+
 pub fn handle_event_save_wallet(
+    mut commands: Commands,
     mut event_reader: EventReader<EventSaveWallet>,
     mut set: ParamSet<(
-        Query<&TextGeneratedKeypair>,
+        Query<(&TextGeneratedKeypair, &GeneratedSeedPhrase)>,
         Query<&TextInput, With<TextPasswordInput>>,
     )>,
-    mut next_state: ResMut<NextState<AppScreenState>>,
+    vault_res: Res<WalletVaultResource>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    query_pending: Query<(), With<TaskSaveWallet>>,
+    mut event_writer_toast: EventWriter<EventToast>,
 ) {
+    let mut pending = !query_pending.is_empty();
     for _ev in event_reader.read() {
-        let generated_keypair = set.p0().single().0.clone();
-
-        let password = set.p1().single().text.clone();
-
-        let wallet_path = Path::new("save.data");
+        if pending {
+            continue;
+        }
+        let (generated_keypair, seed, restore) = {
+            let query = set.p0();
+            let (text_keypair, seed_phrase) = query.single();
+            if !seed_phrase.verified {
+                if seed_phrase.backup.is_some() {
+                    event_writer_toast.send(EventToast::warning("Enter the requested seed phrase words before saving."));
+                } else {
+                    event_writer_toast.send(EventToast::warning("Generate or import a wallet before saving."));
+                }
+                continue;
+            }
+            (text_keypair.0.clone(), seed_phrase.backup.clone(), seed_phrase.restore.clone())
+        };
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
+        } else {
+            error!("Failed to get task_handler_entity. handle_event_save_wallet.");
+            continue;
+        };
 
-        let cocoon = Cocoon::new(password.as_bytes());
-        let wallet_bytes = generated_keypair.to_bytes();
-        let file = File::create(wallet_path);
+        let password = Zeroizing::new(set.p1().single().text.clone());
+        let vault_path = vault_res.path.clone();
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            if let Some(restore) = restore {
+                let restored = BackupBundle::load(&restore, &password).and_then(|bundle| {
+                    let (_, replaced) = bundle.restore_vault(&vault_path, &password)?;
+                    Ok(SavedWallet { bundle: Some(bundle), replaced })
+                });
+                return match restored {
+                    Ok(saved) => Ok(saved),
+                    Err(VaultError::Decrypt) => Err(EventToast::error("Wrong password for this backup.")),
+                    Err(e) => {
+                        error!("Failed to restore backup: {}", e);
+                        Err(EventToast::error(e.to_string()))
+                    },
+                };
+            }
 
-        if let Ok(mut file) = file {
-            let container = cocoon.dump(wallet_bytes.to_vec(), &mut file);
+            // forgot password lands here with the old vault still on disk
+            let vault = WalletVault::new("Wallet 1", &generated_keypair).with_seed(seed);
+            copy_to_backup(&vault_path)
+                .and_then(|replaced| vault.save(&vault_path, &password).map(|_| replaced))
+                .map(|replaced| SavedWallet { bundle: None, replaced })
+                .map_err(|e| {
+                    error!("Error: {}", e);
+                    EventToast::error(e)
+                })
+        });
+        pending = true;
 
-            if let Ok(_) = container {
-                // go to locked screen
-                next_state.set(AppScreenState::Unlock);
-            } else {
-                error!("Error: Failed to save wallet file.");
-            }
-        } else {
-            error!("Error: failed to create file at path: {}", wallet_path.display());
-        }
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskSaveWallet { task });
     }
 }
 
 pub fn handle_event_request_airdrop(
     mut commands: Commands,
     mut event_reader: EventReader<EventRequestAirdrop>,
+    mut event_writer: EventWriter<EventTxResult>,
     app_wallet: Res<AppWallet>,
+    app_state: Res<OreAppState>,
+    rpc_connection: Res<RpcConnection>,
     query_task_handler: Query<Entity, With<EntityTaskHandler>>,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
@@ -1174,12 +2054,26 @@ pub fn handle_event_request_airdrop(
             error!("wallet is None, switching to wallet unlock screen");
             continue;
         }; 
+        let cluster = app_state.config.cluster;
+        let client = if let Some(airdrop) = &rpc_connection.airdrop {
+            airdrop.clone()
+        } else {
+            error!("Airdrop is not available on {}", cluster.to_string());
+            event_writer.send(EventTxResult {
+                tx_type: "Airdrop".to_string(),
+                sig: "".to_string(),
+                tx_time: 0,
+                hash_status: None,
+                tx_status: TxStatus {
+                    status: "FAILED".to_string(),
+                    error: format!("Airdrop is not available on {}", cluster.to_string()),
+                },
+            });
+            continue;
+        };
         if let Ok(task_handler_entity) = query_task_handler.get_single() {
             let pool = IoTaskPool::get();
             let task = pool.spawn(Compat::new(async move {
-                let devnet_url = "https://api.devnet.solana.com".to_string();
-                let client = RpcClient::new(devnet_url);
-
                 let airdrop_request = client.request_airdrop(&wallet.pubkey(), LAMPORTS_PER_SOL).await;
 
                 match airdrop_request {
@@ -1205,7 +2099,7 @@ pub fn handle_event_request_airdrop(
 
                         return Err((
                             process_data,
-                            e.to_string(),
+                            e,
                         ));
                     }
                 }
@@ -1252,13 +2146,12 @@ pub fn handle_event_check_sigs(
                         let scr = SigCheckResults {
                             ents: processor_entities,
                             sigs,
-                            sig_statuses: signature_statuses.value
+                            sig_statuses: signature_statuses
                         };
                         return Ok(scr);
                     }
                     Err(err) => {
-                        let e_str = format!("Sig Check Error: {}", err.to_string());
-                        return Err(e_str);
+                        return Err(err);
                     }
                 }
             }));
@@ -1353,6 +2246,7 @@ pub fn handle_event_proof_account_updated(
                             ),
                             DashboardProofUpdatesLogsListItem
                         ));
+                        spawn_account_link_icon(parent, &asset_server, pubkey.to_string());
                     }).id();
 
                     commands.entity(moving_scroll_panel_entity).add_child(new_list_item_id);
@@ -1403,3 +2297,27 @@ pub fn handle_event_cancel_mining(
         }
     }
 }
+
+pub fn handle_event_toast(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventToast>,
+    mut toast_history: ResMut<ToastHistoryResource>,
+    asset_server: Option<Res<AssetServer>>,
+    query_toast_area: Query<Entity, With<ToastArea>>,
+) {
+    for ev in event_reader.read() {
+        toast_history.entries.push_back(ToastEntry {
+            severity: ev.severity,
+            message: ev.message.clone(),
+            created_at: get_unix_timestamp(),
+        });
+        while toast_history.entries.len() > TOAST_HISTORY_LEN {
+            toast_history.entries.pop_front();
+        }
+        toast_history.unread += 1;
+
+        if let (Some(asset_server), Ok(toast_area)) = (&asset_server, query_toast_area.get_single()) {
+            spawn_toast(&mut commands, asset_server, toast_area, ev.severity, ev.message.clone());
+        }
+    }
+}
@@ -1,31 +1,37 @@
 use std::{
-    fs, path::Path, sync::Arc, time::{Duration, Instant}
+    collections::VecDeque, fs, path::Path, sync::{atomic::{AtomicBool, AtomicUsize, Ordering}, Arc}, time::{Duration, Instant}
 };
 
 use async_compat::Compat;
-use async_std::task::sleep;
-use bevy::{prelude::*, tasks::{futures_lite::StreamExt, IoTaskPool}, utils::HashMap, winit::{UpdateMode, WinitSettings}};
+use async_std::{future::timeout, task::sleep};
+use futures::future::join_all;
+use bevy::{input::{keyboard::KeyboardInput, mouse::{MouseButtonInput, MouseWheel}}, prelude::*, tasks::IoTaskPool, utils::HashMap, winit::{UpdateMode, WinitSettings}};
 use bevy_inspector_egui::{inspector_options::ReflectInspectorOptions, quick::WorldInspectorPlugin, InspectorOptions};
 use copypasta::{ClipboardContext, ClipboardProvider};
 use crossbeam_channel::{unbounded, Receiver, Sender};
+use error::AppError;
 use events::*;
 use ore_api::{consts::TOKEN_DECIMALS, state::{Bus, Proof, Treasury}};
+use ore_rpc::{failover::{FailoverRpc, RpcCallResult}, OreRpc};
 use ore_utils::{ORE_TOKEN_DECIMALS, AccountDeserialize};
 use serde::{Deserialize, Serialize};
-use solana_account_decoder::{parse_token::UiTokenAccount, UiAccountEncoding};
-use solana_client::{nonblocking::{pubsub_client::PubsubClient, rpc_client::RpcClient}, rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSendTransactionConfig}, rpc_filter::RpcFilterType, rpc_response::{Response, RpcKeyedAccount}};
+use solana_account_decoder::parse_token::UiTokenAccount;
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_filter::RpcFilterType, rpc_response::{Response, RpcKeyedAccount}};
 use solana_sdk::{
-    commitment_config::{CommitmentConfig, CommitmentLevel}, keccak::Hash as KeccakHash, program_pack::Pack, pubkey::Pubkey, signature::{Keypair, Signature}, signer::Signer, transaction::Transaction
+    commitment_config::CommitmentConfig, keccak::Hash as KeccakHash, pubkey::Pubkey, signature::{Keypair, Signature}, signer::Signer, transaction::Transaction
 };
-use solana_transaction_status::UiTransactionEncoding;
+use subscriptions::{AccountSubscriptions, WsStatus, WsStatusResource};
+use utils::{get_unix_timestamp, url_encode};
+use vanity::VanitySearchResource;
+use vault::{UnlockAttempts, VaultHeader, WalletVaultResource, VAULT_PATH};
 use tasks::{
-    handle_task_got_sig_checks, handle_task_process_tx_result, handle_task_send_tx_result, handle_task_tx_sig_check_results, task_generate_hash, task_register_wallet, task_update_app_wallet_sol_balance, TaskSendTx
+    handle_task_got_sig_checks, handle_task_process_tx_result, handle_task_rpc_health_checks_result, handle_task_test_connection_result, RpcHealthCheckResult, TaskRpcHealthChecks, handle_task_send_tx_result, handle_task_tx_sig_check_results, handle_task_unlock_result, handle_task_vanity_search_result, handle_task_change_password_result, handle_task_export_wallet_result, handle_task_reveal_seed_result, handle_task_save_wallet_result, task_generate_hash, task_register_wallet, task_update_app_wallet_sol_balance, TaskSendTx
 };
 use ui::{
-    components::{AppScreenParent, BaseScreenNode, ButtonCaptureTextInput, DashboardProofUpdatesLogsList, DashboardScreenNode, MiningScreenNode, NavItem, NavItemArrow, NavItemIcon, NavItemText, NavItemWhiteSelectedBar, ScrollingList, SpinnerIcon, TextInput, TextPasswordInput}, nav_item_systems::nav_item_interactions, screens::{screen_base::spawn_base_screen, screen_dashboard::{despawn_dashboard_screen, spawn_dashboard_screen}, screen_locked::{despawn_locked_screen, spawn_locked_screen}, screen_mining::{despawn_mining_screen, spawn_app_screen_mining}, screen_settings_config::{despawn_settings_config_screen, spawn_settings_config_screen}, screen_settings_general::{despawn_settings_general_screen, spawn_settings_general_screen}, screen_settings_wallet::{despawn_settings_wallet_screen, spawn_settings_wallet_screen}, screen_setup_wallet::{despawn_wallet_create_screen, spawn_wallet_setup_screen}}, ui_button_systems::{
-        button_auto_scroll, button_capture_text, button_claim_ore_rewards, button_copy_text, button_generate_wallet, button_lock, button_open_web_tx_explorer, button_request_airdrop, button_save_config, button_save_wallet, button_stake_ore, button_start_stop_mining, button_unlock, tick_button_cooldowns
+    components::{AppScreenParent, BaseScreenNode, ButtonCaptureTextInput, DashboardProofUpdatesLogsList, DashboardScreenNode, MiningScreenNode, NavItem, NavItemArrow, NavItemIcon, NavItemText, NavItemWhiteSelectedBar, ScrollingList, SpinnerIcon, TextInput, TextMnemonicInput, TextPasswordInput}, nav_item_systems::nav_item_interactions, screens::{screen_base::spawn_base_screen, screen_dashboard::{despawn_dashboard_screen, spawn_dashboard_screen}, screen_locked::{despawn_locked_screen, spawn_locked_screen}, screen_mining::{despawn_mining_screen, spawn_app_screen_mining}, screen_settings_config::{despawn_settings_config_screen, spawn_settings_config_screen}, screen_settings_general::{despawn_settings_general_screen, spawn_settings_general_screen}, screen_settings_wallet::{despawn_settings_wallet_screen, spawn_settings_wallet_screen}, screen_setup_wallet::{despawn_wallet_create_screen, spawn_wallet_setup_screen}}, ui_button_systems::{
+        button_add_wallet, button_auto_scroll, button_capture_text, button_change_password, button_claim_ore_rewards, button_config_bus_selector, button_config_cluster, button_copy_text, button_derivation_path, button_export_wallet, button_forgot_password, button_generate_wallet, button_hide_seed, button_import_derived_address, button_lock, button_config_explorer, button_open_web_account_explorer, button_open_web_tx_explorer, button_remove_wallet, button_rename_wallet, button_request_airdrop, button_reveal_seed, button_save_config, button_save_wallet, button_stake_ore, button_dismiss_toast, button_start_stop_mining, button_switch_wallet, button_test_connection, button_toast_history, button_unlock, button_vanity_option, button_vanity_search, button_watch_pubkey, tick_button_cooldowns
     }, ui_sync_systems::{
-        fps_counter_showhide, fps_text_update_system, mouse_scroll, update_active_miners_ui, update_active_text_input_cursor_vis, update_app_wallet_ui, update_busses_ui, update_hash_rate_ui, update_miner_status_ui, update_proof_account_ui, update_text_input_ui, update_treasury_account_ui
+        fps_counter_showhide, fps_text_update_system, mouse_scroll, update_active_miners_ui, update_active_rpc_ui, update_active_text_input_cursor_vis, update_app_wallet_ui, update_bus_analytics_ui, update_bus_selector_ui, update_busses_ui, update_cluster_badge_ui, update_hash_rate_ui, update_miner_status_ui, update_mnemonic_import_ui, update_proof_account_ui, update_seed_verification_ui, update_settings_wallet_ui, update_text_input_ui, update_toast_history_ui, update_toasts, update_treasury_account_ui, update_unlock_status_ui, update_vanity_search_ui, update_watch_only_ui, update_ws_status_ui
     }
 };
 
@@ -34,21 +40,82 @@ pub const FAST_DURATION: Duration = Duration::from_millis(30);
 pub const REGULAR_DURATION: Duration = Duration::from_millis(100);
 pub const SLOW_DURATION: Duration = Duration::from_millis(1000);
 
+pub const RPC_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+pub const RPC_MAX_SLOT_LAG: u64 = 50;
+pub const RPC_MAX_ERROR_RATE: f64 = 0.5;
+pub const RPC_HEALTH_WINDOW: usize = 10;
+// a probe taking longer counts as a failed check
+pub const RPC_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+pub const UI_FETCH_MAX_BACKOFF: Duration = Duration::from_secs(60);
+pub const TOAST_HISTORY_LEN: usize = 50;
+
+pub mod error;
 pub mod events;
+pub mod ore_rpc;
 pub mod ore_utils;
+pub mod seed_phrase;
+pub mod subscriptions;
 pub mod tasks;
 pub mod ui;
 pub mod utils;
+pub mod vanity;
+pub mod vault;
+
+#[cfg(test)]
+mod tests;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct AppConfig {
     pub rpc_url: String,
     pub ws_url: String,
-    pub is_devnet: bool,
+    #[serde(default)]
+    pub cluster: Cluster,
     pub threads: u64,
     pub ui_fetch_interval: u64,
     pub tx_send_interval: u64,
     pub tx_sigs_check_interval: u64,
+    #[serde(default)]
+    pub bus_selector: BusSelector,
+    // tried in order after rpc_url when it becomes unhealthy
+    #[serde(default)]
+    pub backup_rpc_urls: Vec<String>,
+    #[serde(default)]
+    pub explorer: Explorer,
+    // used by Explorer::Custom, see Explorer::url for the placeholders
+    #[serde(default)]
+    pub explorer_custom_template: String,
+    // pbkdf2 rounds for save.data, unset uses vault::DEFAULT_KDF_ITERATIONS
+    #[serde(default)]
+    pub vault_kdf_iterations: Option<u32>,
+    // minutes without keyboard or mouse input before locking, 0 never locks
+    #[serde(default = "default_auto_lock_minutes")]
+    pub auto_lock_minutes: u64,
+}
+
+fn default_auto_lock_minutes() -> u64 {
+    15
+}
+
+impl AppConfig {
+    /// Parses config.toml. Configs from before cluster profiles have no
+    /// `cluster`, and their `is_devnet` was always written as true, so the
+    /// cluster is inferred from the rpc url instead.
+    pub fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+        let mut config: AppConfig = toml::from_str(text)?;
+        let has_cluster = text
+            .parse::<toml::Table>()
+            .map(|table| table.contains_key("cluster"))
+            .unwrap_or(false);
+        if !has_cluster {
+            config.cluster = Cluster::from_rpc_url(&config.rpc_url);
+            info!("config.toml has no cluster, using {} for {}", config.cluster.to_string(), config.rpc_url);
+        }
+        Ok(config)
+    }
+
+    pub fn explorer_url(&self, link: ExplorerLink) -> String {
+        self.explorer.url(link, self.cluster, &self.rpc_url, &self.explorer_custom_template)
+    }
 }
 
 impl Default for AppConfig {
@@ -56,11 +123,273 @@ impl Default for AppConfig {
         Self {
             rpc_url: "https://floral-dawn-pallet.solana-devnet.quiknode.pro/8b38be5427b44d3b42dc67c891dea71a56cd3a8c".to_string(),
             ws_url: "wss://floral-dawn-pallet.solana-devnet.quiknode.pro/8b38be5427b44d3b42dc67c891dea71a56cd3a8c".to_string(),
-            is_devnet: true,
+            cluster: Cluster::Devnet,
             threads: 1,
             ui_fetch_interval: 1000,
             tx_send_interval: 3000,
             tx_sigs_check_interval: 1000,
+            bus_selector: BusSelector::default(),
+            backup_rpc_urls: vec![],
+            explorer: Explorer::default(),
+            explorer_custom_template: String::new(),
+            vault_kdf_iterations: None,
+            auto_lock_minutes: default_auto_lock_minutes(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum BusSelector {
+    #[default]
+    MaxRewards,
+    WeightedRandom,
+    LeastRecentlyHit,
+    StickyUntilDepleted,
+}
+
+impl BusSelector {
+    pub fn next(&self) -> Self {
+        match self {
+            BusSelector::MaxRewards => BusSelector::WeightedRandom,
+            BusSelector::WeightedRandom => BusSelector::LeastRecentlyHit,
+            BusSelector::LeastRecentlyHit => BusSelector::StickyUntilDepleted,
+            BusSelector::StickyUntilDepleted => BusSelector::MaxRewards,
+        }
+    }
+}
+
+impl ToString for BusSelector {
+    fn to_string(&self) -> String {
+        match self {
+            BusSelector::MaxRewards => {
+                "Max Rewards".to_string()
+            },
+            BusSelector::WeightedRandom => {
+                "Weighted Random".to_string()
+            },
+            BusSelector::LeastRecentlyHit => {
+                "Least Recently Hit".to_string()
+            },
+            BusSelector::StickyUntilDepleted => {
+                "Sticky Until Depleted".to_string()
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Cluster {
+    Mainnet,
+    #[default]
+    Devnet,
+    Localnet,
+    Custom,
+}
+
+impl Cluster {
+    pub fn next(&self) -> Self {
+        match self {
+            Cluster::Mainnet => Cluster::Devnet,
+            Cluster::Devnet => Cluster::Localnet,
+            Cluster::Localnet => Cluster::Custom,
+            Cluster::Custom => Cluster::Mainnet,
+        }
+    }
+
+    /// Best guess from the rpc host. Unknown providers are Custom, which has
+    /// no airdrop and points explorers at the rpc url itself.
+    pub fn from_rpc_url(rpc_url: &str) -> Self {
+        let rpc_url = rpc_url.trim().to_lowercase();
+        let rest = rpc_url.split_once("://").map(|(_, rest)| rest).unwrap_or(&rpc_url);
+        let host = rest.split(['/', '?']).next().unwrap_or_default();
+        let host = host.rsplit('@').next().unwrap_or_default();
+        let host = host.split(':').next().unwrap_or_default();
+
+        if host == "localhost" || host == "127.0.0.1" || host == "0.0.0.0" {
+            Cluster::Localnet
+        } else if host.contains("devnet") {
+            Cluster::Devnet
+        } else if host.contains("mainnet") {
+            Cluster::Mainnet
+        } else {
+            Cluster::Custom
+        }
+    }
+
+    pub fn default_rpc_url(&self) -> Option<&'static str> {
+        match self {
+            Cluster::Mainnet => Some("https://api.mainnet-beta.solana.com"),
+            Cluster::Devnet => Some("https://api.devnet.solana.com"),
+            Cluster::Localnet => Some("http://127.0.0.1:8899"),
+            Cluster::Custom => None,
+        }
+    }
+
+    /// Only set where the websocket can't be derived from the rpc url.
+    pub fn default_ws_url(&self) -> Option<&'static str> {
+        match self {
+            Cluster::Localnet => Some("ws://127.0.0.1:8900"),
+            _ => None,
+        }
+    }
+
+    pub fn airdrop_url(&self) -> Option<&'static str> {
+        match self {
+            Cluster::Devnet => Some("https://api.devnet.solana.com"),
+            Cluster::Localnet => Some("http://127.0.0.1:8899"),
+            Cluster::Mainnet | Cluster::Custom => None,
+        }
+    }
+
+    pub fn genesis_hash(&self) -> Option<&'static str> {
+        match self {
+            Cluster::Mainnet => Some("5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dW2N9d"),
+            Cluster::Devnet => Some("EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG"),
+            Cluster::Localnet | Cluster::Custom => None,
+        }
+    }
+
+    /// The ore program expected on the cluster. Instructions, PDAs and
+    /// subscriptions all come from ore_api, so a custom cluster has to run
+    /// the deployment this build was compiled against.
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            Cluster::Mainnet => ore_api::ID,
+            // deployed with the same program keypair as mainnet
+            Cluster::Devnet => ore_api::ID,
+            // solana-test-validator loads the program at the build's address
+            Cluster::Localnet | Cluster::Custom => ore_api::ID,
+        }
+    }
+
+    /// Value for the `cluster` query param understood by the block explorers,
+    /// None for mainnet.
+    pub fn explorer_cluster_param(&self, rpc_url: &str) -> Option<String> {
+        match self {
+            Cluster::Mainnet => None,
+            Cluster::Devnet => Some("devnet".to_string()),
+            Cluster::Localnet | Cluster::Custom => {
+                Some(format!("custom&customUrl={}", url_encode(rpc_url)))
+            },
+        }
+    }
+}
+
+impl ToString for Cluster {
+    fn to_string(&self) -> String {
+        match self {
+            Cluster::Mainnet => {
+                "Mainnet".to_string()
+            },
+            Cluster::Devnet => {
+                "Devnet".to_string()
+            },
+            Cluster::Localnet => {
+                "Localnet".to_string()
+            },
+            Cluster::Custom => {
+                "Custom".to_string()
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Explorer {
+    #[default]
+    Solscan,
+    SolanaExplorer,
+    SolanaFm,
+    Custom,
+}
+
+pub enum ExplorerLink<'a> {
+    Tx(&'a str),
+    Account(&'a str),
+}
+
+impl Explorer {
+    pub fn next(&self) -> Self {
+        match self {
+            Explorer::Solscan => Explorer::SolanaExplorer,
+            Explorer::SolanaExplorer => Explorer::SolanaFm,
+            Explorer::SolanaFm => Explorer::Custom,
+            Explorer::Custom => Explorer::Solscan,
+        }
+    }
+
+    /// A template without `{id}` would link every tx and account to the same page.
+    pub fn is_valid_custom_template(template: &str) -> bool {
+        template.contains("{id}")
+    }
+
+    /// Custom templates replace `{type}` with `tx` or `account`, `{id}` with
+    /// the signature or address and `{cluster}` with the cluster param.
+    pub fn url(&self, link: ExplorerLink, cluster: Cluster, rpc_url: &str, custom_template: &str) -> String {
+        let (is_tx, id) = match link {
+            ExplorerLink::Tx(sig) => (true, sig),
+            ExplorerLink::Account(address) => (false, address),
+        };
+        // config.toml can still be edited by hand
+        let explorer = match self {
+            Explorer::Custom if !Explorer::is_valid_custom_template(custom_template) => Explorer::Solscan,
+            explorer => *explorer,
+        };
+        let cluster_param = match explorer {
+            Explorer::SolanaFm => match cluster {
+                Cluster::Mainnet => None,
+                Cluster::Devnet => Some("devnet-alpha".to_string()),
+                Cluster::Localnet => Some("localnet-solana".to_string()),
+                // solana.fm can't point at an arbitrary rpc
+                Cluster::Custom => None,
+            },
+            _ => cluster.explorer_cluster_param(rpc_url),
+        };
+
+        let base = match explorer {
+            Explorer::Solscan => {
+                format!("https://solscan.io/{}/{}", if is_tx { "tx" } else { "account" }, id)
+            },
+            Explorer::SolanaExplorer => {
+                format!("https://explorer.solana.com/{}/{}", if is_tx { "tx" } else { "address" }, id)
+            },
+            Explorer::SolanaFm => {
+                format!("https://solana.fm/{}/{}", if is_tx { "tx" } else { "address" }, id)
+            },
+            Explorer::Custom => {
+                return custom_template
+                    .replace("{type}", if is_tx { "tx" } else { "account" })
+                    .replace("{id}", id)
+                    .replace("{cluster}", &cluster_param.unwrap_or_default());
+            },
+        };
+
+        if let Some(cluster_param) = cluster_param {
+            format!("{}?cluster={}", base, cluster_param)
+        } else {
+            base
+        }
+    }
+}
+
+impl ToString for Explorer {
+    fn to_string(&self) -> String {
+        match self {
+            Explorer::Solscan => {
+                "Solscan".to_string()
+            },
+            Explorer::SolanaExplorer => {
+                "Solana Explorer".to_string()
+            },
+            Explorer::SolanaFm => {
+                "SolanaFM".to_string()
+            },
+            Explorer::Custom => {
+                "Custom".to_string()
+            },
         }
     }
 }
@@ -90,7 +419,7 @@ fn main() {
     let config_path = Path::new("config.toml");
     let config: AppConfig = if config_path.exists() {
         let config_string = fs::read_to_string(config_path).unwrap();
-        let config = match toml::from_str(&config_string) {
+        let config = match AppConfig::from_toml(&config_string) {
             Ok(d) => {
                 starting_state = AppScreenState::WalletSetup;
                 Some(d)
@@ -103,16 +432,15 @@ fn main() {
     };
 
     if starting_state == AppScreenState::WalletSetup {
-        let wallet_path = Path::new("save.data");
+        let wallet_path = Path::new(VAULT_PATH);
         if wallet_path.exists() {
             starting_state = AppScreenState::Unlock;
         }
     }
 
     // let tx_send_interval = config.tx_send_interval;
-    let threads = config.threads;
-    App::new()
-        .insert_state(starting_state)
+    let mut app = App::new();
+    app.insert_state(starting_state)
         .add_plugins(
             DefaultPlugins
                 .set(WindowPlugin {
@@ -131,17 +459,193 @@ fn main() {
         .insert_resource(WinitSettings {
             focused_mode: bevy::winit::UpdateMode::ReactiveLowPower { wait: REGULAR_DURATION },
             unfocused_mode: bevy::winit::UpdateMode::ReactiveLowPower { wait: REGULAR_DURATION },
-        })
+        });
+    insert_app_resources(&mut app, &config);
+    add_app_events(&mut app);
+    add_app_systems(&mut app);
+
+    app
+        .add_systems(Startup, setup_base_screen)
+        .add_systems(Update, fps_text_update_system)
+        .add_systems(Update, fps_counter_showhide)
+        .add_systems(Update, text_input)
+        .add_systems(Update, update_text_input_ui)
+        .add_systems(Update, button_capture_text)
+        .add_systems(Update, update_active_text_input_cursor_vis)
+        .add_systems(Update, tick_button_cooldowns)
+        .add_systems(Update, nav_item_interactions)
+        .add_systems(Update, update_app_wallet_ui)
+        .add_systems(Update, (update_active_rpc_ui, update_ws_status_ui, update_cluster_badge_ui))
+        .add_systems(Update, (handle_event_toast, update_toasts, update_toast_history_ui, button_toast_history, button_dismiss_toast))
+        // account links live in the header, mining and dashboard screens
+        .add_systems(Update, button_open_web_account_explorer)
+        .add_systems(Update, mouse_scroll)
+        .add_systems(Update, dashboard_list_cleanup_system)
+        // watch-only mode runs these with just a pubkey
+        .add_systems(Update,
+            (
+                spin_spinner_icons,
+                update_busses_ui,
+                update_bus_analytics_ui,
+                update_treasury_account_ui,
+                update_watch_only_ui,
+                // adds to the dashboard's proof log
+                handle_event_proof_account_updated,
+            ).run_if(run_if_has_some_pubkey)
+        )
+        // auto lock reads window input, the other signing systems are in add_app_systems
+        .add_systems(Update, (button_start_stop_mining, auto_lock_when_idle).run_if(run_if_has_some_wallet))
+        .add_systems(OnEnter(AppScreenState::SettingsConfig), setup_settings_config_screen)
+        .add_systems(
+            OnExit(AppScreenState::SettingsConfig),
+            (
+                despawn_settings_config_screen,
+            )
+        )
+        .add_systems(OnEnter(AppScreenState::SettingsGeneral), setup_settings_general_screen)
+        .add_systems(
+            OnExit(AppScreenState::SettingsGeneral),
+            (
+                despawn_settings_general_screen,
+            )
+        )
+        .add_systems(OnEnter(AppScreenState::SettingsWallet), setup_settings_wallet_screen)
+        .add_systems(
+            OnExit(AppScreenState::SettingsWallet),
+            (
+                despawn_settings_wallet_screen,
+            )
+        )
+        .add_systems(OnEnter(AppScreenState::WalletSetup), setup_wallet_create_screen)
+        .add_systems(OnExit(AppScreenState::WalletSetup), despawn_wallet_create_screen)
+        // .add_systems(OnExit(GameState::WalletSetup), despawn_wallet_setup_screen)
+        .add_systems(OnEnter(AppScreenState::Dashboard), setup_dashboard_screen)
+        .add_systems(OnExit(AppScreenState::Dashboard), hide_dashboard_screen)
+        .add_systems(OnEnter(AppScreenState::Unlock), setup_locked_screen)
+        .add_systems(OnExit(AppScreenState::Unlock), despawn_locked_screen)
+        .add_systems(OnEnter(AppScreenState::Mining), setup_mining_screen)
+        .add_systems(OnExit(AppScreenState::Mining), hide_mining_screen)
+        .add_systems(
+            Update,
+            (
+                button_save_config,
+                button_config_bus_selector,
+                button_config_cluster,
+                button_config_explorer,
+                button_test_connection,
+            )
+                .run_if(in_state(AppScreenState::SettingsConfig)),
+        )
+        .add_systems(
+            Update,
+            (
+                (
+                    button_generate_wallet,
+                    button_save_wallet,
+                    button_derivation_path,
+                    button_import_derived_address,
+                    button_vanity_option,
+                    button_vanity_search,
+                ),
+                (
+                    text_password_input,
+                    file_drop,
+                    text_mnemonic_autocomplete,
+                    update_mnemonic_import_ui,
+                    update_seed_verification_ui,
+                    update_vanity_search_ui,
+                ),
+            )
+                .run_if(in_state(AppScreenState::WalletSetup)),
+        )
+        .add_systems(
+            Update,
+            (
+                (
+                    button_add_wallet,
+                    button_rename_wallet,
+                    button_switch_wallet,
+                    button_remove_wallet,
+                    button_reveal_seed,
+                    button_hide_seed,
+                    button_change_password,
+                    button_export_wallet,
+                ),
+                update_settings_wallet_ui,
+            )
+                .run_if(in_state(AppScreenState::SettingsWallet)),
+        )
+        .add_systems(
+            Update,
+            (
+                button_unlock,
+                button_forgot_password,
+                text_password_input,
+                update_unlock_status_ui,
+            )
+                .run_if(in_state(AppScreenState::Unlock)),
+        )
+        .add_systems(
+            Update,
+            button_watch_pubkey
+                .run_if(in_state(AppScreenState::Unlock).or_else(in_state(AppScreenState::WalletSetup))),
+        )
+        .add_systems(
+            Update,
+            (update_active_miners_ui)
+                .run_if(in_state(AppScreenState::Dashboard)),
+        )
+        .add_systems(
+            Update,
+            (
+                // individual tuple max size is 12
+                (
+                    button_lock,
+                    button_copy_text,
+                    button_auto_scroll,
+                    button_open_web_tx_explorer,
+                ),
+                (
+                    update_proof_account_ui,
+                    update_miner_status_ui,
+                    update_hash_rate_ui,
+                    update_bus_selector_ui,
+                ),
+            )
+                .run_if(is_mining_screen_with_some_pubkey),
+        )
+        .add_systems(
+            Update,
+            (
+                button_claim_ore_rewards,
+                button_stake_ore,
+                button_request_airdrop,
+            )
+                .run_if(is_mining_screen_with_some_wallet),
+        )
+        .run();
+}
+
+/// Resources shared by the app and the headless test harness.
+pub fn insert_app_resources(app: &mut App, config: &AppConfig) {
+    app
         .insert_resource(OreAppState {
             config: config.clone(),
             active_input_node: None,
         })
         .insert_resource(MinerStatusResource {
-            miner_threads: threads,
+            miner_threads: config.threads,
             ..Default::default()
         })
         .insert_resource(RpcConnection {
             rpc: None,
+            airdrop: None,
+            endpoints: vec![],
+            active_endpoint: 0,
+            shared_active_endpoint: Arc::new(AtomicUsize::new(0)),
+            healthy_endpoints: Arc::new(vec![]),
+            call_results: None,
+            fetch_ui_data_failures: 0,
             fetch_ui_data_timer: Timer::new(
                 Duration::from_millis(config.ui_fetch_interval),
                 TimerMode::Once,
@@ -149,13 +653,22 @@ fn main() {
         })
         .insert_resource(AppWallet {
             wallet: None,
+            watch_only: None,
             sol_balance: 0.0,
             ore_balance: 0.0,
         })
         .insert_resource(BussesResource {
             busses: vec![],
             current_bus_id: 0,
+            last_hit_at: HashMap::new(),
         })
+        .init_resource::<BusAnalyticsResource>()
+        .init_resource::<WsStatusResource>()
+        .init_resource::<ToastHistoryResource>()
+        .insert_resource(WalletVaultResource::default().with_kdf_iterations(config.vault_kdf_iterations))
+        .insert_resource(AutoLockResource::new(config.auto_lock_minutes))
+        .init_resource::<UnlockAttempts>()
+        .init_resource::<VanitySearchResource>()
         .insert_resource(HashrateResource {
             hashrate: 0.0,
         })
@@ -172,59 +685,42 @@ fn main() {
         .init_resource::<ProofAccountResource>()
         .register_type::<ProofAccountResource>()
         .init_resource::<TreasuryAccountResource>()
-        .register_type::<TreasuryAccountResource>()
-        .add_event::<EventStartStopMining>()
-        .add_event::<EventSubmitHashTx>()
-        .add_event::<EventTxResult>()
-        .add_event::<EventFetchUiDataFromRpc>()
-        .add_event::<EventMineForHash>()
-        .add_event::<EventRegisterWallet>()
-        .add_event::<EventProcessTx>()
-        .add_event::<EventClaimOreRewards>()
-        .add_event::<EventStakeOre>()
-        .add_event::<EventUnlock>()
-        .add_event::<EventLock>()
-        .add_event::<EventSaveConfig>()
-        .add_event::<EventGenerateWallet>()
-        .add_event::<EventSaveWallet>()
-        .add_event::<EventLoadKeypairFile>()
-        .add_event::<EventRequestAirdrop>()
-        .add_event::<EventCheckSigs>()
-        .add_event::<EventProofAccountUpdated>()
-        .add_event::<EventCancelMining>()
-        .add_systems(Startup, setup_base_screen)
-        .add_systems(Update, fps_text_update_system)
-        .add_systems(Update, fps_counter_showhide)
-        .add_systems(Update, text_input)
-        .add_systems(Update, update_text_input_ui)
-        .add_systems(Update, button_capture_text)
-        .add_systems(Update, update_active_text_input_cursor_vis)
-        .add_systems(Update, tick_button_cooldowns)
-        .add_systems(Update, nav_item_interactions)
-        .add_systems(Update, update_app_wallet_ui)
-        .add_systems(Update, mouse_scroll)
-        .add_systems(Update, dashboard_list_cleanup_system)
-        .add_systems(Update, 
+        .register_type::<TreasuryAccountResource>();
+}
+
+/// Event handlers, tasks and rpc systems shared by the app and the headless
+/// test harness. Buttons and ui updates are added in `main`.
+pub fn add_app_systems(app: &mut App) {
+    app
+        // watch-only mode runs these with just a pubkey
+        .add_systems(
+            Update,
+            (
+                handle_event_fetch_ui_data_from_rpc,
+                handle_event_lock,
+                task_update_app_wallet_sol_balance,
+                read_accounts_update_channel,
+                trigger_rpc_calls_for_ui,
+                rpc_health_checks,
+                handle_task_rpc_health_checks_result,
+                read_rpc_call_results,
+            )
+                .run_if(run_if_has_some_pubkey),
+        )
+        // everything that signs
+        .add_systems(
+            Update,
             (
-                (
-                    button_start_stop_mining,
-                    spin_spinner_icons,
-                    update_busses_ui,
-                    update_treasury_account_ui,
-                ),
                 (
                     handle_event_start_stop_mining_clicked,
                     handle_event_submit_hash_tx,
                     handle_event_tx_result,
-                    handle_event_fetch_ui_data_from_rpc,
                     handle_event_register_wallet,
                     handle_event_mine_for_hash,
                     handle_event_check_sigs,
-                    handle_event_proof_account_updated,
                     handle_event_cancel_mining,
                 ),
                 (
-                    task_update_app_wallet_sol_balance,
                     task_generate_hash,
                     task_register_wallet,
                     handle_task_process_tx_result,
@@ -236,110 +732,109 @@ fn main() {
                     tx_processor_result_checks,
                     tx_processors_send,
                     tx_processors_sigs_check,
-                    read_accounts_update_channel,
-                )
-            ).run_if(run_if_has_some_wallet)
-        )
-        .add_systems(OnEnter(AppScreenState::SettingsConfig), setup_settings_config_screen)
-        .add_systems(
-            OnExit(AppScreenState::SettingsConfig),
-            (
-                despawn_settings_config_screen,
-            )
-        )
-        .add_systems(OnEnter(AppScreenState::SettingsGeneral), setup_settings_general_screen)
-        .add_systems(
-            OnExit(AppScreenState::SettingsGeneral),
-            (
-                despawn_settings_general_screen,
-            )
-        )
-        .add_systems(OnEnter(AppScreenState::SettingsWallet), setup_settings_wallet_screen)
-        .add_systems(
-            OnExit(AppScreenState::SettingsWallet),
-            (
-                despawn_settings_wallet_screen,
-            )
-        )
-        .add_systems(OnEnter(AppScreenState::WalletSetup), setup_wallet_create_screen)
-        .add_systems(
-            OnExit(AppScreenState::WalletSetup),
-            (
-                despawn_wallet_create_screen,
-            )
-        )
-        // .add_systems(OnExit(GameState::WalletSetup), despawn_wallet_setup_screen)
-        .add_systems(OnEnter(AppScreenState::Dashboard), setup_dashboard_screen)
-        .add_systems(OnExit(AppScreenState::Dashboard), hide_dashboard_screen)
-        .add_systems(OnEnter(AppScreenState::Unlock), setup_locked_screen)
-        .add_systems(OnExit(AppScreenState::Unlock), despawn_locked_screen)
-        .add_systems(OnEnter(AppScreenState::Mining), setup_mining_screen)
-        .add_systems(OnExit(AppScreenState::Mining), hide_mining_screen)
-        .add_systems(
-            Update,
-            (
-                button_save_config,
-                handle_event_save_config,
+                ),
             )
-                .run_if(in_state(AppScreenState::SettingsConfig)),
+                .run_if(run_if_has_some_wallet),
         )
         .add_systems(
             Update,
             (
-                (
-                    button_generate_wallet,
-                    button_save_wallet,
-                ),
-                (
-                    handle_event_generate_wallet,
-                    handle_event_save_wallet,
-                    handle_event_load_keypair_file,
-                ),
-                (
-                    text_password_input,
-                    file_drop,
-                ),
+                handle_event_claim_ore_rewards,
+                handle_event_stake_ore,
+                handle_event_request_airdrop,
             )
-                .run_if(in_state(AppScreenState::WalletSetup)),
+                .run_if(is_mining_screen_with_some_wallet),
         )
         .add_systems(
             Update,
-            (button_unlock, handle_event_unlock, text_password_input)
-                .run_if(in_state(AppScreenState::Unlock)),
+            (
+                handle_event_save_config,
+                handle_event_test_connection,
+                handle_task_test_connection_result,
+            )
+                .run_if(in_state(AppScreenState::SettingsConfig)),
         )
         .add_systems(
             Update,
-            (update_active_miners_ui)
-                .run_if(in_state(AppScreenState::Dashboard)),
+            (
+                handle_event_generate_wallet,
+                handle_event_save_wallet,
+                handle_event_load_keypair_file,
+                handle_event_import_mnemonic,
+                handle_event_start_vanity_search,
+                handle_event_stop_vanity_search,
+                handle_task_vanity_search_result,
+                handle_task_save_wallet_result,
+            )
+                .run_if(in_state(AppScreenState::WalletSetup)),
         )
+        .add_systems(OnExit(AppScreenState::WalletSetup), stop_vanity_search)
         .add_systems(
             Update,
             (
-                // individual tuple max size is 12
-                (
-                    button_lock,
-                    button_copy_text,
-                    button_claim_ore_rewards,
-                    button_stake_ore,
-                    button_auto_scroll,
-                    button_open_web_tx_explorer,
-                    button_request_airdrop
-                ),
                 (
-                    handle_event_claim_ore_rewards,
-                    handle_event_stake_ore,
-                    handle_event_lock,
-                    handle_event_request_airdrop,
+                    handle_event_add_wallet,
+                    handle_event_rename_wallet,
+                    handle_event_remove_wallet,
+                    handle_event_switch_wallet,
+                    handle_event_reveal_seed,
+                    handle_event_change_password,
+                    handle_event_export_wallet,
                 ),
                 (
-                    update_proof_account_ui,
-                    update_miner_status_ui,
-                    update_hash_rate_ui,
+                    handle_task_change_password_result,
+                    handle_task_export_wallet_result,
+                    handle_task_reveal_seed_result,
                 ),
             )
-                .run_if(is_mining_screen_with_some_wallet),
+                .run_if(in_state(AppScreenState::SettingsWallet)),
         )
-        .run();
+        .add_systems(
+            Update,
+            (handle_event_unlock, handle_task_unlock_result)
+                .run_if(in_state(AppScreenState::Unlock)),
+        )
+        .add_systems(
+            Update,
+            handle_event_watch_pubkey
+                .run_if(in_state(AppScreenState::Unlock).or_else(in_state(AppScreenState::WalletSetup))),
+        );
+}
+
+pub fn add_app_events(app: &mut App) {
+    app
+        .add_event::<EventStartStopMining>()
+        .add_event::<EventSubmitHashTx>()
+        .add_event::<EventTxResult>()
+        .add_event::<EventFetchUiDataFromRpc>()
+        .add_event::<EventMineForHash>()
+        .add_event::<EventRegisterWallet>()
+        .add_event::<EventProcessTx>()
+        .add_event::<EventClaimOreRewards>()
+        .add_event::<EventStakeOre>()
+        .add_event::<EventUnlock>()
+        .add_event::<EventLock>()
+        .add_event::<EventSaveConfig>()
+        .add_event::<EventTestConnection>()
+        .add_event::<EventGenerateWallet>()
+        .add_event::<EventSaveWallet>()
+        .add_event::<EventLoadKeypairFile>()
+        .add_event::<EventImportMnemonic>()
+        .add_event::<EventStartVanitySearch>()
+        .add_event::<EventStopVanitySearch>()
+        .add_event::<EventRequestAirdrop>()
+        .add_event::<EventCheckSigs>()
+        .add_event::<EventProofAccountUpdated>()
+        .add_event::<EventCancelMining>()
+        .add_event::<EventToast>()
+        .add_event::<EventAddWallet>()
+        .add_event::<EventRenameWallet>()
+        .add_event::<EventRemoveWallet>()
+        .add_event::<EventSwitchWallet>()
+        .add_event::<EventRevealSeed>()
+        .add_event::<EventChangePassword>()
+        .add_event::<EventExportWallet>()
+        .add_event::<EventWatchPubkey>();
 }
 
 fn setup_base_screen(
@@ -365,6 +860,7 @@ fn setup_mining_screen(
     app_state: Res<OreAppState>,
     app_wallet: Res<AppWallet>,
     mut rpc_connection: ResMut<RpcConnection>,
+    account_subscriptions: Option<Res<AccountSubscriptions>>,
     query: Query<Entity, With<AppScreenParent>>,
     mut query_mining_screen: Query<(Entity, &mut Visibility), (With<MiningScreenNode>, Without<AppScreenParent>)>,
     mut event_writer: EventWriter<EventFetchUiDataFromRpc>,
@@ -384,135 +880,14 @@ fn setup_mining_screen(
         *visibility = Visibility::Visible;
     } else {
 
-        if let Some(wallet) = &app_wallet.wallet {
+        if app_wallet.pubkey().is_some() {
             let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
             parent.with_children(|parent| {
                 spawn_app_screen_mining(parent, &asset_server);
             });
-            if rpc_connection.rpc.is_none() {
-                let new_rpc_connection = Arc::new(RpcClient::new_with_commitment(
-                    config.rpc_url.clone(),
-                    CommitmentConfig::confirmed(),
-                ));
-                rpc_connection.rpc = Some(new_rpc_connection);
-                let task_pool = IoTaskPool::get();
-
-                let (sender, receiver) = unbounded::<AccountUpdatesData>();
-
-                let account_update_channel = AccountUpdatesChannel {
-                    channel: receiver.clone(),
-                };
-
-
-                // TODO: use an entity here, we need to unsubscribe and cleanup this task when switching screens.
-                commands.insert_resource(account_update_channel);
-
-                let wallet_pubkey = wallet.pubkey().clone();
-
-                info!("Wallet Pubkey: {}", wallet_pubkey);
-
-                let ws_url = config.ws_url.clone();
-
-                task_pool.spawn(Compat::new(async move {
-                    let sender = sender.clone();
-
-                    let mut ps_client = PubsubClient::new(&ws_url).await;
-                    let mut attempts = 0;
-                    
-                    while ps_client.is_err() && attempts < 3 {
-                        error!("Failed to connect to websocket, retrying...");
-                        ps_client = PubsubClient::new(&ws_url).await;
-                        sleep(Duration::from_millis(1000)).await;
-                        attempts += 1;
-                    }
-
-                    if let Ok(ps_client) = ps_client {
-                        let ps_client = Arc::new(ps_client);
-                        let sender_c = sender.clone();
-                        let ps_client_c = ps_client.clone();
-                        task_pool.spawn(async move {
-                            let ps_client = ps_client_c;
-                            let sender = sender_c;
-                            let account_pubkey = ore_api::ID;
-                            let pubsub =
-                                ps_client.program_subscribe(
-                                    &account_pubkey,
-                                    Some(RpcProgramAccountsConfig {
-                                        //filters: Some(vec![RpcFilterType::DataSize(32)]),
-                                        filters: None,
-                                        account_config: RpcAccountInfoConfig {
-                                            encoding: Some(UiAccountEncoding::Base64),
-                                            data_slice: None,
-                                            commitment: Some(CommitmentConfig::confirmed()),
-                                            min_context_slot: None,
-                                        },
-                                        with_context: None,
-                                    })
-                                ).await;
-
-                                if let Ok((mut account_sub_notifications, _account_unsub)) = pubsub {
-                                    loop {
-                                        if let Some(response) = account_sub_notifications.next().await {
-
-                                            let data = response.value.account.data.decode();
-                                            if let Some(data_bytes) = data {
-                                                if let Ok(bus) = Bus::try_from_bytes(&data_bytes) {
-                                                    let _ = sender.send(AccountUpdatesData::BusData(*bus));
-                                                }
-                                                if let Ok(ore_config) = ore_api::state::Config::try_from_bytes(&data_bytes) {
-                                                    let _ = sender.send(AccountUpdatesData::TreasuryConfigData(*ore_config));
-                                                }
-                                                if let Ok(proof) = Proof::try_from_bytes(&data_bytes) {
-                                                    let _ = sender.send(AccountUpdatesData::ProofData(*proof));
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }).detach();
-
-                        let sender_c = sender.clone();
-                        let ps_client_c = ps_client.clone();
-                        task_pool.spawn(async move {
-                            let ps_client = ps_client_c;
-                            let sender = sender_c;
-                            let account_pubkey = ore_api::consts::TREASURY_TOKENS_ADDRESS;
-                            let pubsub = ps_client.account_subscribe(
-                                &account_pubkey,
-                            Some(
-                                    RpcAccountInfoConfig {
-                                            encoding: Some(UiAccountEncoding::Base64),
-                                            data_slice: None,
-                                            commitment: Some(CommitmentConfig::confirmed()),
-                                            min_context_slot: None,
-                                    }
-                                )).await;
-
-                                if let Ok((mut account_sub_notifications, _account_unsub)) = pubsub {
-                                    loop {
-                                        if let Some(response) = account_sub_notifications.next().await {
-
-                                            let data = response.value.data.decode();
-                                            if let Some(data_bytes) = data {
-                                                if let Ok(token_account) = spl_token::state::Account::unpack(&data_bytes) {
-                                                    let _ = sender.send(AccountUpdatesData::TreasuryBalanceData(token_account.amount));
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }).detach();
-                    } else {
-                        error!("Failed to connect to pubsub client via wss");
-                    }
-                })).detach();
-
-
-                event_writer.send(EventFetchUiDataFromRpc);
-            }
         } else {
 
-            let wallet_path = Path::new("save.data");
+            let wallet_path = Path::new(VAULT_PATH);
             if wallet_path.exists() {
                 next_state.set(AppScreenState::Unlock);
             } else {
@@ -521,6 +896,17 @@ fn setup_mining_screen(
         }
     }
 
+    // subscriptions are torn down on lock and config save, restart them with the current config
+    if let Some(pubkey) = app_wallet.pubkey() {
+        if account_subscriptions.is_none() {
+            rpc_connection.connect(config);
+            info!("Wallet Pubkey: {}", pubkey);
+
+            commands.insert_resource(AccountSubscriptions::start(config.ws_url.clone()));
+            event_writer.send(EventFetchUiDataFromRpc);
+        }
+    }
+
     // Update Nav Items Highlights
     for (mut visibility, nav_item_screen) in set.p0().iter_mut() {
         if nav_item_screen.0 == NavItemScreen::Mining {
@@ -799,14 +1185,27 @@ fn setup_locked_screen(
     app_state: Res<OreAppState>,
     mut event_writer: EventWriter<EventFetchUiDataFromRpc>,
     query: Query<Entity, With<AppScreenParent>>,
+    vault_res: Res<WalletVaultResource>,
+    mut attempts: ResMut<UnlockAttempts>,
 ) {
 
     let base_screen_entity_id = query.get_single().unwrap();
 
+    // failed attempts from before a restart still count
+    *attempts = UnlockAttempts::load(&vault_res.attempts_path());
+
+    let pubkey_hint = match VaultHeader::read(&vault_res.path) {
+        Ok(header) => header.and_then(|header| header.pubkey_hint),
+        Err(e) => {
+            error!("Failed to read wallet file header: {}", e);
+            None
+        },
+    };
+
     let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
 
     parent.with_children(|parent| {
-        spawn_locked_screen(parent, asset_server);
+        spawn_locked_screen(parent, asset_server, pubkey_hint);
     });
 }
 
@@ -817,12 +1216,58 @@ fn is_mining_screen_with_some_wallet(
     *app_screen_state == AppScreenState::Mining && app_wallet.wallet.is_some()
 }
 
+fn is_mining_screen_with_some_pubkey(
+    app_wallet: Res<AppWallet>,
+    app_screen_state: Res<State<AppScreenState>>,
+) -> bool {
+    *app_screen_state == AppScreenState::Mining && app_wallet.pubkey().is_some()
+}
+
 fn run_if_has_some_wallet(
     app_wallet: Res<AppWallet>,
 ) -> bool {
     app_wallet.wallet.is_some()
 }
 
+fn run_if_has_some_pubkey(
+    app_wallet: Res<AppWallet>,
+) -> bool {
+    app_wallet.pubkey().is_some()
+}
+
+/// Sends `EventLock` once there has been no keyboard or mouse input for the
+/// configured timeout.
+pub fn auto_lock_when_idle(
+    time: Res<Time>,
+    mut auto_lock: ResMut<AutoLockResource>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut mouse_buttons: EventReader<MouseButtonInput>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut event_writer_lock: EventWriter<EventLock>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    // read every reader so old input isn't counted next frame
+    let inputs = keyboard.read().count()
+        + mouse_buttons.read().count()
+        + mouse_wheel.read().count()
+        + cursor_moved.read().count();
+    if inputs > 0 {
+        auto_lock.idle = Duration::ZERO;
+        return;
+    }
+
+    if let Some(timeout) = auto_lock.timeout {
+        auto_lock.idle += time.delta();
+        if auto_lock.idle >= timeout {
+            auto_lock.idle = Duration::ZERO;
+            info!("No input for {:?}, locking the wallet", timeout);
+            event_writer_lock.send(EventLock);
+            event_writer_toast.send(EventToast::info("Wallet locked after inactivity."));
+        }
+    }
+}
+
 // Components
 #[derive(Component)]
 pub struct EntityTaskHandler;
@@ -894,10 +1339,19 @@ pub struct EntityTaskFetchUiData;
 #[derive(Resource)]
 pub struct AppWallet {
     wallet: Option<Arc<Keypair>>,
+    // tracked without a keypair, nothing is signed
+    watch_only: Option<Pubkey>,
     sol_balance: f64,
     ore_balance: f64,
 }
 
+impl AppWallet {
+    /// The unlocked keypair's pubkey, or the watched one.
+    pub fn pubkey(&self) -> Option<Pubkey> {
+        self.wallet.as_ref().map(|wallet| wallet.pubkey()).or(self.watch_only)
+    }
+}
+
 #[derive(Reflect, Resource, InspectorOptions)]
 #[reflect(Resource, InspectorOptions)]
 pub struct ProofAccountResource {
@@ -923,10 +1377,91 @@ pub struct HashrateResource {
     hashrate: f64,
 }
 
+pub struct ToastEntry {
+    pub severity: ToastSeverity,
+    pub message: String,
+    pub created_at: u64,
+}
+
+/// Every toast shown this session, newest last, for the history drawer.
+#[derive(Resource, Default)]
+pub struct ToastHistoryResource {
+    pub entries: VecDeque<ToastEntry>,
+    pub unread: usize,
+}
+
 #[derive(Resource)]
 pub struct BussesResource {
     busses: Vec<ore_api::state::Bus>,
     current_bus_id: usize,
+    // bus id -> last time the websocket showed its rewards being drained
+    last_hit_at: HashMap<u64, Instant>,
+}
+
+#[derive(Default, Clone, Copy)]
+pub struct BusStats {
+    pub hits: u64,
+    pub rewards_drained: u64,
+}
+
+/// Per-bus hits and drained rewards seen on the websocket for the current epoch.
+#[derive(Resource, Default)]
+pub struct BusAnalyticsResource {
+    /// Unix time the stats are counted from, `None` until the first treasury update.
+    counted_since: Option<i64>,
+    stats: HashMap<u64, BusStats>,
+}
+
+impl BusAnalyticsResource {
+    /// Starts counting the epoch that began at `last_reset_at` (`Config.last_reset_at`).
+    pub fn reset(&mut self, last_reset_at: i64) {
+        self.counted_since = Some(match self.counted_since {
+            Some(_) => last_reset_at,
+            // joined partway through the epoch, earlier hits weren't seen
+            None => last_reset_at.max(get_unix_timestamp() as i64),
+        });
+        self.stats.clear();
+    }
+
+    pub fn record_hit(&mut self, bus_id: u64, rewards_drained: u64) {
+        let stats = self.stats.entry(bus_id).or_default();
+        stats.hits += 1;
+        stats.rewards_drained += rewards_drained;
+    }
+
+    fn elapsed_minutes(&self) -> f64 {
+        match self.counted_since {
+            Some(counted_since) => (get_unix_timestamp() as i64 - counted_since).max(0) as f64 / 60.0,
+            None => 0.0,
+        }
+    }
+
+    pub fn hits_per_minute(&self, bus_id: u64) -> f64 {
+        let minutes = self.elapsed_minutes();
+        match self.stats.get(&bus_id) {
+            Some(stats) if minutes > 0.0 => stats.hits as f64 / minutes,
+            _ => 0.0,
+        }
+    }
+
+    /// Raw reward units drained per minute
+    pub fn rewards_drained_per_minute(&self, bus_id: u64) -> f64 {
+        let minutes = self.elapsed_minutes();
+        match self.stats.get(&bus_id) {
+            Some(stats) if minutes > 0.0 => stats.rewards_drained as f64 / minutes,
+            _ => 0.0,
+        }
+    }
+
+    /// Minutes until the bus runs dry at the current drain rate
+    pub fn minutes_until_depleted(&self, bus_id: u64, rewards_remaining: u64) -> Option<f64> {
+        let drained_per_minute = self.rewards_drained_per_minute(bus_id);
+        if drained_per_minute > 0.0 {
+            Some(rewards_remaining as f64 / drained_per_minute)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Reflect, Resource, InspectorOptions)]
@@ -973,10 +1508,152 @@ impl Default for MinerStatusResource {
     }
 }
 
+pub struct RpcEndpoint {
+    pub url: String,
+    pub client: Arc<dyn OreRpc>,
+    pub latency_ms: Option<u64>,
+    pub slot: u64,
+    // results of the most recent health checks and calls, true is a success
+    pub recent_checks: VecDeque<bool>,
+    pub last_error: Option<String>,
+}
+
+impl RpcEndpoint {
+    pub fn new(url: String) -> Self {
+        let client = Arc::new(RpcClient::new_with_commitment(
+            url.clone(),
+            CommitmentConfig::confirmed(),
+        ));
+        Self::with_client(url, client)
+    }
+
+    pub fn with_client(url: String, client: Arc<dyn OreRpc>) -> Self {
+        Self {
+            url,
+            client,
+            latency_ms: None,
+            slot: 0,
+            recent_checks: VecDeque::with_capacity(RPC_HEALTH_WINDOW),
+            last_error: None,
+        }
+    }
+
+    pub fn host(&self) -> String {
+        let without_scheme = self.url.split("://").last().unwrap_or(&self.url);
+        without_scheme.split('/').next().unwrap_or(without_scheme).to_string()
+    }
+
+    pub fn record_check(&mut self, success: bool) {
+        if self.recent_checks.len() >= RPC_HEALTH_WINDOW {
+            self.recent_checks.pop_front();
+        }
+        self.recent_checks.push_back(success);
+    }
+
+    pub fn error_rate(&self) -> f64 {
+        if self.recent_checks.is_empty() {
+            return 0.0;
+        }
+        let errors = self.recent_checks.iter().filter(|ok| !**ok).count();
+        errors as f64 / self.recent_checks.len() as f64
+    }
+
+    pub fn unhealthy_reason(&self, highest_slot: u64) -> Option<String> {
+        if let Some(false) = self.recent_checks.back() {
+            return Some(self.last_error.clone().unwrap_or("request failed".to_string()));
+        }
+        if self.error_rate() > RPC_MAX_ERROR_RATE {
+            return Some(format!("error rate {:.0}%", self.error_rate() * 100.0));
+        }
+        let slot_lag = highest_slot.saturating_sub(self.slot);
+        if slot_lag > RPC_MAX_SLOT_LAG {
+            return Some(format!("{} slots behind", slot_lag));
+        }
+        None
+    }
+}
+
 #[derive(Resource)]
 pub struct RpcConnection {
-    rpc: Option<Arc<RpcClient>>,
+    rpc: Option<Arc<dyn OreRpc>>,
+    // the cluster faucet, None where airdrops aren't available
+    airdrop: Option<Arc<dyn OreRpc>>,
+    endpoints: Vec<RpcEndpoint>,
+    active_endpoint: usize,
+    // what `rpc` calls first, moved by failed over calls and the health checks
+    shared_active_endpoint: Arc<AtomicUsize>,
+    healthy_endpoints: Arc<Vec<AtomicBool>>,
+    call_results: Option<Receiver<RpcCallResult>>,
     pub fetch_ui_data_timer: Timer,
+    fetch_ui_data_failures: u32,
+}
+
+impl RpcConnection {
+    pub fn connect(&mut self, config: &AppConfig) {
+        let mut urls = vec![config.rpc_url.clone()];
+        for url in &config.backup_rpc_urls {
+            if !url.is_empty() && !urls.contains(url) {
+                urls.push(url.clone());
+            }
+        }
+        self.set_endpoints(urls.into_iter().map(RpcEndpoint::new).collect());
+        self.airdrop = config.cluster.airdrop_url().map(|url| {
+            Arc::new(RpcClient::new(url.to_string())) as Arc<dyn OreRpc>
+        });
+    }
+
+    pub fn active_url(&self) -> Option<String> {
+        self.endpoints.get(self.active_endpoint).map(|e| e.url.clone())
+    }
+
+    /// Tasks get a `FailoverRpc` over all endpoints, starting on the first.
+    pub fn set_endpoints(&mut self, endpoints: Vec<RpcEndpoint>) {
+        let (sender, receiver) = unbounded::<RpcCallResult>();
+        self.shared_active_endpoint = Arc::new(AtomicUsize::new(0));
+        self.healthy_endpoints = Arc::new(endpoints.iter().map(|_| AtomicBool::new(true)).collect());
+        self.call_results = Some(receiver);
+        self.rpc = Some(Arc::new(FailoverRpc::new(
+            endpoints.iter().map(|e| e.client.clone()).collect(),
+            self.shared_active_endpoint.clone(),
+            self.healthy_endpoints.clone(),
+            sender,
+        )));
+        self.endpoints = endpoints;
+        self.active_endpoint = 0;
+    }
+
+    pub fn set_active_endpoint(&mut self, index: usize) {
+        if index < self.endpoints.len() {
+            self.active_endpoint = index;
+            self.shared_active_endpoint.store(index, Ordering::Relaxed);
+        }
+    }
+
+    pub fn set_endpoint_healthy(&mut self, index: usize, healthy: bool) {
+        if let Some(flag) = self.healthy_endpoints.get(index) {
+            flag.store(healthy, Ordering::Relaxed);
+        }
+    }
+
+    /// Doubles the fetch interval for every consecutive failed ui data fetch,
+    /// and goes back to `ui_fetch_interval` on the first success.
+    pub fn record_fetch_ui_data_result(&mut self, success: bool, ui_fetch_interval: u64) {
+        if success {
+            self.fetch_ui_data_failures = 0;
+        } else {
+            self.fetch_ui_data_failures = self.fetch_ui_data_failures.saturating_add(1);
+        }
+        let interval = Duration::from_millis(ui_fetch_interval);
+        let backoff = interval
+            .saturating_mul(2u32.saturating_pow(self.fetch_ui_data_failures.min(16)))
+            .min(UI_FETCH_MAX_BACKOFF.max(interval));
+        self.fetch_ui_data_timer.set_duration(backoff);
+        self.fetch_ui_data_timer.reset();
+    }
+
+    pub fn has_pending_fetch_retry(&self) -> bool {
+        self.fetch_ui_data_failures > 0
+    }
 }
 
 #[derive(Resource)]
@@ -1004,12 +1681,9 @@ pub enum AccountUpdatesData {
     ProofData(Proof),
     BusData(Bus),
     TreasuryConfigData(ore_api::state::Config),
-    TreasuryBalanceData(u64)
-}
-
-#[derive(Resource)]
-pub struct AccountUpdatesChannel {
-    pub channel: Receiver<AccountUpdatesData>
+    TreasuryBalanceData(u64),
+    // status and reconnect attempt from the subscription manager
+    WsStatus(WsStatus, u32),
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -1024,6 +1698,22 @@ pub struct OreAppState {
     active_input_node: Option<Entity>,
 }
 
+#[derive(Resource)]
+pub struct AutoLockResource {
+    // None when auto lock is turned off
+    timeout: Option<Duration>,
+    idle: Duration,
+}
+
+impl AutoLockResource {
+    pub fn new(minutes: u64) -> Self {
+        Self {
+            timeout: if minutes > 0 { Some(Duration::from_secs(minutes * 60)) } else { None },
+            idle: Duration::ZERO,
+        }
+    }
+}
+
 pub struct LocalResetCooldown {
     reset_timer: Timer
 }
@@ -1046,8 +1736,23 @@ impl Default for LocalResetCooldown {
 pub fn trigger_rpc_calls_for_ui(
     time: Res<Time>,
     mut rpc_connection: ResMut<RpcConnection>,
+    mut ws_status: ResMut<WsStatusResource>,
     mut event_fetch_ui_rpc_data: EventWriter<EventFetchUiDataFromRpc>,
 ) {
+    // websocket updates cover the program accounts, so only poll when they stop flowing
+    let polling = ws_status.status != WsStatus::Live || ws_status.is_stale();
+    if polling != ws_status.polling {
+        if polling {
+            warn!("Websocket updates unavailable, polling rpc for ui data");
+        } else {
+            info!("Websocket updates live, polling suspended");
+        }
+        ws_status.polling = polling;
+    }
+    if !polling && !rpc_connection.has_pending_fetch_retry() {
+        return;
+    }
+
     rpc_connection.fetch_ui_data_timer.tick(time.delta());
     if rpc_connection.fetch_ui_data_timer.just_finished() {
         event_fetch_ui_rpc_data.send(EventFetchUiDataFromRpc);
@@ -1256,6 +1961,35 @@ pub fn text_input(
     }
 }
 
+/// Tab replaces the word being typed in the seed phrase with the first
+/// wordlist match.
+fn text_mnemonic_autocomplete(
+    kbd: Res<ButtonInput<KeyCode>>,
+    app_state: Res<OreAppState>,
+    captured_text_query: Query<&Children, With<ButtonCaptureTextInput>>,
+    mut query_mnemonic: Query<(Entity, &mut TextInput), With<TextMnemonicInput>>,
+) {
+    if !kbd.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    let active_children = if let Some(active_input_node) = app_state.active_input_node {
+        if let Ok(children) = captured_text_query.get(active_input_node) {
+            children
+        } else {
+            return;
+        }
+    } else {
+        return;
+    };
+    if let Ok((entity, mut text_input)) = query_mnemonic.get_single_mut() {
+        if active_children.contains(&entity) {
+            if let Some(word) = seed_phrase::word_suggestions(&text_input.text).first() {
+                text_input.text = seed_phrase::complete_word(&text_input.text, word);
+            }
+        }
+    }
+}
+
 fn file_drop(
     mut dnd_evr: EventReader<FileDragAndDrop>,
     mut event_writer: EventWriter<EventLoadKeypairFile>
@@ -1299,24 +2033,24 @@ pub fn tx_processors_send(
                     let task_pool = IoTaskPool::get();
                     let tx = signed_tx.clone();
                     let task = task_pool.spawn(Compat::new(async move {
-                        let send_cfg = RpcSendTransactionConfig {
-                            skip_preflight: true,
-                            preflight_commitment: Some(CommitmentLevel::Confirmed),
-                            encoding: Some(UiTransactionEncoding::Base64),
-                            max_retries: Some(0),
-                            min_context_slot: None,
-                        };
-
+                        let mut last_error = AppError::Rpc("Failed to send tx".to_string());
                         for _ in 0..3 {
-                            let sig = client.send_transaction_with_config(&tx, send_cfg).await;
-                            if let Ok(sig) = sig {
-                                return Ok(sig);
+                            match client.send_transaction(&tx).await {
+                                Ok(sig) => return Ok(sig),
+                                Err(e) if !e.is_retryable() => {
+                                    error!("Failed to send tx: {}", e);
+                                    return Err(e);
+                                },
+                                Err(e) => {
+                                    let backoff = if let AppError::RateLimited(_) = e { 1000 } else { 100 };
+                                    last_error = e;
+                                    sleep(Duration::from_millis(backoff)).await;
+                                },
                             }
-                            sleep(Duration::from_millis(100)).await;
                         }
 
-                        error!("Failed to send tx.");
-                        return Err("Failed to send tx".to_string());
+                        error!("Failed to send tx: {}", last_error);
+                        return Err(last_error);
                     }));
 
                     commands
@@ -1358,6 +2092,114 @@ impl Default for SigChecksTimer {
     }
 }
 
+pub struct RpcHealthCheckTimer {
+    timer: Timer,
+}
+
+impl Default for RpcHealthCheckTimer {
+    fn default() -> Self {
+        Self {
+            timer: Timer::new(RPC_HEALTH_CHECK_INTERVAL, TimerMode::Repeating)
+        }
+    }
+}
+
+pub fn rpc_health_checks(
+    mut commands: Commands,
+    rpc_connection: Res<RpcConnection>,
+    mut health_check_timer: Local<RpcHealthCheckTimer>,
+    query_task_handler: Query<(Entity, Option<&TaskRpcHealthChecks>), With<EntityTaskHandler>>,
+    time: Res<Time>,
+) {
+    health_check_timer.timer.tick(time.delta());
+    if !health_check_timer.timer.just_finished() || rpc_connection.endpoints.is_empty() {
+        return;
+    }
+
+    if let Ok((task_handler_entity, running_task)) = query_task_handler.get_single() {
+        if running_task.is_some() {
+            return;
+        }
+
+        let clients: Vec<Arc<dyn OreRpc>> = rpc_connection
+            .endpoints
+            .iter()
+            .map(|e| e.client.clone())
+            .collect();
+        let task_pool = IoTaskPool::get();
+        let task = task_pool.spawn(Compat::new(async move {
+            // probed together, one hung endpoint can't hold up the others
+            join_all(clients.into_iter().enumerate().map(|(index, client)| async move {
+                let started_at = Instant::now();
+                let slot = match timeout(RPC_PROBE_TIMEOUT, client.get_slot()).await {
+                    Ok(Ok(slot)) => Ok(slot),
+                    Ok(Err(e)) => Err(format!("getSlot failed: {}", e)),
+                    Err(_) => Err(format!("getSlot timed out after {}s", RPC_PROBE_TIMEOUT.as_secs())),
+                };
+                RpcHealthCheckResult {
+                    index,
+                    slot,
+                    latency_ms: started_at.elapsed().as_millis() as u64,
+                }
+            })).await
+        }));
+
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskRpcHealthChecks { task });
+    }
+}
+
+/// Logs a switch of the active rpc in the tx area.
+pub fn rpc_failover_result(new_url: String, reason: String) -> EventTxResult {
+    warn!("RPC failover to {}: {}", new_url, reason);
+    EventTxResult {
+        tx_type: "RPC".to_string(),
+        sig: new_url,
+        tx_time: 0,
+        hash_status: None,
+        tx_status: TxStatus {
+            status: "FAILOVER".to_string(),
+            error: reason,
+        },
+    }
+}
+
+/// Counts the reads and sends made by tasks towards each endpoint's error
+/// rate, and logs a failover made by a call that gave up on the active endpoint.
+pub fn read_rpc_call_results(
+    mut rpc_connection: ResMut<RpcConnection>,
+    mut event_writer: EventWriter<EventTxResult>,
+) {
+    let receiver = if let Some(receiver) = &rpc_connection.call_results {
+        receiver.clone()
+    } else {
+        return;
+    };
+
+    while let Ok(result) = receiver.try_recv() {
+        if let Some(endpoint) = rpc_connection.endpoints.get_mut(result.index) {
+            if let Some(e) = result.error {
+                endpoint.last_error = Some(e.to_string());
+                endpoint.record_check(false);
+            } else {
+                endpoint.record_check(true);
+            }
+        }
+    }
+
+    let failed_over_to = rpc_connection.shared_active_endpoint.load(Ordering::Relaxed);
+    if failed_over_to != rpc_connection.active_endpoint {
+        let reason = rpc_connection.endpoints[rpc_connection.active_endpoint]
+            .last_error
+            .clone()
+            .unwrap_or("request failed".to_string());
+        let new_url = rpc_connection.endpoints[failed_over_to].url.clone();
+        rpc_connection.active_endpoint = failed_over_to;
+        event_writer.send(rpc_failover_result(new_url, reason));
+    }
+}
+
 pub fn tx_processors_sigs_check(
     mut event_writer: EventWriter<EventCheckSigs>,
     mut sig_checks_timer: Local<SigChecksTimer>,
@@ -1556,28 +2398,72 @@ pub fn spin_spinner_icons(
 }
 
 pub fn read_accounts_update_channel(
-    account_update_channel: ResMut<AccountUpdatesChannel>,
+    account_subscriptions: Option<Res<AccountSubscriptions>>,
     mut proof_account: ResMut<ProofAccountResource>,
     mut treasury_account: ResMut<TreasuryAccountResource>,
     mut busses_res: ResMut<BussesResource>,
     mut mining_proofs_res: ResMut<MiningProofsResource>,
+    mut bus_analytics: ResMut<BusAnalyticsResource>,
+    mut ws_status: ResMut<WsStatusResource>,
     app_wallet: Res<AppWallet>,
-    mut event_proof_account_updated: EventWriter<EventProofAccountUpdated>
+    mut event_proof_account_updated: EventWriter<EventProofAccountUpdated>,
+    mut event_writer_toast: EventWriter<EventToast>,
 ) {
-    let receiver = account_update_channel.channel.clone();
+    let receiver = if let Some(account_subscriptions) = &account_subscriptions {
+        account_subscriptions.receiver.clone()
+    } else {
+        return;
+    };
 
     while let Ok(data) = receiver.try_recv() {
+        if let AccountUpdatesData::WsStatus(status, attempts) = data {
+            if status != ws_status.status {
+                info!("Websocket status: {}", status.to_string());
+                match status {
+                    // once when a live connection drops or the first connect fails,
+                    // retries stay Reconnecting and don't toast again
+                    WsStatus::Reconnecting => {
+                        event_writer_toast.send(EventToast::warning(
+                            "Websocket connection failed, polling rpc until it reconnects."
+                        ));
+                    },
+                    WsStatus::Live if ws_status.status == WsStatus::Reconnecting => {
+                        event_writer_toast.send(EventToast::success("Websocket reconnected."));
+                    },
+                    _ => {},
+                }
+            }
+            ws_status.status = status;
+            ws_status.reconnect_attempts = attempts;
+            continue;
+        }
+        ws_status.last_update_at = Some(Instant::now());
+
         match data {
             AccountUpdatesData::BusData(new_bus_data) => {
-                for bus in &mut busses_res.busses {
-                    if bus.id == new_bus_data.id {
-                        *bus = new_bus_data;
+                let mut rewards_drained = 0;
+                if let Some(bus) = busses_res.busses.iter_mut().find(|bus| bus.id == new_bus_data.id) {
+                    rewards_drained = bus.rewards.saturating_sub(new_bus_data.rewards);
+                    *bus = new_bus_data;
+                } else {
+                    // failed to decode on the last rpc fetch, keep the busses ordered by id
+                    let index = busses_res.busses
+                        .iter()
+                        .position(|bus| bus.id > new_bus_data.id)
+                        .unwrap_or(busses_res.busses.len());
+                    if index <= busses_res.current_bus_id && busses_res.current_bus_id < busses_res.busses.len() {
+                        busses_res.current_bus_id += 1;
                     }
+                    busses_res.busses.insert(index, new_bus_data);
+                }
+                if rewards_drained > 0 {
+                    busses_res.last_hit_at.insert(new_bus_data.id, Instant::now());
+                    bus_analytics.record_hit(new_bus_data.id, rewards_drained);
                 }
             },
             AccountUpdatesData::ProofData(proof) => {
-                if let Some(wallet) = &app_wallet.wallet {
-                    if proof.authority == wallet.pubkey() {
+                if let Some(pubkey) = app_wallet.pubkey() {
+                    if proof.authority == pubkey {
                         let new_proof = ProofAccountResource {
                             challenge: KeccakHash::new_from_array(proof.challenge).to_string(),
                             stake: proof.balance,
@@ -1597,6 +2483,7 @@ pub fn read_accounts_update_channel(
                     // last_reset_at updated
                     mining_proofs_res.miners_last_epoch = mining_proofs_res.miners_this_epoch;
                     mining_proofs_res.miners_this_epoch = 0;
+                    bus_analytics.reset(new_treasury_data.last_reset_at);
 
                     info!("miners last epoch: {}", mining_proofs_res.miners_last_epoch);
                     let top_stake = (new_treasury_data.top_balance as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
@@ -1611,7 +2498,8 @@ pub fn read_accounts_update_channel(
             AccountUpdatesData::TreasuryBalanceData(new_balance) => {
                 let new_balance = (new_balance as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
                 treasury_account.balance = new_balance.to_string();
-            }
+            },
+            AccountUpdatesData::WsStatus(..) => {}
         }
     }
 }
@@ -0,0 +1,132 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use async_trait::async_trait;
+use crossbeam_channel::Sender;
+use solana_account_decoder::parse_token::{UiTokenAccount, UiTokenAmount};
+use solana_sdk::{account::Account, hash::Hash, pubkey::Pubkey, signature::Signature, transaction::Transaction};
+use solana_transaction_status::TransactionStatus;
+
+use super::OreRpc;
+use crate::error::AppError;
+
+/// Outcome of one call on one endpoint, counted towards its error rate by
+/// `read_rpc_call_results`.
+pub struct RpcCallResult {
+    pub index: usize,
+    pub error: Option<AppError>,
+}
+
+/// The rpc handed to tasks. Calls go to the active endpoint, and when it
+/// can't be reached or rate limits they are retried on the next healthy
+/// endpoint in order of preference, which then becomes the active one.
+pub struct FailoverRpc {
+    clients: Vec<Arc<dyn OreRpc>>,
+    // shared with `RpcConnection`, the health checks move it back to the primary
+    active: Arc<AtomicUsize>,
+    // set by the health checks, unhealthy endpoints are skipped when failing over
+    healthy: Arc<Vec<AtomicBool>>,
+    results: Sender<RpcCallResult>,
+}
+
+impl FailoverRpc {
+    pub fn new(
+        clients: Vec<Arc<dyn OreRpc>>,
+        active: Arc<AtomicUsize>,
+        healthy: Arc<Vec<AtomicBool>>,
+        results: Sender<RpcCallResult>,
+    ) -> Self {
+        Self { clients, active, healthy, results }
+    }
+
+    /// The active endpoint first, then the other healthy ones in order.
+    fn call_order(&self) -> Vec<usize> {
+        let active = self.active.load(Ordering::Relaxed);
+        let mut order = vec![active];
+        order.extend((0..self.clients.len()).filter(|index| {
+            *index != active && self.healthy.get(*index).map(|h| h.load(Ordering::Relaxed)).unwrap_or(true)
+        }));
+        order
+    }
+
+    async fn call<T, F, Fut>(&self, f: F) -> Result<T, AppError>
+    where
+        F: Fn(Arc<dyn OreRpc>) -> Fut + Send + Sync,
+        Fut: Future<Output = Result<T, AppError>> + Send,
+        T: Send,
+    {
+        let order = self.call_order();
+        let first = order[0];
+        let mut last_error = AppError::Network("No rpc endpoints".to_string());
+        for index in order {
+            let client = if let Some(client) = self.clients.get(index) {
+                client.clone()
+            } else {
+                continue;
+            };
+            match f(client).await {
+                Err(e) if e.is_endpoint_failure() => {
+                    let _ = self.results.send(RpcCallResult { index, error: Some(e.clone()) });
+                    last_error = e;
+                },
+                result => {
+                    let _ = self.results.send(RpcCallResult { index, error: None });
+                    if index != first {
+                        // later calls skip the failed endpoint until the health checks fail back
+                        let _ = self.active.compare_exchange(first, index, Ordering::Relaxed, Ordering::Relaxed);
+                    }
+                    return result;
+                },
+            }
+        }
+        Err(last_error)
+    }
+}
+
+#[async_trait]
+impl OreRpc for FailoverRpc {
+    async fn get_slot(&self) -> Result<u64, AppError> {
+        self.call(|client| async move { client.get_slot().await }).await
+    }
+
+    async fn get_balance(&self, pubkey: &Pubkey) -> Result<u64, AppError> {
+        self.call(|client| async move { client.get_balance(pubkey).await }).await
+    }
+
+    async fn get_account_data(&self, pubkey: &Pubkey) -> Result<Vec<u8>, AppError> {
+        self.call(|client| async move { client.get_account_data(pubkey).await }).await
+    }
+
+    async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> Result<Vec<Option<Account>>, AppError> {
+        self.call(|client| async move { client.get_multiple_accounts(pubkeys).await }).await
+    }
+
+    async fn get_latest_blockhash(&self) -> Result<Hash, AppError> {
+        self.call(|client| async move { client.get_latest_blockhash().await }).await
+    }
+
+    async fn send_transaction(&self, tx: &Transaction) -> Result<Signature, AppError> {
+        self.call(|client| async move { client.send_transaction(tx).await }).await
+    }
+
+    async fn get_signature_statuses(&self, signatures: &[Signature]) -> Result<Vec<Option<TransactionStatus>>, AppError> {
+        self.call(|client| async move { client.get_signature_statuses(signatures).await }).await
+    }
+
+    async fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> Result<Signature, AppError> {
+        self.call(|client| async move { client.request_airdrop(pubkey, lamports).await }).await
+    }
+
+    async fn get_token_account(&self, pubkey: &Pubkey) -> Result<Option<UiTokenAccount>, AppError> {
+        self.call(|client| async move { client.get_token_account(pubkey).await }).await
+    }
+
+    async fn get_token_account_balance(&self, pubkey: &Pubkey) -> Result<UiTokenAmount, AppError> {
+        self.call(|client| async move { client.get_token_account_balance(pubkey).await }).await
+    }
+}
@@ -0,0 +1,445 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use bytemuck::{Pod, Zeroable};
+use ore_api::{
+    consts::{BUS_ADDRESSES, CONFIG_ADDRESS, MINT_ADDRESS, TREASURY_ADDRESS, TREASURY_TOKENS_ADDRESS},
+    instruction::OreInstruction,
+    state::{AccountDiscriminator, Bus, Config, Proof, Treasury},
+};
+use solana_account_decoder::parse_token::{
+    token_amount_to_ui_amount, UiAccountState, UiTokenAccount, UiTokenAmount,
+};
+use solana_sdk::{
+    account::Account, clock::Clock, hash::Hash, instruction::CompiledInstruction,
+    pubkey::Pubkey, signature::Signature, sysvar, transaction::{Transaction, TransactionError},
+};
+use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
+
+use crate::{
+    error::AppError,
+    ore_utils::{proof_pubkey, AccountDeserialize, ORE_TOKEN_DECIMALS},
+    utils::get_unix_timestamp,
+};
+
+use super::OreRpc;
+
+pub const MOCK_TX_FEE: u64 = 5000;
+pub const MOCK_BUS_REWARDS: u64 = 1_000 * 10u64.pow(ORE_TOKEN_DECIMALS as u32);
+pub const MOCK_BASE_REWARD_RATE: u64 = 10u64.pow(ORE_TOKEN_DECIMALS as u32 - 2);
+
+/// What happens to a sent transaction once its confirmation delay is over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MockLanding {
+    Land,
+    /// Lands but fails, the ore state is left untouched
+    Fail(TransactionError),
+    /// Never shows up in signature statuses
+    Drop,
+}
+
+#[derive(Clone, Debug)]
+pub struct MockTokenAccount {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+struct PendingTx {
+    tx: Transaction,
+    checks_remaining: u32,
+}
+
+pub struct MockChain {
+    pub slot: u64,
+    pub blockhash: Hash,
+    pub accounts: HashMap<Pubkey, Account>,
+    pub token_accounts: HashMap<Pubkey, MockTokenAccount>,
+    pub landing: MockLanding,
+    // signature status checks a sent tx stays unconfirmed for
+    pub confirm_after_checks: u32,
+    // the next n requests fail like a flaky rpc would
+    pub fail_requests: u32,
+    pub sent: Vec<Transaction>,
+    pending: HashMap<Signature, PendingTx>,
+    landed: HashMap<Signature, TransactionStatus>,
+}
+
+/// In-memory cluster holding the ore config, treasury, bus and proof
+/// accounts. Sent transactions land after `confirm_after_checks` signature
+/// status checks, and landed ore instructions update the accounts the way
+/// the program would, close enough for the app's mine, claim and stake flow.
+pub struct MockOreRpc {
+    chain: Mutex<MockChain>,
+}
+
+impl Default for MockOreRpc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockOreRpc {
+    pub fn new() -> Self {
+        let mut chain = MockChain {
+            slot: 1,
+            blockhash: Hash::new_unique(),
+            accounts: HashMap::new(),
+            token_accounts: HashMap::new(),
+            landing: MockLanding::Land,
+            confirm_after_checks: 1,
+            fail_requests: 0,
+            sent: Vec::new(),
+            pending: HashMap::new(),
+            landed: HashMap::new(),
+        };
+
+        let mut config = Config::zeroed();
+        config.base_reward_rate = MOCK_BASE_REWARD_RATE;
+        config.last_reset_at = get_unix_timestamp() as i64;
+        config.min_difficulty = 1;
+        chain.set_ore_account(CONFIG_ADDRESS, AccountDiscriminator::Config as u8, &config);
+        chain.set_ore_account(TREASURY_ADDRESS, AccountDiscriminator::Treasury as u8, &Treasury::zeroed());
+        for (id, address) in BUS_ADDRESSES.iter().enumerate() {
+            let mut bus = Bus::zeroed();
+            bus.id = id as u64;
+            bus.rewards = MOCK_BUS_REWARDS;
+            chain.set_ore_account(*address, AccountDiscriminator::Bus as u8, &bus);
+        }
+        chain.token_accounts.insert(TREASURY_TOKENS_ADDRESS, MockTokenAccount {
+            owner: TREASURY_ADDRESS,
+            mint: MINT_ADDRESS,
+            amount: MOCK_BUS_REWARDS * BUS_ADDRESSES.len() as u64,
+        });
+
+        Self {
+            chain: Mutex::new(chain),
+        }
+    }
+
+    pub fn chain(&self) -> MutexGuard<MockChain> {
+        self.chain.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn set_balance(&self, pubkey: Pubkey, lamports: u64) {
+        self.chain().accounts.entry(pubkey).or_default().lamports = lamports;
+    }
+
+    pub fn set_landing(&self, landing: MockLanding) {
+        self.chain().landing = landing;
+    }
+
+    pub fn set_proof(&self, proof: Proof) {
+        self.chain().set_ore_account(proof_pubkey(proof.authority), AccountDiscriminator::Proof as u8, &proof);
+    }
+
+    pub fn proof(&self, authority: Pubkey) -> Option<Proof> {
+        self.chain().ore_account::<Proof>(&proof_pubkey(authority))
+    }
+
+    pub fn bus(&self, id: usize) -> Option<Bus> {
+        self.chain().ore_account::<Bus>(BUS_ADDRESSES.get(id)?)
+    }
+
+    fn request(&self) -> Result<MutexGuard<MockChain>, AppError> {
+        let mut chain = self.chain();
+        if chain.fail_requests > 0 {
+            chain.fail_requests -= 1;
+            return Err(AppError::Network("mock rpc request failed".to_string()));
+        }
+        Ok(chain)
+    }
+}
+
+impl MockChain {
+    pub fn set_ore_account<T: Pod>(&mut self, address: Pubkey, discriminator: u8, state: &T) {
+        let mut data = vec![0u8; 8];
+        data[0] = discriminator;
+        data.extend_from_slice(bytemuck::bytes_of(state));
+        let account = self.accounts.entry(address).or_default();
+        account.owner = ore_api::ID;
+        account.data = data;
+    }
+
+    pub fn ore_account<T: AccountDeserialize + Copy>(&self, address: &Pubkey) -> Option<T> {
+        let account = self.accounts.get(address)?;
+        T::try_from_bytes(&account.data).ok().copied()
+    }
+
+    fn land(&mut self, signature: Signature, tx: Transaction) {
+        self.slot += 1;
+        let err = match &self.landing {
+            MockLanding::Fail(err) => Some(err.clone()),
+            _ => None,
+        };
+
+        let fee_payer = tx.message.account_keys[0];
+        let payer = self.accounts.entry(fee_payer).or_default();
+        payer.lamports = payer.lamports.saturating_sub(MOCK_TX_FEE);
+
+        if err.is_none() {
+            for ix in &tx.message.instructions {
+                self.apply_instruction(&tx, ix, fee_payer);
+            }
+        }
+
+        self.landed.insert(signature, TransactionStatus {
+            slot: self.slot,
+            confirmations: None,
+            status: match &err {
+                Some(err) => Err(err.clone()),
+                None => Ok(()),
+            },
+            err,
+            confirmation_status: Some(TransactionConfirmationStatus::Confirmed),
+        });
+    }
+
+    fn apply_instruction(&mut self, tx: &Transaction, ix: &CompiledInstruction, signer: Pubkey) {
+        let keys = &tx.message.account_keys;
+        let program_id = keys[ix.program_id_index as usize];
+        let ix_accounts: Vec<Pubkey> = ix.accounts.iter().map(|i| keys[*i as usize]).collect();
+
+        if program_id == spl_associated_token_account::id() {
+            // create: [funding, associated account, wallet, mint, ..]
+            if ix_accounts.len() > 3 {
+                self.token_accounts.entry(ix_accounts[1]).or_insert(MockTokenAccount {
+                    owner: ix_accounts[2],
+                    mint: ix_accounts[3],
+                    amount: 0,
+                });
+            }
+            return;
+        }
+        if program_id != ore_api::ID {
+            return;
+        }
+
+        let instruction = match ix.data.first().and_then(|d| OreInstruction::try_from(*d).ok()) {
+            Some(instruction) => instruction,
+            None => return,
+        };
+        let amount = ix.data.get(1..9)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+            .unwrap_or(0);
+        let proof_address = proof_pubkey(signer);
+        let now = get_unix_timestamp() as i64;
+
+        match instruction {
+            OreInstruction::Open => {
+                if self.ore_account::<Proof>(&proof_address).is_none() {
+                    let mut proof = Proof::zeroed();
+                    proof.authority = signer;
+                    proof.miner = signer;
+                    proof.challenge = Hash::new_unique().to_bytes();
+                    proof.last_hash_at = now;
+                    self.set_ore_account(proof_address, AccountDiscriminator::Proof as u8, &proof);
+                }
+            },
+            OreInstruction::Mine => {
+                let bus_address = ix_accounts.iter().find(|a| BUS_ADDRESSES.contains(*a));
+                let (bus_address, mut bus) = match bus_address.and_then(|a| Some((*a, self.ore_account::<Bus>(a)?))) {
+                    Some(bus) => bus,
+                    None => return,
+                };
+                let mut proof = match self.ore_account::<Proof>(&proof_address) {
+                    Some(proof) => proof,
+                    None => return,
+                };
+                let reward = self.ore_account::<Config>(&CONFIG_ADDRESS)
+                    .map(|config| config.base_reward_rate)
+                    .unwrap_or(MOCK_BASE_REWARD_RATE)
+                    .min(bus.rewards);
+
+                bus.rewards -= reward;
+                proof.balance += reward;
+                proof.total_rewards += reward;
+                proof.total_hashes += 1;
+                proof.last_hash = proof.challenge;
+                proof.challenge = Hash::new_unique().to_bytes();
+                proof.last_hash_at = now;
+                self.set_ore_account(bus_address, AccountDiscriminator::Bus as u8, &bus);
+                self.set_ore_account(proof_address, AccountDiscriminator::Proof as u8, &proof);
+            },
+            OreInstruction::Claim => {
+                let mut proof = match self.ore_account::<Proof>(&proof_address) {
+                    Some(proof) => proof,
+                    None => return,
+                };
+                let amount = amount.min(proof.balance);
+                let beneficiary = ix_accounts.iter()
+                    .find(|a| **a != TREASURY_TOKENS_ADDRESS && self.token_accounts.contains_key(*a))
+                    .copied();
+                if let Some(beneficiary) = beneficiary {
+                    proof.balance -= amount;
+                    self.set_ore_account(proof_address, AccountDiscriminator::Proof as u8, &proof);
+                    if let Some(treasury_tokens) = self.token_accounts.get_mut(&TREASURY_TOKENS_ADDRESS) {
+                        treasury_tokens.amount = treasury_tokens.amount.saturating_sub(amount);
+                    }
+                    if let Some(token_account) = self.token_accounts.get_mut(&beneficiary) {
+                        token_account.amount += amount;
+                    }
+                }
+            },
+            OreInstruction::Stake => {
+                let mut proof = match self.ore_account::<Proof>(&proof_address) {
+                    Some(proof) => proof,
+                    None => return,
+                };
+                let sender = ix_accounts.iter()
+                    .find(|a| **a != TREASURY_TOKENS_ADDRESS && self.token_accounts.contains_key(*a))
+                    .copied();
+                if let Some(sender) = sender {
+                    let amount = match self.token_accounts.get_mut(&sender) {
+                        Some(token_account) => {
+                            let amount = amount.min(token_account.amount);
+                            token_account.amount -= amount;
+                            amount
+                        },
+                        None => return,
+                    };
+                    if let Some(treasury_tokens) = self.token_accounts.get_mut(&TREASURY_TOKENS_ADDRESS) {
+                        treasury_tokens.amount += amount;
+                    }
+                    proof.balance += amount;
+                    proof.last_stake_at = now;
+                    self.set_ore_account(proof_address, AccountDiscriminator::Proof as u8, &proof);
+                }
+            },
+            OreInstruction::Reset => {
+                if let Some(mut config) = self.ore_account::<Config>(&CONFIG_ADDRESS) {
+                    config.last_reset_at = now;
+                    self.set_ore_account(CONFIG_ADDRESS, AccountDiscriminator::Config as u8, &config);
+                }
+                for (id, address) in BUS_ADDRESSES.iter().enumerate() {
+                    let mut bus = Bus::zeroed();
+                    bus.id = id as u64;
+                    bus.rewards = MOCK_BUS_REWARDS;
+                    self.set_ore_account(*address, AccountDiscriminator::Bus as u8, &bus);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn token_account(&self, pubkey: &Pubkey) -> Option<UiTokenAccount> {
+        let token_account = self.token_accounts.get(pubkey)?;
+        Some(UiTokenAccount {
+            mint: token_account.mint.to_string(),
+            owner: token_account.owner.to_string(),
+            token_amount: token_amount_to_ui_amount(token_account.amount, ORE_TOKEN_DECIMALS),
+            delegate: None,
+            state: UiAccountState::Initialized,
+            is_native: false,
+            rent_exempt_reserve: None,
+            delegated_amount: None,
+            close_authority: None,
+            extensions: Vec::new(),
+        })
+    }
+}
+
+#[async_trait]
+impl OreRpc for MockOreRpc {
+    async fn get_slot(&self) -> Result<u64, AppError> {
+        Ok(self.request()?.slot)
+    }
+
+    async fn get_balance(&self, pubkey: &Pubkey) -> Result<u64, AppError> {
+        let chain = self.request()?;
+        Ok(chain.accounts.get(pubkey).map(|a| a.lamports).unwrap_or(0))
+    }
+
+    async fn get_account_data(&self, pubkey: &Pubkey) -> Result<Vec<u8>, AppError> {
+        let chain = self.request()?;
+        if *pubkey == sysvar::clock::ID {
+            let clock = Clock {
+                slot: chain.slot,
+                unix_timestamp: get_unix_timestamp() as i64,
+                ..Default::default()
+            };
+            return bincode::serialize(&clock).map_err(|e| AppError::Rpc(e.to_string()));
+        }
+        chain.accounts
+            .get(pubkey)
+            .map(|a| a.data.clone())
+            .ok_or_else(|| AppError::Rpc(format!("AccountNotFound: pubkey={}", pubkey)))
+    }
+
+    async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> Result<Vec<Option<Account>>, AppError> {
+        let chain = self.request()?;
+        Ok(pubkeys.iter().map(|pubkey| chain.accounts.get(pubkey).cloned()).collect())
+    }
+
+    async fn get_latest_blockhash(&self) -> Result<Hash, AppError> {
+        Ok(self.request()?.blockhash)
+    }
+
+    async fn send_transaction(&self, tx: &Transaction) -> Result<Signature, AppError> {
+        let mut chain = self.request()?;
+        let signature = tx.signatures.first().copied().unwrap_or_default();
+        chain.sent.push(tx.clone());
+        if chain.landing != MockLanding::Drop
+            && !chain.pending.contains_key(&signature)
+            && !chain.landed.contains_key(&signature)
+        {
+            let checks_remaining = chain.confirm_after_checks;
+            chain.pending.insert(signature, PendingTx { tx: tx.clone(), checks_remaining });
+        }
+        Ok(signature)
+    }
+
+    async fn get_signature_statuses(&self, signatures: &[Signature]) -> Result<Vec<Option<TransactionStatus>>, AppError> {
+        let mut chain = self.request()?;
+        let mut statuses = Vec::with_capacity(signatures.len());
+        for signature in signatures {
+            let ready = match chain.pending.get_mut(signature) {
+                Some(pending) if pending.checks_remaining > 0 => {
+                    pending.checks_remaining -= 1;
+                    false
+                },
+                Some(_) => true,
+                None => false,
+            };
+            if ready {
+                if let Some(pending) = chain.pending.remove(signature) {
+                    chain.land(*signature, pending.tx);
+                }
+            }
+            statuses.push(chain.landed.get(signature).cloned());
+        }
+        Ok(statuses)
+    }
+
+    async fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> Result<Signature, AppError> {
+        let mut chain = self.request()?;
+        chain.slot += 1;
+        chain.accounts.entry(*pubkey).or_default().lamports += lamports;
+
+        let signature = Signature::new_unique();
+        let slot = chain.slot;
+        chain.landed.insert(signature, TransactionStatus {
+            slot,
+            confirmations: None,
+            status: Ok(()),
+            err: None,
+            confirmation_status: Some(TransactionConfirmationStatus::Confirmed),
+        });
+        Ok(signature)
+    }
+
+    async fn get_token_account(&self, pubkey: &Pubkey) -> Result<Option<UiTokenAccount>, AppError> {
+        Ok(self.request()?.token_account(pubkey))
+    }
+
+    async fn get_token_account_balance(&self, pubkey: &Pubkey) -> Result<UiTokenAmount, AppError> {
+        let chain = self.request()?;
+        chain.token_account(pubkey)
+            .map(|token_account| token_account.token_amount)
+            .ok_or_else(|| AppError::Rpc(format!("Invalid param: could not find account {}", pubkey)))
+    }
+}
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use solana_account_decoder::parse_token::{UiTokenAccount, UiTokenAmount};
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
+use solana_sdk::{
+    account::Account, commitment_config::CommitmentLevel, hash::Hash, pubkey::Pubkey,
+    signature::Signature, transaction::Transaction,
+};
+use solana_transaction_status::{TransactionStatus, UiTransactionEncoding};
+
+use crate::error::AppError;
+
+pub mod failover;
+#[cfg(test)]
+pub mod mock;
+
+/// The rpc calls made by the mining and tx flow. `RpcClient` is the real
+/// backend, `mock::MockOreRpc` runs the same flow without a network.
+#[async_trait]
+pub trait OreRpc: Send + Sync {
+    async fn get_slot(&self) -> Result<u64, AppError>;
+
+    async fn get_balance(&self, pubkey: &Pubkey) -> Result<u64, AppError>;
+
+    async fn get_account_data(&self, pubkey: &Pubkey) -> Result<Vec<u8>, AppError>;
+
+    async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> Result<Vec<Option<Account>>, AppError>;
+
+    async fn get_latest_blockhash(&self) -> Result<Hash, AppError>;
+
+    /// Sends without preflight or rpc side retries, the tx processors resend.
+    async fn send_transaction(&self, tx: &Transaction) -> Result<Signature, AppError>;
+
+    async fn get_signature_statuses(&self, signatures: &[Signature]) -> Result<Vec<Option<TransactionStatus>>, AppError>;
+
+    async fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> Result<Signature, AppError>;
+
+    async fn get_token_account(&self, pubkey: &Pubkey) -> Result<Option<UiTokenAccount>, AppError>;
+
+    async fn get_token_account_balance(&self, pubkey: &Pubkey) -> Result<UiTokenAmount, AppError>;
+}
+
+#[async_trait]
+impl OreRpc for RpcClient {
+    async fn get_slot(&self) -> Result<u64, AppError> {
+        RpcClient::get_slot(self).await.map_err(AppError::from)
+    }
+
+    async fn get_balance(&self, pubkey: &Pubkey) -> Result<u64, AppError> {
+        RpcClient::get_balance(self, pubkey).await.map_err(AppError::from)
+    }
+
+    async fn get_account_data(&self, pubkey: &Pubkey) -> Result<Vec<u8>, AppError> {
+        RpcClient::get_account_data(self, pubkey).await.map_err(AppError::from)
+    }
+
+    async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> Result<Vec<Option<Account>>, AppError> {
+        RpcClient::get_multiple_accounts(self, pubkeys).await.map_err(AppError::from)
+    }
+
+    async fn get_latest_blockhash(&self) -> Result<Hash, AppError> {
+        self.get_latest_blockhash_with_commitment(self.commitment())
+            .await
+            .map(|(hash, _slot)| hash)
+            .map_err(AppError::from)
+    }
+
+    async fn send_transaction(&self, tx: &Transaction) -> Result<Signature, AppError> {
+        let send_cfg = RpcSendTransactionConfig {
+            skip_preflight: true,
+            preflight_commitment: Some(CommitmentLevel::Confirmed),
+            encoding: Some(UiTransactionEncoding::Base64),
+            max_retries: Some(0),
+            min_context_slot: None,
+        };
+        self.send_transaction_with_config(tx, send_cfg).await.map_err(AppError::from)
+    }
+
+    async fn get_signature_statuses(&self, signatures: &[Signature]) -> Result<Vec<Option<TransactionStatus>>, AppError> {
+        RpcClient::get_signature_statuses(self, signatures)
+            .await
+            .map(|response| response.value)
+            .map_err(AppError::from)
+    }
+
+    async fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> Result<Signature, AppError> {
+        RpcClient::request_airdrop(self, pubkey, lamports).await.map_err(AppError::from)
+    }
+
+    async fn get_token_account(&self, pubkey: &Pubkey) -> Result<Option<UiTokenAccount>, AppError> {
+        RpcClient::get_token_account(self, pubkey).await.map_err(AppError::from)
+    }
+
+    async fn get_token_account_balance(&self, pubkey: &Pubkey) -> Result<UiTokenAmount, AppError> {
+        RpcClient::get_token_account_balance(self, pubkey).await.map_err(AppError::from)
+    }
+}
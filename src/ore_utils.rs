@@ -6,18 +6,17 @@ use drillx::{equix, Hash, Solution};
 use ore_api::{
     ID as ORE_ID,
     instruction,
-    state::{Proof, Treasury},
+    state::{Bus, Config, Proof, Treasury},
     consts::{BUS_ADDRESSES, CONFIG_ADDRESS, EPOCH_DURATION, MINT_ADDRESS, PROOF,
     TOKEN_DECIMALS, TREASURY_ADDRESS }
 };
 pub use ore_utils::AccountDeserialize;
-use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
     account::ReadableAccount, clock::Clock, instruction::Instruction, pubkey::Pubkey, sysvar,
 };
 use spl_associated_token_account::get_associated_token_address;
 
-use crate::MiningDataChannelMessage;
+use crate::{error::AppError, ore_rpc::OreRpc, MiningDataChannelMessage};
 
 pub const ORE_TOKEN_DECIMALS: u8 = TOKEN_DECIMALS;
 
@@ -27,8 +26,12 @@ pub fn get_auth_ix(signer: Pubkey, ) -> Instruction {
     instruction::auth(proof)
 }
 
-pub fn get_mine_ix(signer: Pubkey, solution: Solution, bus: usize) -> Instruction {
-    instruction::mine(signer, signer, BUS_ADDRESSES[bus], solution)
+pub fn get_mine_ix(signer: Pubkey, solution: Solution, bus: usize) -> Result<Instruction, AppError> {
+    if let Some(bus_address) = BUS_ADDRESSES.get(bus) {
+        Ok(instruction::mine(signer, signer, *bus_address, solution))
+    } else {
+        Err(AppError::Program(format!("Bus {} does not exist", bus)))
+    }
 }
 
 pub fn get_register_ix(signer: Pubkey) -> Instruction {
@@ -59,125 +62,121 @@ pub fn get_ore_decimals() -> u8 {
     TOKEN_DECIMALS
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccountDecodeError {
+    /// The account doesn't exist on chain.
+    NotFound { account: String },
+    /// The account exists but doesn't match the expected layout, usually
+    /// after a program upgrade resized or changed it.
+    InvalidData { account: String, len: usize },
+    /// The account couldn't be fetched.
+    Rpc { account: String, error: String },
+}
+
+impl AccountDecodeError {
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, AccountDecodeError::NotFound { .. })
+    }
+}
+
+impl std::fmt::Display for AccountDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountDecodeError::NotFound { account } => write!(f, "{} account not found", account),
+            AccountDecodeError::InvalidData { account, len } => {
+                write!(f, "Failed to decode {} account ({} bytes)", account, len)
+            },
+            AccountDecodeError::Rpc { account, error } => {
+                write!(f, "Failed to fetch {} account: {}", account, error)
+            },
+        }
+    }
+}
+
+pub fn decode_account<T: AccountDeserialize + Copy>(
+    name: &str,
+    data: Option<&[u8]>,
+) -> Result<T, AccountDecodeError> {
+    let data = data.ok_or_else(|| AccountDecodeError::NotFound { account: name.to_string() })?;
+    T::try_from_bytes(data)
+        .map(|account| *account)
+        .map_err(|_| AccountDecodeError::InvalidData {
+            account: name.to_string(),
+            len: data.len(),
+        })
+}
+
+pub struct OreAccounts {
+    pub proof: Result<Proof, AccountDecodeError>,
+    pub treasury: Result<Treasury, AccountDecodeError>,
+    pub config: Result<Config, AccountDecodeError>,
+    /// One entry per bus address, in bus id order.
+    pub busses: Vec<Result<Bus, AccountDecodeError>>,
+}
+
 pub async fn get_proof_and_treasury_with_busses(
-    client: &RpcClient,
+    client: &dyn OreRpc,
     authority: Pubkey,
-) -> (
-    Result<Proof, ()>,
-    Result<Treasury, ()>,
-    Result<ore_api::state::Config, ()>,
-    Result<Vec<Result<ore_api::state::Bus, ()>>, ()>,
-) {
-    let account_pubkeys = vec![
+) -> OreAccounts {
+    let mut account_pubkeys = vec![
         TREASURY_ADDRESS,
         proof_pubkey(authority),
         CONFIG_ADDRESS,
-        BUS_ADDRESSES[0],
-        BUS_ADDRESSES[1],
-        BUS_ADDRESSES[2],
-        BUS_ADDRESSES[3],
-        BUS_ADDRESSES[4],
-        BUS_ADDRESSES[5],
-        BUS_ADDRESSES[6],
-        BUS_ADDRESSES[7],
     ];
-    let datas = client.get_multiple_accounts(&account_pubkeys).await;
-    if let Ok(datas) = datas {
-        let treasury = if let Some(data) = &datas[0] {
-            Ok(*Treasury::try_from_bytes(data.data()).expect("Failed to parse treasury account"))
-        } else {
-            Err(())
-        };
+    account_pubkeys.extend_from_slice(&BUS_ADDRESSES);
+    let bus_names: Vec<String> = (0..BUS_ADDRESSES.len())
+        .map(|id| format!("bus{}", id + 1))
+        .collect();
 
-        let proof = if let Some(data) = &datas[1] {
-            Ok(*Proof::try_from_bytes(data.data()).expect("Failed to parse treasury account"))
-        } else {
-            Err(())
-        };
-
-        let treasury_config = if let Some(data) = &datas[2] {
-            Ok(*ore_api::state::Config::try_from_bytes(data.data())
-                .expect("Failed to parse config account"))
-        } else {
-            Err(())
-        };
-        let bus_1 = if let Some(data) = &datas[3] {
-            Ok(*ore_api::state::Bus::try_from_bytes(data.data())
-                .expect("Failed to parse bus1 account"))
-        } else {
-            Err(())
-        };
-        let bus_2 = if let Some(data) = &datas[4] {
-            Ok(*ore_api::state::Bus::try_from_bytes(data.data())
-                .expect("Failed to parse bus2 account"))
-        } else {
-            Err(())
-        };
-        let bus_3 = if let Some(data) = &datas[5] {
-            Ok(*ore_api::state::Bus::try_from_bytes(data.data())
-                .expect("Failed to parse bus3 account"))
-        } else {
-            Err(())
-        };
-        let bus_4 = if let Some(data) = &datas[6] {
-            Ok(*ore_api::state::Bus::try_from_bytes(data.data())
-                .expect("Failed to parse bus4 account"))
-        } else {
-            Err(())
-        };
-        let bus_5 = if let Some(data) = &datas[7] {
-            Ok(*ore_api::state::Bus::try_from_bytes(data.data())
-                .expect("Failed to parse bus5 account"))
-        } else {
-            Err(())
-        };
-        let bus_6 = if let Some(data) = &datas[8] {
-            Ok(*ore_api::state::Bus::try_from_bytes(data.data())
-                .expect("Failed to parse bus6 account"))
-        } else {
-            Err(())
-        };
-        let bus_7 = if let Some(data) = &datas[9] {
-            Ok(*ore_api::state::Bus::try_from_bytes(data.data())
-                .expect("Failed to parse bus7 account"))
-        } else {
-            Err(())
-        };
-        let bus_8 = if let Some(data) = &datas[10] {
-            Ok(*ore_api::state::Bus::try_from_bytes(data.data())
-                .expect("Failed to parse bus1 account"))
-        } else {
-            Err(())
-        };
-
-        (proof, treasury, treasury_config, Ok(vec![bus_1, bus_2, bus_3, bus_4, bus_5, bus_6, bus_7, bus_8]))
-    } else {
-        (Err(()), Err(()), Err(()), Err(()))
+    match client.get_multiple_accounts(&account_pubkeys).await {
+        Ok(datas) => {
+            let data = |i: usize| datas.get(i).and_then(|d| d.as_ref()).map(|d| d.data());
+            OreAccounts {
+                treasury: decode_account("treasury", data(0)),
+                proof: decode_account("proof", data(1)),
+                config: decode_account("config", data(2)),
+                busses: bus_names
+                    .iter()
+                    .enumerate()
+                    .map(|(id, name)| decode_account(name, data(3 + id)))
+                    .collect(),
+            }
+        },
+        Err(e) => {
+            let rpc_err = |account: &str| AccountDecodeError::Rpc {
+                account: account.to_string(),
+                error: e.to_string(),
+            };
+            OreAccounts {
+                treasury: Err(rpc_err("treasury")),
+                proof: Err(rpc_err("proof")),
+                config: Err(rpc_err("config")),
+                busses: bus_names.iter().map(|name| Err(rpc_err(name))).collect(),
+            }
+        },
     }
 }
 
-pub async fn get_treasury(client: &RpcClient) -> Result<Treasury, ()> {
-    let data = client.get_account_data(&TREASURY_ADDRESS).await;
-    if let Ok(data) = data {
-        Ok(*Treasury::try_from_bytes(&data).expect("Failed to parse treasury account"))
-    } else {
-        Err(())
-    }
+pub async fn get_treasury(client: &dyn OreRpc) -> Result<Treasury, AccountDecodeError> {
+    get_ore_account(client, "treasury", TREASURY_ADDRESS).await
 }
 
-pub async fn get_proof(client: &RpcClient, authority: Pubkey) -> Result<Proof, String> {
-    let proof_address = proof_pubkey(authority);
-    let data = client.get_account_data(&proof_address).await;
-    match data {
-        Ok(data) => {
-            let proof = Proof::try_from_bytes(&data);
-            if let Ok(proof) = proof {
-                return Ok(*proof)
-            } else {
-                return Err("Failed to parse proof account".to_string())
-            }
-        }
-        Err(_) => return Err("Failed to get proof account".to_string()),
+pub async fn get_proof(client: &dyn OreRpc, authority: Pubkey) -> Result<Proof, AccountDecodeError> {
+    get_ore_account(client, "proof", proof_pubkey(authority)).await
+}
+
+async fn get_ore_account<T: AccountDeserialize + Copy>(
+    client: &dyn OreRpc,
+    name: &str,
+    address: Pubkey,
+) -> Result<T, AccountDecodeError> {
+    // get_multiple_accounts tells a missing account apart from a failed request
+    match client.get_multiple_accounts(&[address]).await {
+        Ok(datas) => {
+            let data = datas.first().and_then(|d| d.as_ref()).map(|d| d.data());
+            decode_account(name, data)
+        },
+        Err(e) => Err(AccountDecodeError::Rpc { account: name.to_string(), error: e.to_string() }),
     }
 }
 
@@ -189,16 +188,14 @@ pub fn treasury_tokens_pubkey() -> Pubkey {
     get_associated_token_address(&TREASURY_ADDRESS, &MINT_ADDRESS)
 }
 
-pub async fn get_clock_account(client: &RpcClient) -> Result<Clock, ()> {
-    if let Ok(data) = client.get_account_data(&sysvar::clock::ID).await {
-        if let Ok(data) = bincode::deserialize::<Clock>(&data) {
-            Ok(data)
-        } else {
-            Err(())
-        }
-    } else {
-        Err(())
-    }
+pub async fn get_clock_account(client: &dyn OreRpc) -> Result<Clock, AppError> {
+    let data = client.get_account_data(&sysvar::clock::ID).await?;
+    bincode::deserialize::<Clock>(&data).map_err(|_| {
+        AppError::Decode(AccountDecodeError::InvalidData {
+            account: "clock".to_string(),
+            len: data.len(),
+        })
+    })
 }
 
 pub fn get_cutoff(proof: Proof, buffer_time: u64) -> i64 {
@@ -0,0 +1,170 @@
+use bip39::{Language, Mnemonic, Seed};
+use rand::seq::index::sample;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{derivation_path::DerivationPath, signature::Keypair, signer::SeedDerivable};
+use zeroize::Zeroize;
+
+pub const PREVIEW_ACCOUNTS: u32 = 4;
+pub const MAX_SUGGESTIONS: usize = 6;
+pub const VERIFY_WORDS: usize = 3;
+
+/// How a keypair is derived from the BIP39 seed.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum DerivationScheme {
+    /// m/44'/501'/n'/0', Phantom, Solflare and this app.
+    #[default]
+    Bip44Change,
+    /// m/44'/501'/n', Solflare legacy and Ledger.
+    Bip44,
+    /// The seed itself, `solana-keygen recover` without a path.
+    Root,
+}
+
+impl DerivationScheme {
+    pub fn next(self) -> Self {
+        match self {
+            DerivationScheme::Bip44Change => DerivationScheme::Bip44,
+            DerivationScheme::Bip44 => DerivationScheme::Root,
+            DerivationScheme::Root => DerivationScheme::Bip44Change,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DerivationScheme::Bip44Change => "m/44'/501'/n'/0'",
+            DerivationScheme::Bip44 => "m/44'/501'/n'",
+            DerivationScheme::Root => "No derivation path",
+        }
+    }
+
+    /// The root scheme only has a single address.
+    pub fn accounts(&self) -> u32 {
+        match self {
+            DerivationScheme::Root => 1,
+            _ => PREVIEW_ACCOUNTS,
+        }
+    }
+
+    pub fn path_label(&self, account: u32) -> String {
+        match self {
+            DerivationScheme::Bip44Change => format!("m/44'/501'/{}'/0'", account),
+            DerivationScheme::Bip44 => format!("m/44'/501'/{}'", account),
+            DerivationScheme::Root => self.label().to_string(),
+        }
+    }
+
+    pub fn path(&self, account: u32) -> Option<DerivationPath> {
+        match self {
+            DerivationScheme::Bip44Change => Some(DerivationPath::new_bip44(Some(account), Some(0))),
+            DerivationScheme::Bip44 => Some(DerivationPath::new_bip44(Some(account), None)),
+            DerivationScheme::Root => None,
+        }
+    }
+}
+
+/// Lowercases and collapses whitespace, so pasted phrases parse.
+pub fn normalize_phrase(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub fn is_word(word: &str) -> bool {
+    Language::English
+        .wordlist()
+        .get_words_by_prefix(word)
+        .iter()
+        .any(|entry| *entry == word)
+}
+
+/// Wordlist entries starting with the word being typed, empty once the
+/// phrase ends with a space.
+pub fn word_suggestions(phrase: &str) -> Vec<&'static str> {
+    if phrase.is_empty() || phrase.ends_with(char::is_whitespace) {
+        return vec![];
+    }
+    let partial = match phrase.split_whitespace().last() {
+        Some(partial) => partial.to_lowercase(),
+        None => return vec![],
+    };
+    Language::English
+        .wordlist()
+        .get_words_by_prefix(&partial)
+        .iter()
+        .take(MAX_SUGGESTIONS)
+        .copied()
+        .collect()
+}
+
+/// Replaces the word being typed with `word` and starts the next one.
+pub fn complete_word(phrase: &str, word: &str) -> String {
+    let keep = phrase.trim_end_matches(|c: char| !c.is_whitespace());
+    format!("{}{} ", keep, word)
+}
+
+pub fn parse_mnemonic(phrase: &str) -> Result<Mnemonic, String> {
+    let phrase = normalize_phrase(phrase);
+    let words: Vec<&str> = phrase.split(' ').filter(|word| !word.is_empty()).collect();
+    if words.len() != 12 && words.len() != 24 {
+        return Err(format!("Enter 12 or 24 words, got {}.", words.len()));
+    }
+    if let Some(word) = words.iter().find(|word| !is_word(word)) {
+        return Err(format!("Unknown word: {}", word));
+    }
+    Mnemonic::from_phrase(&phrase, Language::English)
+        .map_err(|_| "Invalid seed phrase checksum.".to_string())
+}
+
+pub fn derive_keypair(
+    mnemonic: &Mnemonic,
+    passphrase: &str,
+    scheme: DerivationScheme,
+    account: u32,
+) -> Result<Keypair, String> {
+    let seed = Seed::new(mnemonic, passphrase);
+    let keypair = match scheme.path(account) {
+        Some(path) => Keypair::from_seed_and_derivation_path(seed.as_bytes(), Some(path)),
+        None => Keypair::from_seed(seed.as_bytes()),
+    };
+    keypair.map_err(|e| format!("Failed to derive keypair: {}", e))
+}
+
+/// Everything needed to derive a vault keypair again, stored encrypted with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeedBackup {
+    pub phrase: String,
+    pub passphrase: String,
+    pub scheme: DerivationScheme,
+    pub account: u32,
+}
+
+impl Drop for SeedBackup {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.passphrase.zeroize();
+    }
+}
+
+impl SeedBackup {
+    pub fn generated(phrase: String) -> Self {
+        Self {
+            phrase,
+            passphrase: String::new(),
+            scheme: DerivationScheme::default(),
+            account: 0,
+        }
+    }
+
+    pub fn words(&self) -> Vec<&str> {
+        self.phrase.split_whitespace().collect()
+    }
+}
+
+/// Random word positions the user has to type back, in phrase order.
+pub fn pick_verify_indices(word_count: usize) -> Vec<usize> {
+    let mut indices = sample(&mut rand::thread_rng(), word_count, VERIFY_WORDS.min(word_count)).into_vec();
+    indices.sort();
+    indices
+}
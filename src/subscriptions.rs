@@ -0,0 +1,340 @@
+use std::{future::Future, mem::size_of, time::{Duration, Instant}};
+
+use async_compat::Compat;
+use async_std::{channel as async_channel, future::timeout, task::sleep};
+use bevy::{prelude::*, tasks::{futures_lite::{stream, FutureExt, StreamExt}, IoTaskPool, Task}};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use futures::stream::select_all;
+use ore_api::{
+    consts::{BUS_ADDRESSES, CONFIG_ADDRESS, TREASURY_TOKENS_ADDRESS},
+    state::{AccountDiscriminator, Bus, Proof},
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::pubsub_client::PubsubClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{commitment_config::CommitmentConfig, program_pack::Pack};
+
+use crate::{ore_utils::AccountDeserialize, AccountUpdatesData};
+
+pub const WS_RECONNECT_MIN_BACKOFF: Duration = Duration::from_secs(1);
+pub const WS_RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+// no notification from any subscription for this long and the socket is treated as dead
+pub const WS_STALE_TIMEOUT: Duration = Duration::from_secs(90);
+// 8 byte discriminator prefix + account data
+pub const PROOF_ACCOUNT_SIZE: usize = 8 + size_of::<Proof>();
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WsStatus {
+    Connecting,
+    Live,
+    Reconnecting,
+}
+
+impl ToString for WsStatus {
+    fn to_string(&self) -> String {
+        match self {
+            WsStatus::Connecting => "Connecting".to_string(),
+            WsStatus::Live => "Live".to_string(),
+            WsStatus::Reconnecting => "Reconnecting".to_string(),
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct WsStatusResource {
+    pub status: WsStatus,
+    pub last_update_at: Option<Instant>,
+    pub reconnect_attempts: u32,
+    // true while ui data is polled over rpc instead
+    pub polling: bool,
+}
+
+impl Default for WsStatusResource {
+    fn default() -> Self {
+        Self {
+            status: WsStatus::Connecting,
+            last_update_at: None,
+            reconnect_attempts: 0,
+            polling: false,
+        }
+    }
+}
+
+impl WsStatusResource {
+    pub fn is_stale(&self) -> bool {
+        match self.last_update_at {
+            Some(last_update_at) => last_update_at.elapsed() > WS_STALE_TIMEOUT,
+            None => true,
+        }
+    }
+}
+
+/// Owns the websocket subscription task. Removing or replacing the resource
+/// unsubscribes and closes the socket.
+#[derive(Resource)]
+pub struct AccountSubscriptions {
+    pub receiver: Receiver<AccountUpdatesData>,
+    shutdown: async_channel::Sender<()>,
+    task: Option<Task<()>>,
+}
+
+impl AccountSubscriptions {
+    pub fn start(ws_url: String) -> Self {
+        let (sender, receiver) = unbounded::<AccountUpdatesData>();
+        let (shutdown, shutdown_receiver) = async_channel::bounded::<()>(1);
+
+        info!("Starting account subscriptions on {}", ws_url);
+        let task = IoTaskPool::get().spawn(Compat::new(
+            run_account_subscriptions(ws_url, sender, shutdown_receiver)
+        ));
+
+        Self {
+            receiver,
+            shutdown,
+            task: Some(task),
+        }
+    }
+}
+
+impl Drop for AccountSubscriptions {
+    fn drop(&mut self) {
+        info!("Stopping account subscriptions");
+        self.shutdown.close();
+        // let the task finish unsubscribing instead of cancelling it mid await
+        if let Some(task) = self.task.take() {
+            task.detach();
+        }
+    }
+}
+
+/// Keeps the account subscriptions alive until `shutdown` is closed,
+/// reconnecting with exponential backoff whenever the socket fails, a stream
+/// ends or the watchdog sees no updates.
+async fn run_account_subscriptions(
+    ws_url: String,
+    sender: Sender<AccountUpdatesData>,
+    shutdown: async_channel::Receiver<()>,
+) {
+    let mut backoff = WS_RECONNECT_MIN_BACKOFF;
+    let mut attempts = 0;
+
+    while !shutdown.is_closed() {
+        let status = if attempts == 0 {
+            WsStatus::Connecting
+        } else {
+            WsStatus::Reconnecting
+        };
+        if sender.send(AccountUpdatesData::WsStatus(status, attempts)).is_err() {
+            return;
+        }
+
+        let connect = async { Some(PubsubClient::new(&ws_url).await) };
+        let ps_client = match connect.or(wait_for_shutdown(&shutdown)).await {
+            Some(ps_client) => ps_client,
+            None => break,
+        };
+
+        match ps_client {
+            Ok(ps_client) => {
+                let received_updates = forward_account_updates(&ps_client, &sender, &shutdown).await;
+                let _ = ps_client.shutdown().await;
+
+                if received_updates {
+                    backoff = WS_RECONNECT_MIN_BACKOFF;
+                    attempts = 0;
+                }
+            },
+            Err(e) => {
+                error!("Failed to connect to websocket: {}", e.to_string());
+            }
+        }
+
+        if shutdown.is_closed() {
+            break;
+        }
+
+        attempts += 1;
+        warn!("Websocket disconnected, reconnecting in {}s", backoff.as_secs());
+        let wait = async {
+            sleep(backoff).await;
+            Some(())
+        };
+        if wait.or(wait_for_shutdown(&shutdown)).await.is_none() {
+            break;
+        }
+        backoff = (backoff * 2).min(WS_RECONNECT_MAX_BACKOFF);
+    }
+
+    info!("Account subscriptions stopped");
+}
+
+/// Item of the merged subscription streams, each stream ends with `Ended` so
+/// a single dropped subscription is noticed right away.
+enum SubscriptionEvent {
+    Update(Option<AccountUpdatesData>),
+    Ended(String),
+}
+
+/// Resolves to None once the owning `AccountSubscriptions` is dropped.
+async fn wait_for_shutdown<T>(shutdown: &async_channel::Receiver<()>) -> Option<T> {
+    let _ = shutdown.recv().await;
+    None
+}
+
+/// Subscribes to the config, bus, treasury token and proof accounts on
+/// `ps_client` and forwards updates until a stream ends, the watchdog fires or
+/// the receiver is dropped. Returns true if at least one update was received.
+async fn forward_account_updates(
+    ps_client: &PubsubClient,
+    sender: &Sender<AccountUpdatesData>,
+    shutdown: &async_channel::Receiver<()>,
+) -> bool {
+    let account_config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        data_slice: None,
+        commitment: Some(CommitmentConfig::confirmed()),
+        min_context_slot: None,
+    };
+
+    let mut streams = Vec::new();
+    let mut unsubscribes = Vec::new();
+
+    let mut ore_accounts = vec![("config".to_string(), CONFIG_ADDRESS)];
+    ore_accounts.extend(BUS_ADDRESSES.iter().enumerate().map(|(id, address)| (format!("bus {}", id), *address)));
+    for (name, address) in ore_accounts {
+        match ps_client.account_subscribe(&address, Some(account_config.clone())).await {
+            Ok((notifications, unsub)) => {
+                streams.push((name, notifications.map(|response| {
+                    response.value.data.decode().and_then(|data| decode_ore_account(&data))
+                }).boxed()));
+                unsubscribes.push(unsub);
+            },
+            Err(e) => {
+                error!("Failed to subscribe to ore {} account {}: {}", name, address, e.to_string());
+                unsubscribe_all(unsubscribes).await;
+                return false;
+            }
+        }
+    }
+
+    match ps_client.account_subscribe(&TREASURY_TOKENS_ADDRESS, Some(account_config.clone())).await {
+        Ok((notifications, unsub)) => {
+            streams.push(("treasury tokens".to_string(), notifications.map(|response| {
+                response.value.data.decode()
+                    .and_then(|data| spl_token::state::Account::unpack(&data).ok())
+                    .map(|token_account| AccountUpdatesData::TreasuryBalanceData(token_account.amount))
+            }).boxed()));
+            unsubscribes.push(unsub);
+        },
+        Err(e) => {
+            error!("Failed to subscribe to treasury tokens account: {}", e.to_string());
+            unsubscribe_all(unsubscribes).await;
+            return false;
+        }
+    }
+
+    // every miner's proof, for the dashboard and active miner counts
+    let proof_sub = ps_client.program_subscribe(
+        &ore_api::ID,
+        Some(RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(PROOF_ACCOUNT_SIZE as u64),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![AccountDiscriminator::Proof as u8])),
+            ]),
+            account_config,
+            with_context: None,
+        })
+    ).await;
+    match proof_sub {
+        Ok((notifications, unsub)) => {
+            streams.push(("proof".to_string(), notifications.map(|response| {
+                response.value.account.data.decode().and_then(|data| decode_ore_account(&data))
+            }).boxed()));
+            unsubscribes.push(unsub);
+        },
+        Err(e) => {
+            error!("Failed to subscribe to ore proof accounts: {}", e.to_string());
+            unsubscribe_all(unsubscribes).await;
+            return false;
+        }
+    }
+
+    if sender.send(AccountUpdatesData::WsStatus(WsStatus::Live, 0)).is_err() {
+        unsubscribe_all(unsubscribes).await;
+        return false;
+    }
+
+    if streams.is_empty() {
+        return false;
+    }
+    // polled fairly, a busy proof stream can't starve the bus and config ones
+    let mut updates = select_all(streams.into_iter().map(|(name, notifications)| {
+        notifications.map(SubscriptionEvent::Update).chain(stream::once(SubscriptionEvent::Ended(name)))
+    }));
+
+    let mut received_updates = false;
+    loop {
+        let next_update = async { Some(timeout(WS_STALE_TIMEOUT, updates.next()).await) };
+        let next_update = match next_update.or(wait_for_shutdown(shutdown)).await {
+            Some(next_update) => next_update,
+            None => break,
+        };
+        match next_update {
+            Ok(Some(SubscriptionEvent::Update(update))) => {
+                received_updates = true;
+                if let Some(update) = update {
+                    if sender.send(update).is_err() {
+                        break;
+                    }
+                }
+            },
+            Ok(Some(SubscriptionEvent::Ended(name))) => {
+                warn!("Websocket {} subscription stream ended", name);
+                break;
+            },
+            Ok(None) => {
+                warn!("Websocket subscription streams ended");
+                break;
+            },
+            Err(_) => {
+                warn!("No websocket updates for {}s", WS_STALE_TIMEOUT.as_secs());
+                break;
+            }
+        }
+    }
+
+    drop(updates);
+    unsubscribe_all(unsubscribes).await;
+
+    received_updates
+}
+
+async fn unsubscribe_all<F, Fut>(unsubscribes: Vec<F>)
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    for unsub in unsubscribes {
+        unsub().await;
+    }
+}
+
+/// Routes an ore account by its discriminator byte.
+fn decode_ore_account(data: &[u8]) -> Option<AccountUpdatesData> {
+    let discriminator = AccountDiscriminator::try_from(*data.first()?).ok()?;
+    match discriminator {
+        AccountDiscriminator::Bus => {
+            Bus::try_from_bytes(data).ok().map(|bus| AccountUpdatesData::BusData(*bus))
+        },
+        AccountDiscriminator::Config => {
+            ore_api::state::Config::try_from_bytes(data).ok().map(|config| AccountUpdatesData::TreasuryConfigData(*config))
+        },
+        AccountDiscriminator::Proof => {
+            Proof::try_from_bytes(data).ok().map(|proof| AccountUpdatesData::ProofData(*proof))
+        },
+        _ => None,
+    }
+}
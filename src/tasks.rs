@@ -1,4 +1,4 @@
-use std::time::{Duration, Instant};
+use std::{path::{Path, PathBuf}, sync::Arc, time::{Duration, Instant}};
 
 use bevy::{
     prelude::*,
@@ -7,16 +7,18 @@ use bevy::{
 use drillx::Solution;
 use ore_api::state::Bus;
 use solana_client::{rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
-use solana_sdk::{commitment_config::CommitmentLevel, signature::Signature, transaction::Transaction};
+use solana_sdk::{commitment_config::CommitmentLevel, pubkey::Pubkey, signature::Signature, signer::Signer, transaction::Transaction};
 use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus, UiTransactionEncoding};
+use zeroize::Zeroizing;
 
 use crate::{
-    ui::{components::{SpinnerIcon, TextTxProcessorTxType, ToggleAutoMineParent, TxPopUpArea}, styles::{hex_black, CURRENT_TX_STATUS_BACKGROUND, FONT_REGULAR, FONT_SIZE_MEDIUM, SPINNER_ICON, TX_POP_UP_BACKGROUND}}, utils::get_unix_timestamp, AppConfig, AppWallet, BussesResource, EventFetchUiDataFromRpc, EventProcessTx, EventSubmitHashTx, EventTxResult, HashStatus, MinerStatusResource, OreAppState, ProofAccountResource, TreasuryAccountResource, TxProcessor, TxStatus, TxType, FAST_DURATION, REGULAR_DURATION
+    error::AppError, events::EventToast, seed_phrase::{pick_verify_indices, SeedBackup}, ui::{components::{GeneratedSeedPhrase, SpinnerIcon, TextConnectionTestResult, TextGeneratedKeypair, TextInput, TextMnemonicLine1, TextMnemonicLine2, TextMnemonicLine3, TextPasswordInput, TextRevealedSeed, TextSeedVerifyInput, TextTxProcessorTxType, ToggleAutoMineParent, TxPopUpArea}, styles::{hex_black, CURRENT_TX_STATUS_BACKGROUND, FONT_REGULAR, FONT_SIZE_MEDIUM, SPINNER_ICON, TX_POP_UP_BACKGROUND}}, utils::{get_unix_timestamp, human_duration, shorten_string}, vanity::{VanityMatch, VanitySearchResource}, vault::{write_file_atomic, BackupBundle, UnlockAttempts, VaultError, VaultKey, WalletVault, WalletVaultResource}, AppConfig, AppScreenState, AppWallet, BussesResource, EventProcessTx, EventSubmitHashTx, EventTxResult, HashStatus, MinerStatusResource, OreAppState, ProofAccountResource, rpc_failover_result, RpcConnection, TreasuryAccountResource, TxProcessor, TxStatus, TxType, FAST_DURATION, REGULAR_DURATION
 };
 
 // Task Components
 // TODO: tasks should return results so errors can be dealt with by the task handler system
 pub struct TaskUpdateAppWalletSolBalanceData {
+    pub pubkey: Pubkey,
     pub sol_balance: f64,
     pub ore_balance: f64,
     pub proof_account_data: ProofAccountResource,
@@ -25,27 +27,27 @@ pub struct TaskUpdateAppWalletSolBalanceData {
 }
 #[derive(Component)]
 pub struct TaskUpdateAppWalletSolBalance {
-    pub task: Task<Result<TaskUpdateAppWalletSolBalanceData, String>>,
+    pub task: Task<Result<TaskUpdateAppWalletSolBalanceData, AppError>>,
 }
 
 #[derive(Component)]
 pub struct TaskGenerateHash {
-    pub task: Task<Result<(Solution, u32, u64, u64), String>>,
+    pub task: Task<Result<(Solution, u32, u64, u64), AppError>>,
 }
 
 #[derive(Component)]
 pub struct TaskSendAndConfirmTx {
-    pub task: Task<Result<(String, String), String>>,
+    pub task: Task<Result<(String, String), AppError>>,
 }
 
 #[derive(Component)]
 pub struct TaskSendTx {
-    pub task: Task<Result<Signature, String>>,
+    pub task: Task<Result<Signature, AppError>>,
 }
 
 #[derive(Component)]
 pub struct TaskCheckSigStatus {
-    pub task: Task<Result<Option<TransactionStatus>, String>>,
+    pub task: Task<Result<Option<TransactionStatus>, AppError>>,
 }
 
 pub struct SigCheckResults {
@@ -56,12 +58,12 @@ pub struct SigCheckResults {
 
 #[derive(Component)]
 pub struct TaskSigChecks {
-    pub task: Task<Result<SigCheckResults, String>>,
+    pub task: Task<Result<SigCheckResults, AppError>>,
 }
 
 #[derive(Component)]
 pub struct TaskConfirmTx {
-    pub task: Task<Result<Signature, String>>,
+    pub task: Task<Result<Signature, AppError>>,
 }
 
 #[derive(Component)]
@@ -79,7 +81,7 @@ pub struct TaskProcessTxData {
 
 #[derive(Component)]
 pub struct TaskProcessTx {
-    pub task: Task<Result<TaskProcessTxData, (TaskProcessTxData, String)>>,
+    pub task: Task<Result<TaskProcessTxData, (TaskProcessTxData, AppError)>>,
 }
 
 #[derive(Component)]
@@ -92,20 +94,88 @@ pub struct TaskProcessCurrentTx {
     pub task: Task<(Option<Signature>, TxStatus)>,
 }
 
+pub struct RpcHealthCheckResult {
+    pub index: usize,
+    pub slot: Result<u64, String>,
+    pub latency_ms: u64,
+}
+
+#[derive(Component)]
+pub struct TaskRpcHealthChecks {
+    pub task: Task<Vec<RpcHealthCheckResult>>,
+}
+
+pub struct ConnectionTestResult {
+    pub solana_core: String,
+    pub slot: u64,
+    pub genesis_hash: String,
+    pub rpc_latency_ms: u64,
+    pub ws_latency_ms: u64,
+}
+
+#[derive(Component)]
+pub struct TaskTestConnection {
+    pub task: Task<Result<ConnectionTestResult, String>>,
+}
+
+/// Decrypting runs the full kdf, too slow for the main thread.
+#[derive(Component)]
+pub struct TaskUnlockVault {
+    pub task: Task<Result<(WalletVault, VaultKey), VaultError>>,
+}
+
+/// The operations below check the password against the file, each runs the
+/// kdf at least once.
+#[derive(Component)]
+pub struct TaskChangePassword {
+    pub task: Task<Result<(WalletVault, VaultKey), String>>,
+}
+
+#[derive(Component)]
+pub struct TaskExportWallet {
+    pub task: Task<Result<PathBuf, EventToast>>,
+}
+
+#[derive(Component)]
+pub struct TaskRevealSeed {
+    pub task: Task<Result<Zeroizing<String>, EventToast>>,
+}
+
+pub struct SavedWallet {
+    /// Set when a backup was restored.
+    pub bundle: Option<BackupBundle>,
+    /// Copy of the `save.data` that was replaced.
+    pub replaced: Option<PathBuf>,
+}
+
+#[derive(Component)]
+pub struct TaskSaveWallet {
+    pub task: Task<Result<SavedWallet, EventToast>>,
+}
+
+#[derive(Component)]
+pub struct TaskVanitySearch {
+    pub task: Task<Option<VanityMatch>>,
+}
+
 pub fn task_update_app_wallet_sol_balance(
     mut commands: Commands,
     mut app_wallet: ResMut<AppWallet>,
     mut proof_account_res: ResMut<ProofAccountResource>,
     mut treasury_account_res: ResMut<TreasuryAccountResource>,
     mut busses_res: ResMut<BussesResource>,
+    mut rpc_connection: ResMut<RpcConnection>,
+    app_state: Res<OreAppState>,
     mut query: Query<(Entity, &mut TaskUpdateAppWalletSolBalance)>,
     mut query_toggle_mine: Query<&mut Visibility, With<ToggleAutoMineParent>>,
-    mut event_fetch_ui_data: EventWriter<EventFetchUiDataFromRpc>,
 ) {
     for (entity, mut task) in &mut query.iter_mut() {
         if let Some(result) = block_on(future::poll_once(&mut task.task)) {
             let mut fetch_failed = false;
             match result {
+                Ok(result) if app_wallet.pubkey() != Some(result.pubkey) => {
+                    info!("Dropping ui data fetched for a previous wallet");
+                },
                 Ok(result) => {
                     // if result.proof_account_data.challenge == "Not Found" {
                     //     // TODO: Spawn Open Button
@@ -125,7 +195,8 @@ pub fn task_update_app_wallet_sol_balance(
                 },
                 Err(e) => {
                     error!("Tasks UpdateResources error: {}", e);
-                    fetch_failed = true;
+                    // only back off for failures that retrying the rpc can fix
+                    fetch_failed = e.is_retryable();
                 }
             }
 
@@ -133,9 +204,7 @@ pub fn task_update_app_wallet_sol_balance(
                 .entity(entity)
                 .remove::<TaskUpdateAppWalletSolBalance>();
 
-            if fetch_failed {
-                event_fetch_ui_data.send(EventFetchUiDataFromRpc);
-            }
+            rpc_connection.record_fetch_ui_data_result(!fetch_failed, app_state.config.ui_fetch_interval);
         }
     }
 }
@@ -191,11 +260,11 @@ pub fn task_register_wallet(
 
 pub fn handle_task_process_tx_result(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    asset_server: Option<Res<AssetServer>>,
     app_wallet: Res<AppWallet>,
     proof_account: Res<ProofAccountResource>,
     ore_app_state: Res<OreAppState>,
-    mut winit_settings: ResMut<WinitSettings>,
+    mut winit_settings: Option<ResMut<WinitSettings>>,
     mut query_task_handler: Query<(Entity, &mut TaskProcessTx)>,
     mut event_writer: EventWriter<EventTxResult>,
     mut query_pop_up: Query<Entity, With<TxPopUpArea>>,
@@ -268,12 +337,36 @@ pub fn handle_task_process_tx_result(
                     let tx_send_interval = ore_app_state.config.tx_send_interval;
                     let timer = Timer::new(Duration::from_millis(tx_send_interval), TimerMode::Once);
 
-                    let pop_up_area = query_pop_up.single_mut();
-
                     let sol_balance = app_wallet.sol_balance;
                     let staked_balance = Some(proof_account.stake);
 
                     let new_tx = commands.spawn((
+                        TxProcessor {
+                            tx_type: tx_type.clone(),
+                            status: "SENDING".to_string(),
+                            error: "".to_string(),
+                            sol_balance,
+                            staked_balance,
+                            signature: None,
+                            signed_tx: tx,
+                            hash_status,
+                            created_at: Instant::now(),
+                            challenge: proof_account.challenge.clone(),
+                            send_and_confirm_interval: timer,
+                        },
+                        Name::new("TxProcessor"),
+                    )).id();
+
+                    // the processor runs without its pop up when there is no ui
+                    let (asset_server, pop_up_area) = match (&asset_server, query_pop_up.get_single_mut()) {
+                        (Some(asset_server), Ok(pop_up_area)) => (asset_server, pop_up_area),
+                        _ => {
+                            commands.entity(entity).remove::<TaskProcessTx>();
+                            continue;
+                        }
+                    };
+
+                    commands.entity(new_tx).insert((
                         NodeBundle {
                             background_color: Color::WHITE.into(),
                             style: Style {
@@ -288,19 +381,6 @@ pub fn handle_task_process_tx_result(
                             ..default()
                         },
                         UiImage::new(asset_server.load(TX_POP_UP_BACKGROUND)),
-                        TxProcessor {
-                            tx_type: tx_type.clone(),
-                            status: "SENDING".to_string(),
-                            error: "".to_string(),
-                            sol_balance,
-                            staked_balance,
-                            signature: None,
-                            signed_tx: tx,
-                            hash_status,
-                            created_at: Instant::now(),
-                            challenge: proof_account.challenge.clone(),
-                            send_and_confirm_interval: timer,
-                        },
                     )).with_children(|parent| {
                         parent.spawn((
                             TextBundle::from_section(
@@ -340,14 +420,17 @@ pub fn handle_task_process_tx_result(
                             UiImage::new(asset_server.load(SPINNER_ICON)),
                             SpinnerIcon,
                         ));
-                    }).id();
+                    });
 
                     commands.entity(pop_up_area).add_child(new_tx);
 
-                    winit_settings.focused_mode = UpdateMode::ReactiveLowPower { wait: FAST_DURATION };
-                    winit_settings.unfocused_mode = UpdateMode::ReactiveLowPower { wait: FAST_DURATION };
+                    if let Some(winit_settings) = winit_settings.as_mut() {
+                        winit_settings.focused_mode = UpdateMode::ReactiveLowPower { wait: FAST_DURATION };
+                        winit_settings.unfocused_mode = UpdateMode::ReactiveLowPower { wait: FAST_DURATION };
+                    }
                 },
-                Err((task_process_tx_data, error_str)) => {
+                Err((task_process_tx_data, error)) => {
+                    error!("{} tx failed: {}", task_process_tx_data.tx_type, error);
                     let sig = if let Some(sig) = &task_process_tx_data.signature {
                         sig.to_string()
                     } else {
@@ -360,7 +443,7 @@ pub fn handle_task_process_tx_result(
                         hash_status: None,
                         tx_status: TxStatus {
                             status: "FAILED".to_string(),
-                            error: error_str.clone(),
+                            error: error.ui_message(),
                         }
 
                     };
@@ -511,3 +594,354 @@ pub fn handle_task_got_sig_checks(
         }
     }
 }
+
+pub fn handle_task_rpc_health_checks_result(
+    mut commands: Commands,
+    mut rpc_connection: ResMut<RpcConnection>,
+    mut query: Query<(Entity, &mut TaskRpcHealthChecks)>,
+    mut event_writer: EventWriter<EventTxResult>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(results) = block_on(future::poll_once(&mut task.task)) {
+            commands.entity(entity).remove::<TaskRpcHealthChecks>();
+            if rpc_connection.endpoints.is_empty() {
+                continue;
+            }
+
+            for result in results {
+                if let Some(endpoint) = rpc_connection.endpoints.get_mut(result.index) {
+                    match result.slot {
+                        Ok(slot) => {
+                            endpoint.slot = slot;
+                            endpoint.latency_ms = Some(result.latency_ms);
+                            endpoint.record_check(true);
+                        },
+                        Err(e) => {
+                            endpoint.latency_ms = None;
+                            endpoint.last_error = Some(e);
+                            endpoint.record_check(false);
+                        },
+                    }
+                }
+            }
+
+            let highest_slot = rpc_connection
+                .endpoints
+                .iter()
+                .map(|e| e.slot)
+                .max()
+                .unwrap_or(0);
+
+            for index in 0..rpc_connection.endpoints.len() {
+                let healthy = rpc_connection.endpoints[index].unhealthy_reason(highest_slot).is_none();
+                rpc_connection.set_endpoint_healthy(index, healthy);
+            }
+
+            // endpoints are in order of preference, so this also fails back to
+            // the primary once it is healthy again.
+            let healthy_endpoint = rpc_connection
+                .endpoints
+                .iter()
+                .position(|e| e.unhealthy_reason(highest_slot).is_none());
+
+            let active_endpoint = rpc_connection.active_endpoint;
+            if let Some(healthy_endpoint) = healthy_endpoint {
+                if healthy_endpoint != active_endpoint {
+                    let reason = rpc_connection.endpoints[active_endpoint]
+                        .unhealthy_reason(highest_slot)
+                        .unwrap_or("primary recovered".to_string());
+                    let new_url = rpc_connection.endpoints[healthy_endpoint].url.clone();
+                    rpc_connection.set_active_endpoint(healthy_endpoint);
+                    event_writer.send(rpc_failover_result(new_url, reason));
+                }
+            } else {
+                error!("No healthy RPC endpoints, staying on {}", rpc_connection.endpoints[active_endpoint].url);
+            }
+        }
+    }
+}
+
+pub fn handle_task_test_connection_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskTestConnection)>,
+    mut query_result_text: Query<&mut Text, With<TextConnectionTestResult>>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            commands.entity(entity).remove::<TaskTestConnection>();
+
+            let (value, color) = match result {
+                Ok(result) => {
+                    info!("Connection test passed. genesis hash: {}", result.genesis_hash);
+                    (
+                        format!(
+                            "OK v{} | slot {} | rpc {}ms | ws {}ms | genesis {}",
+                            result.solana_core,
+                            result.slot,
+                            result.rpc_latency_ms,
+                            result.ws_latency_ms,
+                            shorten_string(result.genesis_hash, 10)
+                        ),
+                        Color::GREEN
+                    )
+                },
+                Err(e) => {
+                    error!("Connection test failed: {}", e);
+                    (format!("Failed: {}", e), Color::RED)
+                }
+            };
+
+            // the config screen may have been closed while the test was running
+            if let Ok(mut text) = query_result_text.get_single_mut() {
+                text.sections[0].value = value;
+                text.sections[0].style.color = color;
+            }
+        }
+    }
+}
+
+pub fn handle_task_unlock_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskUnlockVault)>,
+    mut app_wallet: ResMut<AppWallet>,
+    mut vault_res: ResMut<WalletVaultResource>,
+    mut attempts: ResMut<UnlockAttempts>,
+    mut query_password: Query<&mut TextInput, With<TextPasswordInput>>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            commands.entity(entity).remove::<TaskUnlockVault>();
+            let attempts_path = vault_res.attempts_path();
+
+            match result {
+                Ok((vault, key)) => {
+                    let wallet = vault_res.set_unlocked(vault, key);
+                    attempts.reset(&attempts_path);
+                    app_wallet.wallet = Some(Arc::new(wallet));
+                    next_state.set(AppScreenState::Mining);
+                },
+                Err(VaultError::Decrypt) => {
+                    attempts.record_failure(get_unix_timestamp());
+                    warn!("Failed unlock attempt {}", attempts.failed);
+                    if let Err(e) = attempts.save(&attempts_path) {
+                        error!("Failed to save unlock attempts: {}", e);
+                    }
+                    if let Ok(mut text_input) = query_password.get_single_mut() {
+                        text_input.text.clear();
+                    }
+                },
+                Err(e) => {
+                    error!("{} (tasks.rs: handle_task_unlock_result)", e);
+                    attempts.error = Some(e.to_string());
+                },
+            }
+        }
+    }
+}
+
+pub fn handle_task_change_password_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskChangePassword)>,
+    mut vault_res: ResMut<WalletVaultResource>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            commands.entity(entity).remove::<TaskChangePassword>();
+            vault_res.password_change_pending = false;
+
+            match result {
+                Ok((vault, key)) => {
+                    // locked while the file was rewritten, the next unlock reads it
+                    if vault_res.vault.is_some() {
+                        vault_res.set_unlocked(vault, key);
+                    }
+                    event_writer_toast.send(EventToast::success("Password changed."));
+                },
+                Err(e) => {
+                    error!("Failed to change password: {}", e);
+                    event_writer_toast.send(EventToast::error(e));
+                },
+            }
+        }
+    }
+}
+
+pub fn handle_task_export_wallet_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskExportWallet)>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            commands.entity(entity).remove::<TaskExportWallet>();
+
+            match result {
+                Ok(path) => {
+                    event_writer_toast.send(EventToast::success(format!("Exported to {}", path.display())));
+                },
+                Err(toast) => {
+                    event_writer_toast.send(toast);
+                },
+            }
+        }
+    }
+}
+
+pub fn handle_task_reveal_seed_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskRevealSeed)>,
+    mut query_revealed: Query<&mut Text, With<TextRevealedSeed>>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            commands.entity(entity).remove::<TaskRevealSeed>();
+
+            match result {
+                Ok(value) => {
+                    if let Ok(mut text) = query_revealed.get_single_mut() {
+                        text.sections[0].value = value.to_string();
+                    }
+                },
+                Err(toast) => {
+                    event_writer_toast.send(toast);
+                },
+            }
+        }
+    }
+}
+
+pub fn handle_task_save_wallet_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskSaveWallet)>,
+    vault_res: Res<WalletVaultResource>,
+    mut attempts: ResMut<UnlockAttempts>,
+    mut ore_app_state: ResMut<OreAppState>,
+    mut miner_status: ResMut<MinerStatusResource>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            commands.entity(entity).remove::<TaskSaveWallet>();
+
+            let saved = match result {
+                Ok(saved) => saved,
+                Err(toast) => {
+                    event_writer_toast.send(toast);
+                    continue;
+                },
+            };
+            if let Some(replaced) = &saved.replaced {
+                event_writer_toast.send(EventToast::info(format!(
+                    "The previous wallet file was kept as {}.",
+                    replaced.display()
+                )));
+            }
+            if let Some(config) = saved.bundle.as_ref().and_then(|bundle| bundle.config.as_ref()) {
+                match AppConfig::from_toml(config) {
+                    Ok(config) => {
+                        if let Err(e) = write_file_atomic(Path::new("config.toml"), toml::to_string(&config).unwrap().as_bytes()) {
+                            error!("{}", e);
+                        }
+                        miner_status.miner_threads = config.threads;
+                        ore_app_state.config = config;
+                    },
+                    Err(e) => {
+                        error!("Backup config is invalid: {}", e);
+                        event_writer_toast.send(EventToast::warning("Backup config is invalid, keeping the current config."));
+                    },
+                }
+            }
+            if saved.bundle.is_some() {
+                event_writer_toast.send(EventToast::success("Backup restored."));
+            }
+            // the failed attempts were against the replaced file
+            attempts.reset(&vault_res.attempts_path());
+            // go to locked screen
+            next_state.set(AppScreenState::Unlock);
+        }
+    }
+}
+
+pub fn handle_task_vanity_search_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskVanitySearch)>,
+    mut vanity_res: ResMut<VanitySearchResource>,
+    mut event_writer_toast: EventWriter<EventToast>,
+    mut set: ParamSet<(
+        Query<(&mut Text, &mut TextGeneratedKeypair, &mut GeneratedSeedPhrase)>,
+        Query<&mut Text, With<TextMnemonicLine1>>,
+        Query<&mut Text, With<TextMnemonicLine2>>,
+        Query<&mut Text, With<TextMnemonicLine3>>,
+    )>,
+    mut query_verify_inputs: Query<&mut TextInput, With<TextSeedVerifyInput>>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            commands.entity(entity).remove::<TaskVanitySearch>();
+            vanity_res.finish();
+
+            let vanity_match = if let Some(vanity_match) = result {
+                vanity_match
+            } else {
+                event_writer_toast.send(EventToast::info(format!(
+                    "Vanity search stopped after {} keys.",
+                    vanity_res.attempts()
+                )));
+                continue;
+            };
+
+            let keypair = Arc::new(vanity_match.keypair);
+            let pubkey = keypair.pubkey().to_string();
+            let words: Vec<String> = vanity_match
+                .phrase
+                .as_ref()
+                .map(|phrase| phrase.split_whitespace().map(|word| word.to_string()).collect())
+                .unwrap_or_default();
+            for (mut text, mut text_keypair, mut seed_phrase) in set.p0().iter_mut() {
+                text.sections[0].value = pubkey.clone();
+                text_keypair.0 = keypair.clone();
+                // a phrase still has to be typed back before saving, a bare keypair has none
+                *seed_phrase = GeneratedSeedPhrase {
+                    backup: vanity_match.phrase.clone().map(SeedBackup::generated),
+                    verify_indices: if words.is_empty() { vec![] } else { pick_verify_indices(words.len()) },
+                    verified: words.is_empty(),
+                    restore: None,
+                };
+            }
+            for mut text_input in query_verify_inputs.iter_mut() {
+                text_input.text.clear();
+            }
+            for mut text in set.p1().iter_mut() {
+                text.sections[0].value = mnemonic_line(&words, 0);
+            }
+            for mut text in set.p2().iter_mut() {
+                text.sections[0].value = mnemonic_line(&words, 1);
+            }
+            for mut text in set.p3().iter_mut() {
+                text.sections[0].value = mnemonic_line(&words, 2);
+            }
+
+            info!("Vanity search found {} after {} keys", pubkey, vanity_res.attempts());
+            event_writer_toast.send(EventToast::success(format!(
+                "Found {} after {} keys in {}. Set a password and click Save.",
+                pubkey,
+                vanity_res.attempts(),
+                human_duration(vanity_res.elapsed().as_secs_f64())
+            )));
+        }
+    }
+}
+
+/// One of the three rows of four words shown under a generated key.
+fn mnemonic_line(words: &[String], line: usize) -> String {
+    let mut value = String::new();
+    for word in words.iter().skip(line * 4).take(4) {
+        value += word;
+        value += "     ";
+    }
+    value
+}
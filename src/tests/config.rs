@@ -0,0 +1,47 @@
+use crate::{AppConfig, Cluster, Explorer, ExplorerLink};
+
+const LEGACY_CONFIG: &str = r#"
+rpc_url = "https://example.solana-mainnet.quiknode.pro/abc"
+ws_url = "wss://example.solana-mainnet.quiknode.pro/abc"
+is_devnet = true
+threads = 4
+ui_fetch_interval = 1000
+tx_send_interval = 3000
+tx_sigs_check_interval = 1000
+"#;
+
+#[test]
+fn infers_cluster_from_rpc_url() {
+    assert_eq!(Cluster::from_rpc_url("https://api.mainnet-beta.solana.com"), Cluster::Mainnet);
+    assert_eq!(Cluster::from_rpc_url("https://api.devnet.solana.com/"), Cluster::Devnet);
+    assert_eq!(Cluster::from_rpc_url("http://127.0.0.1:8899"), Cluster::Localnet);
+    assert_eq!(Cluster::from_rpc_url("http://localhost:8899"), Cluster::Localnet);
+    // only the host counts, not a devnet path or query
+    assert_eq!(Cluster::from_rpc_url("https://rpc.example.com/devnet?key=mainnet"), Cluster::Custom);
+}
+
+#[test]
+fn legacy_config_ignores_is_devnet() {
+    // is_devnet was always written as true, the rpc url is the only hint
+    let config = AppConfig::from_toml(LEGACY_CONFIG).unwrap();
+    assert_eq!(config.cluster, Cluster::Mainnet);
+    assert_eq!(config.threads, 4);
+
+    // an explicit cluster always wins
+    let config = AppConfig::from_toml(&format!("cluster = \"devnet\"\n{}", LEGACY_CONFIG)).unwrap();
+    assert_eq!(config.cluster, Cluster::Devnet);
+}
+
+#[test]
+fn custom_explorer_needs_id_placeholder() {
+    let link = ExplorerLink::Tx("sig");
+    let url = Explorer::Custom.url(link, Cluster::Mainnet, "", "https://ex.com/{type}/{id}");
+    assert_eq!(url, "https://ex.com/tx/sig");
+
+    // blank or hand edited templates fall back to solscan
+    for template in ["", "https://ex.com/tx"] {
+        assert!(!Explorer::is_valid_custom_template(template));
+        let url = Explorer::Custom.url(ExplorerLink::Tx("sig"), Cluster::Mainnet, "", template);
+        assert_eq!(url, "https://solscan.io/tx/sig");
+    }
+}
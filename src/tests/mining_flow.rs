@@ -0,0 +1,179 @@
+use std::{sync::Arc, thread::sleep, time::Duration};
+
+use drillx::Solution;
+use ore_api::consts::BUS_ADDRESSES;
+use solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::TransactionError};
+
+use crate::{
+    events::EventStartStopMining,
+    ore_rpc::{mock::{MockLanding, MockOreRpc, MOCK_BASE_REWARD_RATE, MOCK_BUS_REWARDS}, OreRpc},
+    ore_utils::{get_mine_ix, proof_pubkey},
+    utils::get_unix_timestamp,
+    BusAnalyticsResource, BussesResource, MinerStatusResource, ProofAccountResource, RpcConnection, RpcEndpoint, TxProcessor,
+};
+
+use super::TestApp;
+
+#[test]
+fn mine_submit_confirm() {
+    let mut test_app = TestApp::registered();
+    test_app.fetch_ui_data();
+    let challenge = test_app.resource::<ProofAccountResource>().challenge.clone();
+    assert_eq!(test_app.resource::<ProofAccountResource>().stake, 0);
+
+    test_app.send(EventStartStopMining);
+    test_app.update_until("mine tx processor", |world| {
+        world.query::<&TxProcessor>().iter(world).count() == 1
+    });
+    assert_eq!(test_app.tx_processors()[0].0, "Mine");
+
+    let result = test_app.wait_for_tx_result("Mine");
+    assert!(result.status.starts_with("SUCCESS"), "{:?}", result);
+    assert!(test_app.tx_processors().is_empty());
+
+    let proof = test_app.resource::<ProofAccountResource>();
+    assert_eq!(proof.stake, MOCK_BASE_REWARD_RATE);
+    assert_eq!(proof.total_hashes, 1);
+    assert_ne!(proof.challenge, challenge);
+
+    let mined_bus = (0..8)
+        .filter_map(|id| test_app.rpc.bus(id))
+        .find(|bus| bus.rewards < MOCK_BUS_REWARDS)
+        .expect("no bus paid out the reward");
+    assert_eq!(mined_bus.rewards, MOCK_BUS_REWARDS - MOCK_BASE_REWARD_RATE);
+}
+
+#[test]
+fn reads_and_sends_fail_over() {
+    let mut test_app = TestApp::registered();
+    let dead = Arc::new(MockOreRpc::new());
+    dead.chain().fail_requests = u32::MAX;
+    {
+        let live: Arc<dyn OreRpc> = test_app.rpc.clone();
+        let mut rpc_connection = test_app.app.world.resource_mut::<RpcConnection>();
+        rpc_connection.set_endpoints(vec![
+            RpcEndpoint::with_client("dead".to_string(), dead.clone()),
+            RpcEndpoint::with_client("live".to_string(), live),
+        ]);
+    }
+
+    // the first read fails over without waiting for a health check
+    test_app.fetch_ui_data();
+    let failover = test_app.wait_for_tx_result("RPC");
+    assert_eq!(failover.status, "FAILOVER");
+    assert!(failover.error.contains("mock rpc request failed"), "{:?}", failover);
+    let rpc_connection = test_app.resource::<RpcConnection>();
+    assert_eq!(rpc_connection.active_url(), Some("live".to_string()));
+    assert_eq!(rpc_connection.endpoints[0].recent_checks.back(), Some(&false));
+
+    test_app.send(EventStartStopMining);
+    let result = test_app.wait_for_tx_result("Mine");
+    assert!(result.status.starts_with("SUCCESS"), "{:?}", result);
+    assert!(dead.chain().sent.is_empty());
+    assert!(!test_app.rpc.chain().sent.is_empty());
+}
+
+#[test]
+fn failed_mine_tx_is_reported() {
+    let mut test_app = TestApp::registered();
+    test_app.rpc.set_landing(MockLanding::Fail(TransactionError::AccountInUse));
+    test_app.fetch_ui_data();
+
+    test_app.send(EventStartStopMining);
+    let result = test_app.wait_for_tx_result("Mine");
+    assert_eq!(result.status, "FAILED");
+    assert!(!result.error.is_empty());
+
+    let proof = test_app.rpc.proof(test_app.wallet.pubkey()).unwrap();
+    assert_eq!(proof.balance, 0);
+    assert!(test_app.tx_processors().is_empty());
+}
+
+#[test]
+fn start_without_proof_registers() {
+    let mut test_app = TestApp::new();
+    test_app.fetch_ui_data();
+    assert_eq!(test_app.resource::<ProofAccountResource>().challenge, "Not Found");
+
+    test_app.send(EventStartStopMining);
+    let result = test_app.wait_for_tx_result("Register");
+    assert_eq!(result.status, "SUCCESS");
+    assert!(test_app.rpc.proof(test_app.wallet.pubkey()).is_some());
+    // registering doesn't start the miner
+    assert_eq!(test_app.resource::<MinerStatusResource>().miner_status, "STOPPED");
+}
+
+#[test]
+fn stop_cancels_mining() {
+    let mut test_app = TestApp::registered();
+    test_app.fetch_ui_data();
+
+    test_app.send(EventStartStopMining);
+    test_app.update_until("miner to start", |world| {
+        world.resource::<MinerStatusResource>().miner_status != "STOPPED"
+    });
+
+    test_app.send(EventStartStopMining);
+    test_app.update();
+    assert_eq!(test_app.resource::<MinerStatusResource>().miner_status, "STOPPED");
+
+    // hashes or txs still in flight don't restart the miner
+    for _ in 0..50 {
+        test_app.update();
+        sleep(Duration::from_millis(5));
+    }
+    assert_eq!(test_app.resource::<MinerStatusResource>().miner_status, "STOPPED");
+}
+
+#[test]
+fn mine_ix_rejects_unknown_bus() {
+    let signer = Pubkey::new_unique();
+    let last_bus = BUS_ADDRESSES.len() - 1;
+    let ix = get_mine_ix(signer, Solution::new([0; 16], [0; 8]), last_bus).unwrap();
+    assert!(ix.accounts.iter().any(|account| account.pubkey == BUS_ADDRESSES[last_bus]));
+    // never falls back to another bus
+    assert!(get_mine_ix(signer, Solution::new([0; 16], [0; 8]), BUS_ADDRESSES.len()).is_err());
+}
+
+#[test]
+fn malformed_accounts_are_unavailable() {
+    let mut test_app = TestApp::registered();
+    {
+        // an upgraded program resizing the accounts
+        let mut chain = test_app.rpc.chain();
+        let proof_address = proof_pubkey(test_app.wallet.pubkey());
+        chain.accounts.get_mut(&proof_address).unwrap().data.truncate(40);
+        chain.accounts.get_mut(&BUS_ADDRESSES[2]).unwrap().data.push(0);
+    }
+    test_app.fetch_ui_data();
+    assert_eq!(test_app.resource::<ProofAccountResource>().challenge, "unavailable");
+
+    let busses = &test_app.resource::<BussesResource>().busses;
+    assert_eq!(busses.len(), BUS_ADDRESSES.len() - 1);
+    assert!(busses.iter().all(|bus| bus.id != 2));
+
+    // an unreadable proof must not be mistaken for a missing one
+    test_app.send(EventStartStopMining);
+    for _ in 0..10 {
+        test_app.update();
+    }
+    assert_eq!(test_app.resource::<MinerStatusResource>().miner_status, "STOPPED");
+    assert!(test_app.tx_processors().is_empty());
+}
+
+#[test]
+fn bus_rates_follow_the_epoch_reset() {
+    let now = get_unix_timestamp() as i64;
+    let mut analytics = BusAnalyticsResource::default();
+    assert_eq!(analytics.hits_per_minute(0), 0.0);
+    // the first update lands partway through an epoch, that one is timed from now
+    analytics.reset(now - 600);
+    analytics.record_hit(0, 100);
+
+    // later epochs are timed from the on chain reset, not from when it was seen
+    analytics.reset(now - 120);
+    analytics.record_hit(0, 100);
+    assert!((analytics.hits_per_minute(0) - 0.5).abs() < 0.01);
+    assert!((analytics.rewards_drained_per_minute(0) - 50.0).abs() < 1.0);
+    assert_eq!(analytics.minutes_until_depleted(1, 100), None);
+}
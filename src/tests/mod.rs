@@ -0,0 +1,216 @@
+//! Headless harness for the event pipeline. The app is built with
+//! `MinimalPlugins`, no window or `AssetServer`, a fake wallet and
+//! `MockOreRpc` standing in for the cluster.
+
+mod config;
+mod mining_flow;
+mod seed_phrase;
+mod vanity;
+mod wallet_flow;
+
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::*;
+use bytemuck::Zeroable;
+use ore_api::state::Proof;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::{
+    add_app_events,
+    add_app_systems,
+    events::*,
+    insert_app_resources,
+    ore_rpc::{mock::{MockOreRpc, MockTokenAccount}, OreRpc},
+    ore_utils::get_ore_mint,
+    utils::get_unix_timestamp, vault::{WalletVault, WalletVaultResource},
+    AppConfig, AppScreenState, AppWallet,
+    EntityTaskFetchUiData, EntityTaskHandler, EventTxResult, ProofAccountResource, RpcConnection,
+    RpcEndpoint, TxProcessor,
+};
+
+const UPDATE_TIMEOUT: Duration = Duration::from_secs(60);
+const UPDATE_SLEEP: Duration = Duration::from_millis(5);
+const WALLET_LAMPORTS: u64 = 1_000_000_000;
+
+#[derive(Clone, Debug)]
+pub struct TxResultEntry {
+    pub tx_type: String,
+    pub status: String,
+    pub error: String,
+}
+
+/// Every `EventTxResult` seen, the ui normally turns these into list items.
+#[derive(Resource, Default)]
+pub struct TxResultLog(pub Vec<TxResultEntry>);
+
+fn record_tx_results(
+    mut event_reader: EventReader<EventTxResult>,
+    mut log: ResMut<TxResultLog>,
+) {
+    for ev in event_reader.read() {
+        log.0.push(TxResultEntry {
+            tx_type: ev.tx_type.clone(),
+            status: ev.tx_status.status.clone(),
+            error: ev.tx_status.error.clone(),
+        });
+    }
+}
+
+pub struct TestApp {
+    pub app: App,
+    pub rpc: Arc<MockOreRpc>,
+    pub wallet: Arc<Keypair>,
+}
+
+impl TestApp {
+    /// Unlocked wallet with some sol and no proof account yet.
+    pub fn new() -> Self {
+        let config = AppConfig {
+            threads: 1,
+            ui_fetch_interval: 50,
+            tx_send_interval: 20,
+            ..Default::default()
+        };
+
+        let rpc = Arc::new(MockOreRpc::new());
+        let wallet = Arc::new(Keypair::new());
+        rpc.set_balance(wallet.pubkey(), WALLET_LAMPORTS);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_state(AppScreenState::Mining);
+        insert_app_resources(&mut app, &config);
+        add_app_events(&mut app);
+        add_app_systems(&mut app);
+        app.init_resource::<TxResultLog>()
+            .add_systems(Update, record_tx_results);
+
+        app.world.spawn((EntityTaskHandler, Name::new("EntityTaskHandler")));
+        app.world.spawn((EntityTaskFetchUiData, Name::new("EntityFetchUiData")));
+
+        app.world.resource_mut::<AppWallet>().wallet = Some(wallet.clone());
+        {
+            let client: Arc<dyn OreRpc> = rpc.clone();
+            let mut rpc_connection = app.world.resource_mut::<RpcConnection>();
+            rpc_connection.set_endpoints(vec![RpcEndpoint::with_client("mock".to_string(), client.clone())]);
+            rpc_connection.airdrop = Some(client);
+        }
+
+        Self { app, rpc, wallet }
+    }
+
+    /// Wallet with a proof that can mine right away and an ore token account.
+    pub fn registered() -> Self {
+        let test_app = Self::new();
+        let mut proof = Proof::zeroed();
+        proof.authority = test_app.wallet.pubkey();
+        proof.miner = test_app.wallet.pubkey();
+        proof.challenge = [7; 32];
+        // a hash is due, so the miner only has to meet the min difficulty
+        proof.last_hash_at = get_unix_timestamp() as i64 - 120;
+        test_app.rpc.set_proof(proof);
+        test_app.create_ore_token_account(0);
+        test_app
+    }
+
+    /// Saves the wallet as the only entry of a vault in the temp dir and
+    /// unlocks it. Returns the vault file path.
+    pub fn with_vault(&mut self, password: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("ore-app-test-{}.data", self.wallet.pubkey()));
+        WalletVault::new("Mining", &self.wallet).save(&path, password).unwrap();
+
+        let mut vault_res = self.app.world.resource_mut::<WalletVaultResource>();
+        vault_res.path = path.clone();
+        vault_res.unlock(password).unwrap();
+        path
+    }
+
+    pub fn create_ore_token_account(&self, amount: u64) {
+        let address = spl_associated_token_account::get_associated_token_address(
+            &self.wallet.pubkey(),
+            &get_ore_mint(),
+        );
+        self.rpc.chain().token_accounts.insert(address, MockTokenAccount {
+            owner: self.wallet.pubkey(),
+            mint: get_ore_mint(),
+            amount,
+        });
+    }
+
+    pub fn send<E: Event>(&mut self, event: E) {
+        self.app.world.send_event(event);
+    }
+
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    /// Runs frames until `condition` holds, panics after `UPDATE_TIMEOUT`.
+    pub fn update_until(&mut self, waiting_for: &str, condition: impl Fn(&mut World) -> bool) {
+        let started_at = Instant::now();
+        loop {
+            self.app.update();
+            if condition(&mut self.app.world) {
+                return;
+            }
+            if started_at.elapsed() > UPDATE_TIMEOUT {
+                panic!("Timed out waiting for {}", waiting_for);
+            }
+            sleep(UPDATE_SLEEP);
+        }
+    }
+
+    /// Screen gated systems run as in the app, the transition is applied
+    /// at the start of the frame this runs.
+    pub fn set_state(&mut self, state: AppScreenState) {
+        self.app.world.resource_mut::<NextState<AppScreenState>>().set(state);
+        self.app.update();
+    }
+
+    /// Runs the frame that spawns a `T` task, then frames until it is done.
+    pub fn wait_for_task<T: Component>(&mut self, waiting_for: &str) {
+        self.app.update();
+        self.update_until(waiting_for, |world| world.query::<&T>().iter(world).next().is_none());
+    }
+
+    /// Fetches ui data until the proof resource reflects the mock proof.
+    pub fn fetch_ui_data(&mut self) {
+        self.send(EventFetchUiDataFromRpc);
+        self.update_until("ui data", |world| {
+            world.resource::<ProofAccountResource>().challenge != "loading..."
+        });
+    }
+
+    pub fn resource<R: Resource>(&self) -> &R {
+        self.app.world.resource::<R>()
+    }
+
+    /// (tx type, status) of every live tx processor.
+    pub fn tx_processors(&mut self) -> Vec<(String, String)> {
+        let mut query = self.app.world.query::<&TxProcessor>();
+        query
+            .iter(&self.app.world)
+            .map(|tx_processor| (tx_processor.tx_type.to_string(), tx_processor.status.clone()))
+            .collect()
+    }
+
+    pub fn tx_results(&self) -> Vec<TxResultEntry> {
+        self.resource::<TxResultLog>().0.clone()
+    }
+
+    pub fn wait_for_tx_result(&mut self, tx_type: &str) -> TxResultEntry {
+        let tx_type = tx_type.to_string();
+        self.update_until(&format!("{} tx result", tx_type), |world| {
+            world.resource::<TxResultLog>().0.iter().any(|entry| entry.tx_type == tx_type)
+        });
+        self.tx_results()
+            .into_iter()
+            .find(|entry| entry.tx_type == tx_type)
+            .unwrap()
+    }
+}
@@ -0,0 +1,67 @@
+use bip39::{Language, Mnemonic, MnemonicType};
+use solana_sdk::signer::Signer;
+
+use crate::seed_phrase::{
+    complete_word, derive_keypair, normalize_phrase, parse_mnemonic, pick_verify_indices,
+    word_suggestions, DerivationScheme, VERIFY_WORDS,
+};
+
+pub const PHRASE_12: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+#[test]
+fn parses_12_and_24_words() {
+    assert!(parse_mnemonic(PHRASE_12).is_ok());
+    // pasted with odd spacing and casing
+    assert!(parse_mnemonic(&format!("  {}\n", PHRASE_12.to_uppercase())).is_ok());
+
+    let phrase_24 = Mnemonic::new(MnemonicType::Words24, Language::English).into_phrase();
+    assert!(parse_mnemonic(&phrase_24).is_ok());
+
+    let phrase_15 = Mnemonic::new(MnemonicType::Words15, Language::English).into_phrase();
+    assert!(parse_mnemonic(&phrase_15).is_err());
+}
+
+#[test]
+fn rejects_bad_words_and_checksum() {
+    let unknown = PHRASE_12.replace("about", "aboot");
+    assert_eq!(parse_mnemonic(&unknown).unwrap_err(), "Unknown word: aboot");
+
+    // every word is valid but the last one breaks the checksum
+    let bad_checksum = PHRASE_12.replace("about", "abandon");
+    assert_eq!(parse_mnemonic(&bad_checksum).unwrap_err(), "Invalid seed phrase checksum.");
+}
+
+#[test]
+fn derivation_follows_scheme_account_and_passphrase() {
+    let mnemonic = parse_mnemonic(PHRASE_12).unwrap();
+    let derive = |passphrase, scheme, account| {
+        derive_keypair(&mnemonic, passphrase, scheme, account).unwrap().pubkey()
+    };
+
+    let default = derive("", DerivationScheme::Bip44Change, 0);
+    assert_eq!(default, derive("", DerivationScheme::Bip44Change, 0));
+    assert_ne!(default, derive("", DerivationScheme::Bip44Change, 1));
+    assert_ne!(default, derive("", DerivationScheme::Bip44, 0));
+    assert_ne!(default, derive("", DerivationScheme::Root, 0));
+    assert_ne!(default, derive("secret", DerivationScheme::Bip44Change, 0));
+}
+
+#[test]
+fn suggests_and_completes_words() {
+    assert_eq!(word_suggestions("abandon ab"), vec!["abandon", "ability", "able", "about", "above", "absent"]);
+    assert!(word_suggestions("abandon ").is_empty());
+    assert!(word_suggestions("zzz").is_empty());
+
+    assert_eq!(complete_word("abandon abi", "ability"), "abandon ability ");
+    assert_eq!(normalize_phrase(&complete_word("abi", "ability")), "ability");
+}
+
+#[test]
+fn picks_distinct_words_to_verify() {
+    for _ in 0..20 {
+        let indices = pick_verify_indices(12);
+        assert_eq!(indices.len(), VERIFY_WORDS);
+        assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(indices.iter().all(|index| *index < 12));
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::{atomic::{AtomicU64, Ordering}, Arc};
+
+use bevy::prelude::*;
+use crossbeam_channel::bounded;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use super::TestApp;
+use crate::{
+    events::{EventStartVanitySearch, EventStopVanitySearch},
+    seed_phrase::{derive_keypair, parse_mnemonic, DerivationScheme},
+    tasks::TaskVanitySearch,
+    ui::components::{GeneratedSeedPhrase, TextGeneratedKeypair},
+    utils::human_duration,
+    vanity::{find_vanity_par, VanityPattern, VanitySearchMessage, VanitySearchResource, VanitySource},
+    AppScreenState,
+};
+
+#[test]
+fn validates_patterns() {
+    assert!(VanityPattern::new("", " ", false).is_err());
+    assert!(VanityPattern::new("abcde", "fghij", false).is_err());
+    // 0, O, I and l are not in the base58 alphabet
+    assert_eq!(VanityPattern::new("0re", "", false).unwrap_err(), "'0' can never appear in an address.");
+    assert!(VanityPattern::new("Ore", "", false).is_err());
+    assert!(VanityPattern::new("Ore", "", true).is_ok());
+    assert!(VanityPattern::new("", "l", true).is_ok());
+
+    let pattern = VanityPattern::new(" Ab ", "9", false).unwrap();
+    assert_eq!(pattern.prefix, "Ab");
+    assert!(pattern.matches("Abc1239"));
+    assert!(!pattern.matches("abc1239"));
+    assert!(!pattern.matches("Ab"));
+
+    let pattern = VanityPattern::new("ore", "Z", true).unwrap();
+    assert!(pattern.matches("oRExyzz"));
+    assert!(!pattern.matches("orxyzz"));
+}
+
+#[test]
+fn expected_attempts_count_case_variants() {
+    assert_eq!(VanityPattern::new("A", "", false).unwrap().expected_attempts(), 58.0);
+    assert_eq!(VanityPattern::new("A", "", true).unwrap().expected_attempts(), 29.0);
+    // digits and 'o' only have one base58 character either way
+    assert_eq!(VanityPattern::new("1o", "", true).unwrap().expected_attempts(), 58.0 * 58.0);
+    assert_eq!(VanityPattern::new("ab", "c", true).unwrap().expected_attempts(), 29.0 * 29.0 * 29.0);
+
+    assert_eq!(human_duration(42.4), "42s");
+    assert_eq!(human_duration(200.0), "3m 20s");
+    assert_eq!(human_duration(90_000.0), "1d 1h");
+}
+
+#[test]
+fn finds_keypairs_and_seed_phrases() {
+    let (sender, receiver) = bounded::<VanitySearchMessage>(1);
+    let attempts = Arc::new(AtomicU64::new(0));
+    let pattern = VanityPattern::new("", "a", true).unwrap();
+
+    let found = find_vanity_par(pattern.clone(), VanitySource::Keypair, 2, attempts.clone(), receiver.clone(), sender.clone()).unwrap();
+    assert!(pattern.matches(&found.keypair.pubkey().to_string()));
+    assert!(found.phrase.is_none());
+    assert!(attempts.load(Ordering::Relaxed) > 0);
+
+    while let Ok(_) = receiver.try_recv() {}
+    let found = find_vanity_par(pattern.clone(), VanitySource::SeedPhrase, 2, attempts, receiver, sender).unwrap();
+    // the phrase derives the same key as a generated wallet would
+    let mnemonic = parse_mnemonic(found.phrase.as_ref().unwrap()).unwrap();
+    let derived = derive_keypair(&mnemonic, "", DerivationScheme::default(), 0).unwrap();
+    assert_eq!(derived.pubkey(), found.keypair.pubkey());
+    assert!(pattern.matches(&derived.pubkey().to_string()));
+}
+
+fn spawn_generated_keypair(test_app: &mut TestApp) -> Entity {
+    test_app.set_state(AppScreenState::WalletSetup);
+    test_app.app.world.spawn((
+        Text::from_section("", TextStyle::default()),
+        TextGeneratedKeypair(Arc::new(Keypair::new())),
+        GeneratedSeedPhrase::default(),
+    )).id()
+}
+
+fn search_finished(world: &mut World) -> bool {
+    world.query::<&TaskVanitySearch>().iter(world).count() == 0
+        && world.resource::<VanitySearchResource>().finished_in.is_some()
+}
+
+#[test]
+fn vanity_search_hands_match_to_save_flow() {
+    let mut test_app = TestApp::new();
+    let entity = spawn_generated_keypair(&mut test_app);
+
+    test_app.send(EventStartVanitySearch {
+        prefix: String::new(),
+        suffix: "b".to_string(),
+        ignore_case: true,
+        source: VanitySource::Keypair,
+    });
+    test_app.update_until("vanity match", search_finished);
+
+    let world = &test_app.app.world;
+    let pubkey = world.get::<TextGeneratedKeypair>(entity).unwrap().0.pubkey().to_string();
+    assert!(pubkey.to_lowercase().ends_with('b'));
+    assert_eq!(world.get::<Text>(entity).unwrap().sections[0].value, pubkey);
+    // a bare keypair has no phrase to verify, it can be saved right away
+    let seed_phrase = world.get::<GeneratedSeedPhrase>(entity).unwrap();
+    assert!(seed_phrase.verified);
+    assert!(seed_phrase.backup.is_none());
+    assert!(test_app.resource::<VanitySearchResource>().attempts() > 0);
+}
+
+#[test]
+fn vanity_search_can_be_stopped() {
+    let mut test_app = TestApp::new();
+    let entity = spawn_generated_keypair(&mut test_app);
+    let before = test_app.app.world.get::<TextGeneratedKeypair>(entity).unwrap().0.pubkey();
+
+    // invalid patterns never start a search
+    test_app.send(EventStartVanitySearch {
+        prefix: "0".to_string(),
+        suffix: String::new(),
+        ignore_case: false,
+        source: VanitySource::Keypair,
+    });
+    test_app.update();
+    assert!(test_app.resource::<VanitySearchResource>().started_at.is_none());
+
+    test_app.send(EventStartVanitySearch {
+        prefix: "zzzzzzzz".to_string(),
+        suffix: String::new(),
+        ignore_case: false,
+        source: VanitySource::Keypair,
+    });
+    test_app.update_until("vanity search running", |world| {
+        world.resource::<VanitySearchResource>().attempts() > 0
+    });
+    test_app.send(EventStopVanitySearch);
+    test_app.update_until("vanity search stopped", search_finished);
+
+    let after = test_app.app.world.get::<TextGeneratedKeypair>(entity).unwrap().0.pubkey();
+    assert_eq!(before, after);
+}
@@ -0,0 +1,473 @@
+use std::{
+    fs::{self, File},
+    time::Duration,
+};
+
+use bevy::{
+    input::{keyboard::KeyboardInput, mouse::{MouseButtonInput, MouseWheel}},
+    prelude::*,
+};
+use cocoon::Cocoon;
+use serde::Serialize;
+use solana_sdk::{signature::{read_keypair_file, Keypair}, signer::Signer};
+
+use crate::{
+    events::{
+        EventAddWallet, EventChangePassword, EventClaimOreRewards, EventExportWallet,
+        EventFetchUiDataFromRpc, EventLock, EventRemoveWallet, EventRenameWallet,
+        EventStartStopMining, EventSwitchWallet, EventUnlock, EventWatchPubkey, WalletExport,
+    },
+    ore_utils::get_ore_mint,
+    seed_phrase::{derive_keypair, parse_mnemonic, SeedBackup},
+    tasks::{TaskChangePassword, TaskExportWallet, TaskUnlockVault},
+    ui::components::{TextInput, TextPasswordInput},
+    utils::get_unix_timestamp,
+    vault::{
+        copy_to_backup, BackupBundle, Kdf, UnlockAttempts, VaultError, VaultHeader, WalletVault,
+        WalletVaultResource, EXPORT_DIR, MIN_KDF_ITERATIONS, UNLOCK_BASE_DELAY_SECS,
+        UNLOCK_FREE_ATTEMPTS, UNLOCK_MAX_DELAY_SECS, VAULT_FORMAT_VERSION, VAULT_MAGIC,
+    },
+    auto_lock_when_idle, run_if_has_some_wallet, AppScreenState, AppWallet, AutoLockResource,
+    MinerStatusResource, ProofAccountResource, RpcConnection,
+};
+
+use super::{seed_phrase::PHRASE_12, TestApp};
+
+const CLAIMABLE: u64 = 5_000_000_000;
+
+#[test]
+fn claim_moves_stake_to_token_account() {
+    let mut test_app = TestApp::registered();
+    let mut proof = test_app.rpc.proof(test_app.wallet.pubkey()).unwrap();
+    proof.balance = CLAIMABLE;
+    test_app.rpc.set_proof(proof);
+    test_app.fetch_ui_data();
+    assert_eq!(test_app.resource::<ProofAccountResource>().stake, CLAIMABLE);
+
+    test_app.send(EventClaimOreRewards);
+    let result = test_app.wait_for_tx_result("Claim");
+    assert_eq!(result.status, "SUCCESS");
+    assert!(test_app.tx_processors().is_empty());
+
+    let token_account = spl_associated_token_account::get_associated_token_address(
+        &test_app.wallet.pubkey(),
+        &get_ore_mint(),
+    );
+    assert_eq!(test_app.rpc.chain().token_accounts[&token_account].amount, CLAIMABLE);
+
+    test_app.send(EventFetchUiDataFromRpc);
+    test_app.update_until("claimed stake", |world| {
+        world.resource::<ProofAccountResource>().stake == 0
+    });
+}
+
+#[test]
+fn lock_clears_wallet_and_blocks_mining() {
+    let mut test_app = TestApp::registered();
+    test_app.fetch_ui_data();
+
+    test_app.send(EventLock);
+    test_app.update();
+    // the state transition is applied on the next frame
+    test_app.update();
+    assert!(test_app.resource::<AppWallet>().wallet.is_none());
+    assert_eq!(
+        *test_app.resource::<bevy::prelude::State<AppScreenState>>().get(),
+        AppScreenState::Unlock
+    );
+
+    test_app.send(EventStartStopMining);
+    for _ in 0..10 {
+        test_app.update();
+    }
+    assert_eq!(test_app.resource::<MinerStatusResource>().miner_status, "STOPPED");
+    assert!(test_app.tx_results().is_empty());
+}
+
+#[test]
+fn fetch_backs_off_and_retries_network_errors() {
+    let mut test_app = TestApp::registered();
+    test_app.rpc.chain().fail_requests = 1;
+
+    test_app.send(EventFetchUiDataFromRpc);
+    test_app.update_until("failed fetch", |world| {
+        world.resource::<RpcConnection>().has_pending_fetch_retry()
+    });
+    assert_eq!(test_app.resource::<ProofAccountResource>().challenge, "loading...");
+
+    test_app.update_until("retried fetch", |world| {
+        world.resource::<ProofAccountResource>().challenge != "loading..."
+    });
+    assert!(!test_app.resource::<RpcConnection>().has_pending_fetch_retry());
+}
+
+#[test]
+fn legacy_wallet_file_opens_as_vault() {
+    let keypair = Keypair::new();
+    let path = std::env::temp_dir().join(format!("ore-app-test-{}.data", keypair.pubkey()));
+    let mut file = File::create(&path).unwrap();
+    Cocoon::new(b"password").dump(keypair.to_bytes().to_vec(), &mut file).unwrap();
+
+    let mut vault_res = WalletVaultResource::default();
+    vault_res.path = path.clone();
+    assert!(vault_res.unlock("wrong").is_err());
+    assert_eq!(vault_res.unlock("password").unwrap().pubkey(), keypair.pubkey());
+    assert_eq!(vault_res.vault.as_ref().unwrap().wallets.len(), 1);
+
+    // unlocking upgrades the file to the headered format
+    let header = VaultHeader::read(&path).unwrap().unwrap();
+    assert_eq!(header.version, VAULT_FORMAT_VERSION);
+    assert_eq!(header.pubkey_hint, Some(keypair.pubkey().to_string()));
+
+    vault_res.update(|vault| vault.rename(0, "Mining")).unwrap();
+    let vault = WalletVault::load(&path, "password").unwrap();
+    assert_eq!(vault.active_wallet().name, "Mining");
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn vault_header_tracks_kdf_and_version() {
+    let keypair = Keypair::new();
+    let path = std::env::temp_dir().join(format!("ore-app-test-{}.data", keypair.pubkey()));
+    let vault = WalletVault::new("Mining", &keypair);
+    vault.save_with_kdf(&path, "password", Kdf::pbkdf2(MIN_KDF_ITERATIONS)).unwrap();
+    assert!(fs::read(&path).unwrap().starts_with(VAULT_MAGIC));
+    let header = VaultHeader::read(&path).unwrap().unwrap();
+    assert_eq!(header.kdf.iterations(), MIN_KDF_ITERATIONS);
+
+    // a stronger configured kdf is applied on the next unlock
+    let mut vault_res = WalletVaultResource::default().with_kdf_iterations(Some(MIN_KDF_ITERATIONS * 2));
+    vault_res.path = path.clone();
+    assert_eq!(vault_res.unlock("password").unwrap().pubkey(), keypair.pubkey());
+    let header = VaultHeader::read(&path).unwrap().unwrap();
+    assert_eq!(header.kdf.iterations(), MIN_KDF_ITERATIONS * 2);
+    assert!(WalletVault::load(&path, "wrong").is_err());
+
+    // files from a newer app are refused instead of misread
+    let mut bytes = fs::read(&path).unwrap();
+    let newer = VaultHeader { version: VAULT_FORMAT_VERSION + 1, ..header };
+    let header_bytes = bincode::serialize(&newer).unwrap();
+    let header_len = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize;
+    bytes.splice(12..12 + header_len, header_bytes);
+    fs::write(&path, bytes).unwrap();
+    assert!(matches!(VaultHeader::read(&path), Err(VaultError::Corrupted(_))));
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn seed_backup_is_stored_encrypted() {
+    #[derive(Serialize)]
+    struct VaultWithoutSeeds {
+        active: usize,
+        wallets: Vec<(String, Vec<u8>)>,
+    }
+
+    let keypair = Keypair::new();
+    // vaults written before seed backups still open, without a seed
+    let old_bytes = bincode::serialize(&VaultWithoutSeeds {
+        active: 0,
+        wallets: vec![("Mining".to_string(), keypair.to_bytes().to_vec())],
+    })
+    .unwrap();
+    let vault = WalletVault::from_bytes(&old_bytes).unwrap();
+    assert_eq!(vault.active_wallet().pubkey(), keypair.pubkey());
+    assert!(vault.active_wallet().seed.is_none());
+
+    let mnemonic = parse_mnemonic(PHRASE_12).unwrap();
+    let seed = SeedBackup::generated(PHRASE_12.to_string());
+    let derived = derive_keypair(&mnemonic, "", seed.scheme, seed.account).unwrap();
+    let path = std::env::temp_dir().join(format!("ore-app-test-{}.data", derived.pubkey()));
+    WalletVault::new("Wallet 1", &derived).with_seed(Some(seed.clone())).save(&path, "password").unwrap();
+
+    assert!(!fs::read(&path).unwrap().windows(7).any(|bytes| bytes == b"abandon"));
+    assert!(WalletVault::load(&path, "wrong").is_err());
+    let vault = WalletVault::load(&path, "password").unwrap();
+    assert_eq!(vault.active_wallet().seed, Some(seed));
+    assert_eq!(vault.active_wallet().pubkey(), derived.pubkey());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn vault_changes_are_saved() {
+    let mut test_app = TestApp::new();
+    let path = test_app.with_vault("password");
+    test_app.set_state(AppScreenState::SettingsWallet);
+    let treasury = Keypair::new();
+
+    test_app.send(EventAddWallet { name: "Treasury".to_string(), keypair: treasury.insecure_clone(), seed: None });
+    test_app.update();
+    test_app.send(EventRenameWallet { index: 0, name: "Miner".to_string() });
+    test_app.update();
+    // the active wallet can't be removed
+    test_app.send(EventRemoveWallet(0));
+    test_app.update();
+
+    let vault = WalletVault::load(&path, "password").unwrap();
+    let names: Vec<&str> = vault.wallets.iter().map(|wallet| wallet.name.as_str()).collect();
+    assert_eq!(names, vec!["Miner", "Treasury"]);
+    assert_eq!(vault.wallets[1].pubkey(), treasury.pubkey());
+
+    test_app.send(EventRemoveWallet(1));
+    test_app.update();
+    let vault = WalletVault::load(&path, "password").unwrap();
+    assert_eq!(vault.wallets.len(), 1);
+    assert_eq!(vault.active_wallet().pubkey(), test_app.wallet.pubkey());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn change_password_reencrypts_vault() {
+    let mut test_app = TestApp::new();
+    let path = test_app.with_vault("password");
+    test_app.set_state(AppScreenState::SettingsWallet);
+
+    // wrong current password and mismatched confirmation leave the file alone
+    test_app.send(EventChangePassword {
+        current: "wrong".to_string(),
+        new: "new".to_string(),
+        confirm: "new".to_string(),
+    });
+    test_app.wait_for_task::<TaskChangePassword>("wrong password check");
+    test_app.send(EventChangePassword {
+        current: "password".to_string(),
+        new: "new".to_string(),
+        confirm: "typo".to_string(),
+    });
+    test_app.wait_for_task::<TaskChangePassword>("mismatched confirmation");
+    assert!(WalletVault::load(&path, "password").is_ok());
+
+    test_app.send(EventChangePassword {
+        current: "password".to_string(),
+        new: "new".to_string(),
+        confirm: "new".to_string(),
+    });
+    test_app.wait_for_task::<TaskChangePassword>("password change");
+    assert!(!test_app.resource::<WalletVaultResource>().password_change_pending);
+    assert!(WalletVault::load(&path, "password").is_err());
+    let vault = WalletVault::load(&path, "new").unwrap();
+    assert_eq!(vault.active_wallet().pubkey(), test_app.wallet.pubkey());
+    let mut tmp_name = path.file_name().unwrap().to_os_string();
+    tmp_name.push(".tmp");
+    assert!(!path.with_file_name(tmp_name).exists());
+
+    // later changes are written under the new password
+    test_app.send(EventRenameWallet { index: 0, name: "Miner".to_string() });
+    test_app.update();
+    assert_eq!(WalletVault::load(&path, "new").unwrap().active_wallet().name, "Miner");
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn exports_keypair_and_backup() {
+    let mut test_app = TestApp::new();
+    let path = test_app.with_vault("password");
+    test_app.set_state(AppScreenState::SettingsWallet);
+    let export_dir = path.with_file_name(EXPORT_DIR);
+    let keypair_path = export_dir.join(format!("{}.json", test_app.wallet.pubkey()));
+
+    test_app.send(EventExportWallet { export: WalletExport::Keypair, password: "wrong".to_string() });
+    test_app.wait_for_task::<TaskExportWallet>("wrong password export");
+    assert!(!keypair_path.exists());
+
+    test_app.send(EventExportWallet { export: WalletExport::Keypair, password: "password".to_string() });
+    test_app.wait_for_task::<TaskExportWallet>("keypair export");
+    assert_eq!(read_keypair_file(&keypair_path).unwrap().pubkey(), test_app.wallet.pubkey());
+
+    test_app.send(EventExportWallet { export: WalletExport::Backup, password: "password".to_string() });
+    test_app.wait_for_task::<TaskExportWallet>("backup export");
+    let prefix = format!("ore-backup-{}-", test_app.wallet.pubkey());
+    let backup_path = fs::read_dir(&export_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.file_name().unwrap().to_string_lossy().starts_with(&prefix))
+        .unwrap();
+    assert!(BackupBundle::is_backup(&backup_path));
+    assert!(!BackupBundle::is_backup(&path));
+
+    // restoring on another machine writes the same vault
+    assert!(matches!(BackupBundle::load(&backup_path, "wrong"), Err(VaultError::Decrypt)));
+    let bundle = BackupBundle::load(&backup_path, "password").unwrap();
+    let restored_path = path.with_extension("restored");
+    let (vault, replaced) = bundle.restore_vault(&restored_path, "password").unwrap();
+    assert_eq!(vault.active_wallet().pubkey(), test_app.wallet.pubkey());
+    assert!(replaced.is_none());
+    assert_eq!(WalletVault::load(&restored_path, "password").unwrap().active_wallet().name, "Mining");
+
+    for path in [path, keypair_path, backup_path, restored_path] {
+        let _ = fs::remove_file(path);
+    }
+}
+
+#[test]
+fn replaced_vault_is_kept_as_copy() {
+    let old = Keypair::new();
+    let path = std::env::temp_dir().join(format!("ore-app-test-{}.data", old.pubkey()));
+    assert_eq!(copy_to_backup(&path).unwrap(), None);
+    WalletVault::new("Old", &old).save_with_kdf(&path, "old", Kdf::pbkdf2(MIN_KDF_ITERATIONS)).unwrap();
+
+    // forgot password saves a new vault over the old one
+    let first = copy_to_backup(&path).unwrap().unwrap();
+    let second = copy_to_backup(&path).unwrap().unwrap();
+    assert_ne!(first, second);
+    WalletVault::new("New", &Keypair::new()).save_with_kdf(&path, "new", Kdf::pbkdf2(MIN_KDF_ITERATIONS)).unwrap();
+    assert!(first.file_name().unwrap().to_string_lossy().contains(".data.bak-"));
+    assert_eq!(WalletVault::load(&first, "old").unwrap().active_wallet().pubkey(), old.pubkey());
+    assert_eq!(WalletVault::load(&path, "new").unwrap().active_wallet().name, "New");
+
+    for path in [path, first, second] {
+        let _ = fs::remove_file(path);
+    }
+}
+
+#[test]
+fn switch_wallet_tracks_new_proof() {
+    let mut test_app = TestApp::registered();
+    let path = test_app.with_vault("password");
+    test_app.fetch_ui_data();
+    assert_ne!(test_app.resource::<ProofAccountResource>().challenge, "Not Found");
+
+    test_app.set_state(AppScreenState::SettingsWallet);
+    let treasury = Keypair::new();
+    let treasury_pubkey = treasury.pubkey();
+    test_app.send(EventAddWallet { name: "Treasury".to_string(), keypair: treasury, seed: None });
+    test_app.update();
+    test_app.send(EventSwitchWallet(1));
+    test_app.update();
+    assert_eq!(
+        test_app.resource::<AppWallet>().wallet.as_ref().unwrap().pubkey(),
+        treasury_pubkey
+    );
+
+    // the new wallet hasn't registered a proof
+    test_app.update_until("new wallet ui data", |world| {
+        world.resource::<ProofAccountResource>().challenge == "Not Found"
+    });
+    assert_eq!(WalletVault::load(&path, "password").unwrap().active, 1);
+
+    test_app.send(EventLock);
+    test_app.update();
+    assert!(test_app.resource::<WalletVaultResource>().vault.is_none());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn idle_timeout_locks_and_stops_mining() {
+    let mut test_app = TestApp::registered();
+    let path = test_app.with_vault("password");
+    test_app.fetch_ui_data();
+    test_app.send(EventStartStopMining);
+    test_app.update_until("miner started", |world| {
+        world.resource::<MinerStatusResource>().miner_status == "MINING"
+    });
+
+    test_app.app
+        .add_event::<KeyboardInput>()
+        .add_event::<MouseButtonInput>()
+        .add_event::<MouseWheel>()
+        .add_event::<CursorMoved>()
+        .insert_resource(AutoLockResource {
+            timeout: Some(Duration::from_millis(50)),
+            idle: Duration::ZERO,
+        })
+        .add_systems(Update, auto_lock_when_idle.run_if(run_if_has_some_wallet));
+
+    test_app.update_until("auto lock", |world| world.resource::<AppWallet>().wallet.is_none());
+    assert!(test_app.resource::<WalletVaultResource>().vault.is_none());
+    assert_eq!(test_app.resource::<MinerStatusResource>().miner_status, "STOPPED");
+    let _ = fs::remove_file(path);
+}
+
+fn enter_password(test_app: &mut TestApp, password: &str) {
+    let mut query = test_app.app.world.query_filtered::<&mut TextInput, With<TextPasswordInput>>();
+    query.single_mut(&mut test_app.app.world).text = password.to_string();
+    test_app.send(EventUnlock);
+}
+
+#[test]
+fn failed_unlocks_are_delayed_and_persisted() {
+    let mut test_app = TestApp::registered();
+    let path = test_app.with_vault("password");
+    test_app.app.world.spawn((
+        TextInput { hidden: true, numbers_only: false, text: String::new() },
+        TextPasswordInput,
+    ));
+    test_app.send(EventLock);
+    test_app.update();
+    assert!(test_app.resource::<AppWallet>().wallet.is_none());
+
+    let attempts_path = test_app.resource::<WalletVaultResource>().attempts_path();
+    for failed in 1..=UNLOCK_FREE_ATTEMPTS {
+        enter_password(&mut test_app, "wrong");
+        test_app.update_until("failed unlock", |world| {
+            world.resource::<UnlockAttempts>().failed == failed
+        });
+    }
+    assert_eq!(UnlockAttempts::load(&attempts_path).failed, UNLOCK_FREE_ATTEMPTS);
+    assert!(test_app.resource::<UnlockAttempts>().retry_in(get_unix_timestamp()) > 0);
+
+    // not even the right password is tried until the delay has passed
+    enter_password(&mut test_app, "password");
+    test_app.update();
+    let pending = test_app.app.world.query::<&TaskUnlockVault>().iter(&test_app.app.world).count();
+    assert_eq!(pending, 0);
+    assert!(test_app.resource::<AppWallet>().wallet.is_none());
+
+    test_app.app.world.resource_mut::<UnlockAttempts>().last_failed_at -= UNLOCK_MAX_DELAY_SECS;
+    enter_password(&mut test_app, "password");
+    test_app.update_until("unlocked", |world| world.resource::<AppWallet>().wallet.is_some());
+    assert_eq!(test_app.resource::<UnlockAttempts>().failed, 0);
+    assert!(!attempts_path.exists());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn unlock_delay_doubles_up_to_the_max() {
+    let mut attempts = UnlockAttempts::default();
+    for _ in 1..UNLOCK_FREE_ATTEMPTS {
+        attempts.record_failure(1_000);
+    }
+    assert_eq!(attempts.delay_secs(), 0);
+    attempts.record_failure(1_000);
+    assert_eq!(attempts.delay_secs(), UNLOCK_BASE_DELAY_SECS);
+    assert_eq!(attempts.retry_in(1_001), UNLOCK_BASE_DELAY_SECS - 1);
+    attempts.record_failure(1_000);
+    assert_eq!(attempts.delay_secs(), UNLOCK_BASE_DELAY_SECS * 2);
+
+    attempts.failed = 100;
+    assert_eq!(attempts.delay_secs(), UNLOCK_MAX_DELAY_SECS);
+    assert_eq!(attempts.retry_in(1_000 + UNLOCK_MAX_DELAY_SECS), 0);
+}
+
+#[test]
+fn watch_only_tracks_proof_without_signing() {
+    let mut test_app = TestApp::registered();
+    let watched = test_app.wallet.pubkey();
+    let mut proof = test_app.rpc.proof(watched).unwrap();
+    proof.balance = CLAIMABLE;
+    test_app.rpc.set_proof(proof);
+    test_app.app.world.resource_mut::<AppWallet>().wallet = None;
+    test_app.set_state(AppScreenState::Unlock);
+
+    test_app.send(EventWatchPubkey("not a pubkey".to_string()));
+    test_app.update();
+    assert!(test_app.resource::<AppWallet>().pubkey().is_none());
+
+    test_app.send(EventWatchPubkey(format!(" {} ", watched)));
+    test_app.update();
+    assert_eq!(test_app.resource::<AppWallet>().pubkey(), Some(watched));
+    test_app.fetch_ui_data();
+    assert_eq!(test_app.resource::<ProofAccountResource>().stake, CLAIMABLE);
+
+    // the signing handlers only run with a keypair
+    test_app.set_state(AppScreenState::Mining);
+    test_app.send(EventClaimOreRewards);
+    for _ in 0..10 {
+        test_app.update();
+    }
+    assert!(test_app.tx_processors().is_empty());
+    assert!(test_app.tx_results().is_empty());
+
+    test_app.send(EventLock);
+    test_app.update();
+    assert!(test_app.resource::<AppWallet>().pubkey().is_none());
+}
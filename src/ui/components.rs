@@ -1,9 +1,9 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use bevy::prelude::*;
 use solana_sdk::signature::Keypair;
 
-use crate::NavItemScreen;
+use crate::{events::WalletExport, seed_phrase::{DerivationScheme, SeedBackup}, vanity::VanityOption, BusSelector, Cluster, Explorer, NavItemScreen};
 
 // Components
 #[derive(Component, Default)]
@@ -62,6 +62,26 @@ pub struct NavItemArrow(pub NavItemScreen);
 #[derive(Component)]
 pub struct TxPopUpArea;
 
+#[derive(Component)]
+pub struct ToastArea;
+
+#[derive(Component)]
+pub struct Toast {
+    pub timer: Timer,
+}
+
+#[derive(Component)]
+pub struct ButtonToastHistory;
+
+#[derive(Component)]
+pub struct TextToastHistoryButton;
+
+#[derive(Component)]
+pub struct ToastHistoryDrawer;
+
+#[derive(Component)]
+pub struct ToastHistoryList;
+
 #[derive(Component)]
 pub struct InitialSetupScreenNode;
 
@@ -89,6 +109,62 @@ pub struct SettingsGeneralScreenNode;
 #[derive(Component)]
 pub struct SettingsWalletScreenNode;
 
+#[derive(Component)]
+pub struct SettingsWalletList;
+
+#[derive(Component)]
+pub struct TextWalletNameInput;
+
+#[derive(Component)]
+pub struct ButtonAddWallet;
+
+#[derive(Component)]
+pub struct ButtonRenameWallet;
+
+#[derive(Component)]
+pub struct ButtonSwitchWallet(pub usize);
+
+/// Armed by the first press, the second press removes the wallet.
+#[derive(Component)]
+pub struct ButtonRemoveWallet {
+    pub index: usize,
+    pub armed: bool,
+}
+
+#[derive(Component)]
+pub struct TextCurrentPasswordInput;
+
+#[derive(Component)]
+pub struct TextNewPasswordInput;
+
+#[derive(Component)]
+pub struct TextConfirmPasswordInput;
+
+#[derive(Component)]
+pub struct ButtonChangePassword;
+
+#[derive(Component)]
+pub struct TextExportPasswordInput;
+
+/// Armed by the first press, the second press exports.
+#[derive(Component)]
+pub struct ButtonExportWallet {
+    pub export: WalletExport,
+    pub armed: bool,
+}
+
+#[derive(Component)]
+pub struct TextRevealPasswordInput;
+
+#[derive(Component)]
+pub struct ButtonRevealSeed;
+
+#[derive(Component)]
+pub struct ButtonHideSeed;
+
+#[derive(Component)]
+pub struct TextRevealedSeed;
+
 #[derive(Component)]
 pub struct CopyableText {
     pub full_text: String,
@@ -116,6 +192,78 @@ pub struct TextMnemonicLine2;
 #[derive(Component)]
 pub struct TextMnemonicLine3;
 
+/// The seed behind the keypair in `TextGeneratedKeypair`. A generated phrase
+/// has to be verified before the wallet can be saved.
+#[derive(Component, Default)]
+pub struct GeneratedSeedPhrase {
+    pub backup: Option<SeedBackup>,
+    pub verify_indices: Vec<usize>,
+    pub verified: bool,
+    /// A dropped backup file, restored on save instead of a new vault.
+    pub restore: Option<PathBuf>,
+}
+
+#[derive(Component)]
+pub struct SeedVerifyNode;
+
+#[derive(Component)]
+pub struct TextSeedVerifyLabel(pub usize);
+
+#[derive(Component)]
+pub struct TextSeedVerifyInput(pub usize);
+
+#[derive(Component)]
+pub struct TextSeedVerifyStatus;
+
+#[derive(Component)]
+pub struct TextMnemonicInput;
+
+#[derive(Component)]
+pub struct TextMnemonicPassphraseInput;
+
+#[derive(Component)]
+pub struct TextMnemonicSuggestions;
+
+#[derive(Component)]
+pub struct TextMnemonicStatus;
+
+#[derive(Component)]
+pub struct ButtonDerivationPath(pub DerivationScheme);
+
+#[derive(Component)]
+pub struct TextDerivationPath;
+
+/// Preview row for one derived account, pressing it imports that keypair.
+#[derive(Component)]
+pub struct ButtonImportDerivedAddress(pub u32);
+
+#[derive(Component)]
+pub struct TextDerivedAddress(pub u32);
+
+#[derive(Component)]
+pub struct TextVanityPrefixInput;
+
+#[derive(Component)]
+pub struct TextVanitySuffixInput;
+
+#[derive(Component)]
+pub struct ButtonVanityOption {
+    pub option: VanityOption,
+    pub enabled: bool,
+}
+
+#[derive(Component)]
+pub struct TextVanityOption(pub VanityOption);
+
+#[derive(Component)]
+pub struct ButtonVanitySearch;
+
+#[derive(Component)]
+pub struct TextVanitySearchButton;
+
+#[derive(Component)]
+pub struct TextVanityStatus;
+
 #[derive(Component)]
 pub struct TextPasswordInput;
 
@@ -137,6 +285,22 @@ pub struct MiningScreenTxResultList;
 #[derive(Component)]
 pub struct ButtonOpenWebTxExplorer;
 
+/// Address to open in the block explorer, empty while unknown
+#[derive(Component)]
+pub struct ButtonOpenWebAccountExplorer(pub String);
+
+#[derive(Component)]
+pub struct ButtonOpenWalletExplorer;
+
+#[derive(Component)]
+pub struct ButtonConfigExplorer(pub Explorer);
+
+#[derive(Component)]
+pub struct TextConfigExplorer;
+
+#[derive(Component)]
+pub struct TextConfigInputExplorerTemplate;
+
 #[derive(Component)]
 pub struct TextWalletSolBalance;
 
@@ -205,6 +369,19 @@ pub struct TextBus7;
 #[derive(Component)]
 pub struct TextBus8;
 
+#[derive(Component)]
+pub struct TextBusSelector;
+
+#[derive(Component)]
+pub struct TextActiveRpc;
+
+#[derive(Component)]
+pub struct TextWsStatus;
+
+/// Bus index the analytics text is shown for
+#[derive(Component)]
+pub struct TextBusAnalytics(pub usize);
+
 #[derive(Component)]
 pub struct TextMinerStatusStatus;
 
@@ -244,6 +421,36 @@ pub struct TextConfigInputRpcFetchAccountsInterval;
 #[derive(Component)]
 pub struct TextConfigInputRpcSendTxInterval;
 
+#[derive(Component)]
+pub struct TextConfigInputAutoLock;
+
+#[derive(Component)]
+pub struct TextConfigInputBackupRpcUrls;
+
+#[derive(Component)]
+pub struct TextConfigInputWsUrl;
+
+#[derive(Component)]
+pub struct ButtonTestConnection;
+
+#[derive(Component)]
+pub struct TextConnectionTestResult;
+
+#[derive(Component)]
+pub struct ButtonConfigBusSelector(pub BusSelector);
+
+#[derive(Component)]
+pub struct TextConfigBusSelector;
+
+#[derive(Component)]
+pub struct ButtonConfigCluster(pub Cluster);
+
+#[derive(Component)]
+pub struct TextConfigCluster;
+
+#[derive(Component)]
+pub struct TextClusterBadge;
+
 #[derive(Component)]
 pub struct TextTxProcessorTxType;
 
@@ -278,6 +485,22 @@ pub struct ButtonUnlock;
 #[derive(Component)]
 pub struct ButtonLock;
 
+#[derive(Component)]
+pub struct TextUnlockStatus;
+
+#[derive(Component)]
+pub struct TextWatchPubkeyInput;
+
+#[derive(Component)]
+pub struct ButtonWatchPubkey;
+
+/// Armed by the first press, the second press goes to the wallet setup
+/// screen to restore from a seed phrase or backup.
+#[derive(Component)]
+pub struct ButtonForgotPassword {
+    pub armed: bool,
+}
+
 #[derive(Component)]
 pub struct ButtonClaimOreRewards;
 
@@ -19,7 +19,7 @@ pub fn nav_item_interactions(
                 border_color.0 = Color::RED;
 
                 // event_writer.send(EventNavItemClicked);
-                if let Some(_) = &app_wallet.wallet {
+                if app_wallet.pubkey().is_some() {
                     match nav_item.0 {
                         NavItemScreen::Dashboard => {
                             next_state.set(AppScreenState::Dashboard);
@@ -3,7 +3,7 @@ use bevy::
     prelude::*
 ;
 
-use crate::ui::styles::{hex_dark_mode_app_screen_background, hex_dark_mode_background, hex_dark_mode_header_border, hex_dark_mode_nav_title, hex_dark_mode_text_gray, hex_dark_mode_text_white, hex_dark_mode_text_white_2, DASHBOARD_ICON_WHITE, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, MINE_TOGGLE_BACKGROUND, MINE_TOGGLE_BUTTON, MINE_TOGGLE_OFF, MINING_ICON, NAV_ARROW_ICON, ORE_LOGO_WHITE};
+use crate::ui::styles::{hex_dark_mode_app_screen_background, hex_dark_mode_background, hex_dark_mode_header_border, hex_dark_mode_nav_title, hex_dark_mode_text_gray, hex_dark_mode_text_white, hex_dark_mode_text_white_2, DASHBOARD_ICON_WHITE, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, FONT_SIZE_X_SMALL, MINE_TOGGLE_BACKGROUND, MINE_TOGGLE_BUTTON, MINE_TOGGLE_OFF, MINING_ICON, NAV_ARROW_ICON, ORE_LOGO_WHITE, SOLANA_ICON};
 use crate::NavItemScreen;
 use crate::{
     ui::
@@ -16,7 +16,7 @@ use crate::{
 };
 
 use crate::ui::
-    components::{AppScreenParent, BaseScreenNode, NavItem, NavItemArrow, NavItemIcon, NavItemText, NavItemWhiteSelectedBar, TextWalletOreBalance, TextWalletPubkey, TextWalletSolBalance, ToggleAutoMine, ToggleAutoMineParent, TxPopUpArea}
+    components::{AppScreenParent, BaseScreenNode, ButtonToastHistory, TextToastHistoryButton, ToastArea, ToastHistoryDrawer, ToastHistoryList, NavItem, NavItemArrow, NavItemIcon, NavItemText, NavItemWhiteSelectedBar, ButtonOpenWalletExplorer, ButtonOpenWebAccountExplorer, TextActiveRpc, TextClusterBadge, TextWalletOreBalance, TextWsStatus, TextWalletPubkey, TextWalletSolBalance, ToggleAutoMine, ToggleAutoMineParent, TxPopUpArea}
     
 ;
 
@@ -90,6 +90,89 @@ pub fn spawn_base_screen(
                     Name::new("TxPopUpArea"),
                 ));
             });
+            // toast area, top right above the tx pop ups
+            parent.spawn((
+                NodeBundle {
+                    z_index: ZIndex::Global(16),
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        padding: UiRect {
+                            top: Val::Px(10.0),
+                            right: Val::Px(25.0),
+                            left: Val::Px(0.0),
+                            bottom: Val::Px(10.0),
+                        },
+                        position_type: PositionType::Absolute,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::End,
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("ToastScreen Node"),
+            )).with_children(|parent| {
+                parent.spawn((
+                    NodeBundle {
+                        background_color: hex_dark_mode_background().into(),
+                        border_color: hex_dark_mode_header_border().into(),
+                        style: Style {
+                            // toggled by ButtonToastHistory
+                            display: Display::None,
+                            width: Val::Px(300.0),
+                            max_height: Val::Px(400.0),
+                            padding: UiRect::all(Val::Px(8.0)),
+                            border: UiRect::all(Val::Px(1.0)),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(4.0),
+                            overflow: Overflow::clip_y(),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    ToastHistoryDrawer,
+                    Name::new("ToastHistoryDrawer"),
+                )).with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "Notifications",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE_SMALL,
+                                color: hex_dark_mode_text_white().into()
+                            },
+                        ),
+                        Name::new("TextToastHistoryTitle"),
+                    ));
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(4.0),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        ToastHistoryList,
+                        Name::new("ToastHistoryList"),
+                    ));
+                });
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(250.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    ToastArea,
+                    Name::new("ToastArea"),
+                ));
+            });
             parent.spawn((
                 NodeBundle {
                     border_color: Color::PURPLE.into(),
@@ -163,8 +246,9 @@ pub fn spawn_base_screen(
                             style: Style {
                                 width: Val::Percent(32.0),
                                 height: Val::Percent(100.0),
-                                align_items: AlignItems::Center,
-                                justify_content: JustifyContent::Start,
+                                align_items: AlignItems::Start,
+                                justify_content: JustifyContent::Center,
+                                flex_direction: FlexDirection::Column,
                                 padding: UiRect::left(Val::Px(5.0)),
                                 ..default()
                             },
@@ -183,6 +267,63 @@ pub fn spawn_base_screen(
                             ),
                             Name::new("TextAppScreenTitle"),
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                config.cluster.to_string().to_uppercase(),
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ),
+                            Name::new("TextClusterBadge"),
+                            TextClusterBadge,
+                        ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "RPC: not connected",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ),
+                            Name::new("TextActiveRpc"),
+                            TextActiveRpc,
+                        ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "WS: Connecting",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ),
+                            Name::new("TextWsStatus"),
+                            TextWsStatus,
+                        ));
+                        parent.spawn((
+                            ButtonBundle {
+                                background_color: Color::NONE.into(),
+                                ..default()
+                            },
+                            ButtonToastHistory,
+                            Name::new("ButtonToastHistory"),
+                        )).with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "Notifications",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_X_SMALL,
+                                        color: hex_dark_mode_text_gray().into()
+                                    },
+                                ),
+                                Name::new("TextToastHistoryButton"),
+                                TextToastHistoryButton,
+                            ));
+                        });
                     });
 
                     // Mine Toggle
@@ -319,17 +460,45 @@ pub fn spawn_base_screen(
                                 Name::new("Top Section Header Wallet Right"),
                             )).with_children(|parent| {
                                 parent.spawn((
-                                    TextBundle::from_section(
-                                        "dfsadjkl...sdioa",
-                                        TextStyle {
-                                            font: asset_server.load(FONT_REGULAR),
-                                            font_size: FONT_SIZE_MEDIUM,
-                                            color: hex_dark_mode_text_gray().into()
+                                    NodeBundle {
+                                        style: Style {
+                                            flex_direction: FlexDirection::Row,
+                                            align_items: AlignItems::Center,
+                                            ..default()
                                         },
-                                    ),
-                                    Name::new("TextWalletPubkey"),
-                                    TextWalletPubkey,
-                                ));
+                                        ..default()
+                                    },
+                                    Name::new("Wallet Pubkey Row"),
+                                )).with_children(|parent| {
+                                    parent.spawn((
+                                        TextBundle::from_section(
+                                            "dfsadjkl...sdioa",
+                                            TextStyle {
+                                                font: asset_server.load(FONT_REGULAR),
+                                                font_size: FONT_SIZE_MEDIUM,
+                                                color: hex_dark_mode_text_gray().into()
+                                            },
+                                        ),
+                                        Name::new("TextWalletPubkey"),
+                                        TextWalletPubkey,
+                                    ));
+                                    parent.spawn((
+                                        ButtonBundle {
+                                            style: Style {
+                                                width: Val::Px(14.0),
+                                                height: Val::Px(14.0),
+                                                margin: UiRect::left(Val::Px(8.0)),
+                                                ..default()
+                                            },
+                                            image: UiImage::new(asset_server.load(SOLANA_ICON)),
+                                            ..default()
+                                        },
+                                        // address is filled in by update_app_wallet_ui
+                                        ButtonOpenWebAccountExplorer(String::new()),
+                                        ButtonOpenWalletExplorer,
+                                        Name::new("ButtonOpenWalletExplorer"),
+                                    ));
+                                });
                                 parent.spawn((
                                     TextBundle::from_section(
                                         "420.696969 ORE",
@@ -1,10 +1,12 @@
 use bevy::{ecs::storage::Column, prelude::*};
+use ore_api::consts::{BUS_ADDRESSES, TREASURY_ADDRESS};
 
 use crate::ui::{
     components::{
-        BaseScreenNode, ButtonCaptureTextInput, ButtonUnlock, DashboardProofUpdatesLogsList, DashboardScreenNode, LockedScreenNode, MovingScrollPanel, ScrollingList, ScrollingListNode, TextActiveMinersLastEpoch, TextActiveMinersThisEpoch, TextBus1, TextBus2, TextBus3, TextBus4, TextBus5, TextBus6, TextBus7, TextBus8, TextCrownStakeAmount, TextCursor, TextHighestDifficultySeen, TextInput, TextPasswordInput, TextPasswordLabel, TextTreasuryBalance, TextTreasuryRewardRate
+        BaseScreenNode, ButtonCaptureTextInput, ButtonUnlock, DashboardProofUpdatesLogsList, DashboardScreenNode, LockedScreenNode, MovingScrollPanel, ScrollingList, ScrollingListNode, TextActiveMinersLastEpoch, TextActiveMinersThisEpoch, TextBus1, TextBus2, TextBus3, TextBus4, TextBus5, TextBus6, TextBus7, TextBus8, TextBusAnalytics, TextCrownStakeAmount, TextCursor, TextHighestDifficultySeen, TextInput, TextPasswordInput, TextPasswordLabel, TextTreasuryBalance, TextTreasuryRewardRate
     },
-    styles::{hex_dark_mode_background, hex_dark_mode_nav_title, hex_dark_mode_text_gray, hex_dark_mode_text_white_2, CONTENT_BACKGROUND_MEDIUM, CONTENT_BACKGROUND_SMALL, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, NORMAL_BUTTON},
+    spawn_utils::spawn_account_link_icon,
+    styles::{hex_dark_mode_background, hex_dark_mode_nav_title, hex_dark_mode_text_gray, hex_dark_mode_text_white_2, CONTENT_BACKGROUND_MEDIUM, CONTENT_BACKGROUND_SMALL, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, FONT_SIZE_X_SMALL, NORMAL_BUTTON},
 };
 
 pub fn spawn_dashboard_screen(
@@ -93,6 +95,7 @@ pub fn spawn_dashboard_screen(
                             ),
                             Name::new("TextTreasuryBalance"),
                         ));
+                        spawn_account_link_icon(parent, asset_server, TREASURY_ADDRESS.to_string());
                         parent.spawn((
                             TextBundle::from_section(
                                 "loading...",
@@ -351,6 +354,22 @@ pub fn spawn_dashboard_screen(
                             Name::new("TextBus1"),
                             TextBus1,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics1"),
+                            TextBusAnalytics(0),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[0].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -398,6 +417,22 @@ pub fn spawn_dashboard_screen(
                             Name::new("TextBus2"),
                             TextBus2
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics2"),
+                            TextBusAnalytics(1),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[1].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -445,6 +480,22 @@ pub fn spawn_dashboard_screen(
                             Name::new("TextBus3"),
                             TextBus3,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics3"),
+                            TextBusAnalytics(2),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[2].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -492,6 +543,22 @@ pub fn spawn_dashboard_screen(
                             Name::new("TextBus4"),
                             TextBus4,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics4"),
+                            TextBusAnalytics(3),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[3].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -539,6 +606,22 @@ pub fn spawn_dashboard_screen(
                             Name::new("TextBus5"),
                             TextBus5,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics5"),
+                            TextBusAnalytics(4),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[4].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -586,6 +669,22 @@ pub fn spawn_dashboard_screen(
                             Name::new("TextBus6"),
                             TextBus6,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics6"),
+                            TextBusAnalytics(5),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[5].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -633,6 +732,22 @@ pub fn spawn_dashboard_screen(
                             Name::new("TextBus7"),
                             TextBus7
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics7"),
+                            TextBusAnalytics(6),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[6].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -680,6 +795,22 @@ pub fn spawn_dashboard_screen(
                             Name::new("TextBus8"),
                             TextBus8,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics8"),
+                            TextBusAnalytics(7),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[7].to_string());
                     });
                 });
             });
@@ -1,15 +1,20 @@
 use bevy::prelude::*;
 
-use crate::ui::{
-    components::{
-        BaseScreenNode, ButtonCaptureTextInput, ButtonUnlock, LockedScreenNode, TextCursor, TextInput, TextPasswordInput, TextPasswordLabel
+use crate::{
+    ui::{
+        components::{
+            BaseScreenNode, ButtonCaptureTextInput, ButtonForgotPassword, ButtonUnlock, LockedScreenNode, TextCursor, TextInput, TextPasswordInput, TextPasswordLabel, TextUnlockStatus
+        },
+        spawn_utils::spawn_watch_pubkey_input,
+        styles::{FONT_SIZE_SMALL, NORMAL_BUTTON},
     },
-    styles::{FONT_SIZE_SMALL, NORMAL_BUTTON},
+    utils::shorten_string,
 };
 
 pub fn spawn_locked_screen(
     parent: &mut ChildBuilder,
     asset_server: Res<AssetServer>,
+    pubkey_hint: Option<String>,
 ) -> Option<Entity> {
     let mut password_capture_text_entity = None;
     parent
@@ -30,6 +35,20 @@ pub fn spawn_locked_screen(
             LockedScreenNode,
         ))
         .with_children(|parent| {
+            // written by older versions without a header
+            if let Some(pubkey_hint) = pubkey_hint {
+                parent.spawn((
+                    TextBundle::from_section(
+                        format!("Wallet: {}", shorten_string(pubkey_hint, 10)),
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: FONT_SIZE_SMALL,
+                            color: Color::rgb(0.9, 0.9, 0.9),
+                        },
+                    ),
+                    Name::new("TextPubkeyHint"),
+                ));
+            }
             parent
                 .spawn((
                     NodeBundle {
@@ -177,6 +196,47 @@ pub fn spawn_locked_screen(
                             ));
                         });
                 });
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                        font_size: FONT_SIZE_SMALL,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                TextUnlockStatus,
+                Name::new("TextUnlockStatus"),
+            ));
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(160.0),
+                            height: Val::Px(40.0),
+                            border: UiRect::all(Val::Px(2.5)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        border_color: BorderColor(Color::BLACK),
+                        background_color: NORMAL_BUTTON.into(),
+                        ..default()
+                    },
+                    ButtonForgotPassword { armed: false },
+                    Name::new("ButtonForgotPassword"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Forgot Password",
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: FONT_SIZE_SMALL,
+                            color: Color::rgb(0.9, 0.9, 0.9),
+                        },
+                    ));
+                });
+            spawn_watch_pubkey_input(parent, &asset_server);
         });
 
     password_capture_text_entity
@@ -7,16 +7,17 @@ use bevy::{
     },
     prelude::*,
 };
+use ore_api::consts::BUS_ADDRESSES;
 use solana_sdk::signer::Signer;
 
 use crate::{
     ui::{
         components::{
-            AutoScrollCheckIcon, ButtonAutoScroll, ButtonCooldownSpinner, ButtonRequestAirdrop, ButtonStakeOre, MiningScreenNode, MiningScreenTxResultList, SpinnerIcon, TextBurnAmount, TextBus1, TextBus2, TextBus3, TextBus4, TextBus5, TextBus6, TextBus7, TextBus8, TextHashrate, TextLastClaimAt, TextLastHashAt, TextMinerStatusThreads, TxPopUpArea
+            AutoScrollCheckIcon, ButtonAutoScroll, ButtonCooldownSpinner, ButtonRequestAirdrop, ButtonStakeOre, MiningScreenNode, MiningScreenTxResultList, SpinnerIcon, TextBurnAmount, TextBus1, TextBus2, TextBus3, TextBus4, TextBus5, TextBus6, TextBus7, TextBus8, TextBusAnalytics, TextBusSelector, TextHashrate, TextLastClaimAt, TextLastHashAt, TextMinerStatusThreads, TxPopUpArea
         },
-        spawn_utils::spawn_copyable_text,
+        spawn_utils::{spawn_account_link_icon, spawn_copyable_text},
         styles::{
-            hex_black, hex_dark_mode_app_screen_background, hex_dark_mode_background, hex_dark_mode_nav_title, hex_dark_mode_text_gray, BUTTON_CLAIM, BUTTON_GREEN_MEDIUM, BUTTON_RED_MEDIUM, BUTTON_STAKE, CHECKBOX, CHECK_ICON, CONTENT_BACKGROUND_MEDIUM, CONTENT_BACKGROUND_SMALL, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, LOG_ITEMS_BACKGROUND, MINE_TOGGLE_BUTTON, PROOF_ACCOUNT_BACKGROUND, SPINNER_ICON, SYSTEM_OVERVIEW_BACKGROUND, TOGGLE_OFF, TREASURY_BACKGROUND, TX_RESULTS_BACKGROUND
+            hex_black, hex_dark_mode_app_screen_background, hex_dark_mode_background, hex_dark_mode_nav_title, hex_dark_mode_text_gray, BUTTON_CLAIM, BUTTON_GREEN_MEDIUM, BUTTON_RED_MEDIUM, BUTTON_STAKE, CHECKBOX, CHECK_ICON, CONTENT_BACKGROUND_MEDIUM, CONTENT_BACKGROUND_SMALL, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_X_SMALL, LOG_ITEMS_BACKGROUND, MINE_TOGGLE_BUTTON, PROOF_ACCOUNT_BACKGROUND, SPINNER_ICON, SYSTEM_OVERVIEW_BACKGROUND, TOGGLE_OFF, TREASURY_BACKGROUND, TX_RESULTS_BACKGROUND
         },
     }, utils::shorten_string, AppConfig, AppWallet
 };
@@ -168,6 +169,54 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                         TextTreasuryLastResetAt,
                     ));
                 });
+                parent.spawn((
+                    NodeBundle {
+                        background_color: hex_dark_mode_nav_title().into(),
+                        style: Style {
+                            width: Val::Percent(25.0),
+                            height: Val::Percent(90.0),
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Start,
+                            flex_direction: FlexDirection::Column,
+                            padding: UiRect {
+                                top: Val::Px(5.0),
+                                bottom: Val::Px(5.0),
+                                left: Val::Px(8.0),
+                                right: Val::Px(0.0),
+                            },
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    UiImage::new(
+                            asset_server.load(CONTENT_BACKGROUND_SMALL),
+                        ),
+                    Name::new("Mining App Screen Top Section Bus Selector"),
+                )).with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "Bus Selector",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE_MEDIUM,
+                                color: hex_dark_mode_text_gray().into()
+                            },
+                        ),
+                        Name::new("TextBusSelectorLabel"),
+                    ));
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "loading...",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE_MEDIUM,
+                                color: hex_dark_mode_text_gray().into()
+                            },
+                        ),
+                        Name::new("TextBusSelector"),
+                        TextBusSelector,
+                    ));
+                });
             });
             parent.spawn((
                 NodeBundle {
@@ -265,6 +314,22 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             Name::new("TextBus1"),
                             TextBus1,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics1"),
+                            TextBusAnalytics(0),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[0].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -312,6 +377,22 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             Name::new("TextBus2"),
                             TextBus2
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics2"),
+                            TextBusAnalytics(1),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[1].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -359,6 +440,22 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             Name::new("TextBus3"),
                             TextBus3,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics3"),
+                            TextBusAnalytics(2),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[2].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -406,6 +503,22 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             Name::new("TextBus4"),
                             TextBus4,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics4"),
+                            TextBusAnalytics(3),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[3].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -453,6 +566,22 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             Name::new("TextBus5"),
                             TextBus5,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics5"),
+                            TextBusAnalytics(4),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[4].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -500,6 +629,22 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             Name::new("TextBus6"),
                             TextBus6,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics6"),
+                            TextBusAnalytics(5),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[5].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -547,6 +692,22 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             Name::new("TextBus7"),
                             TextBus7
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics7"),
+                            TextBusAnalytics(6),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[6].to_string());
                     });
                     parent.spawn((
                         NodeBundle {
@@ -594,6 +755,22 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             Name::new("TextBus8"),
                             TextBus8,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_X_SMALL,
+                                    color: hex_dark_mode_text_gray().into()
+                                },
+                            ).with_style(Style {
+                                margin: UiRect::left(Val::Px(10.0)),
+                                ..default()
+                            }),
+                            Name::new("TextBusAnalytics8"),
+                            TextBusAnalytics(7),
+                        ));
+                        spawn_account_link_icon(parent, asset_server, BUS_ADDRESSES[7].to_string());
                     });
 
                 });
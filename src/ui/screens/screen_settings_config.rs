@@ -2,18 +2,25 @@ use bevy::prelude::*;
 
 use crate::{ui::{
     components::{
-        BaseScreenNode, ButtonCaptureTextInput, ButtonSaveConfig, InitialSetupScreenNode, SettingsConfigScreenNode, TextConfigInputRpcFetchAccountsInterval, TextConfigInputRpcSendTxInterval, TextConfigInputRpcUrl, TextConfigInputThreads, TextCursor, TextInput
+        BaseScreenNode, ButtonCaptureTextInput, ButtonConfigBusSelector, ButtonConfigCluster, ButtonConfigExplorer, ButtonSaveConfig, InitialSetupScreenNode, SettingsConfigScreenNode, TextConfigInputRpcFetchAccountsInterval, TextConfigInputRpcSendTxInterval, TextConfigInputRpcUrl, TextConfigInputAutoLock, TextConfigBusSelector, TextConfigCluster, TextConfigExplorer, TextConfigInputBackupRpcUrls, TextConfigInputExplorerTemplate, TextConfigInputThreads, TextConfigInputWsUrl, TextConnectionTestResult, ButtonTestConnection, TextCursor, TextInput
     },
     styles::{
-        BUTTON, BUTTON_SAVE_CONFIG, CURRENT_TX_STATUS_BACKGROUND, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, MENU_BACKGROUND, SCREEN_BACKGROUND_1, SETTINGS_ICON, TITLE_BACKGROUND, TREASURY_BACKGROUND
+        BUTTON, BUTTON_SAVE_CONFIG, CURRENT_TX_STATUS_BACKGROUND, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, MENU_BACKGROUND, SCREEN_BACKGROUND_1, SETTINGS_ICON, TITLE_BACKGROUND, TREASURY_BACKGROUND
     },
-}, AppConfig};
+}, utils::derive_ws_url, AppConfig};
 
 pub fn spawn_settings_config_screen(
     parent: &mut ChildBuilder,
     asset_server: Res<AssetServer>,
     config: AppConfig
 ) {
+    // only show the ws url when it isn't the one derived from the rpc url
+    let ws_url_text = if derive_ws_url(&config.rpc_url).as_ref() == Some(&config.ws_url) {
+        String::new()
+    } else {
+        config.ws_url.clone()
+    };
+
     parent
         .spawn((
             NodeBundle {
@@ -181,7 +188,7 @@ pub fn spawn_settings_config_screen(
                                     NodeBundle {
                                         style: Style {
                                             width: Val::Percent(30.0),
-                                            height: Val::Percent(60.0),
+                                            height: Val::Percent(90.0),
                                             padding: UiRect::right(Val::Px(20.0)),
                                             // flex_direction: FlexDirection::Column,
                                             // align_items: AlignItems::Center,
@@ -210,6 +217,14 @@ pub fn spawn_settings_config_screen(
                                             Name::new("Config Input Field Headers"),
                                         ))
                                         .with_children(|parent| {
+                                            parent.spawn(TextBundle::from_section(
+                                                "Cluster: ",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
                                             parent.spawn(TextBundle::from_section(
                                                 "RPC URL: ",
                                                 TextStyle {
@@ -218,6 +233,22 @@ pub fn spawn_settings_config_screen(
                                                     color: Color::rgb(0.9, 0.9, 0.9),
                                                 },
                                             ));
+                                            parent.spawn(TextBundle::from_section(
+                                                "WS URL (blank = auto): ",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
+                                            parent.spawn(TextBundle::from_section(
+                                                "Backup RPC URLs: ",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
                                             parent.spawn(TextBundle::from_section(
                                                 "Threads: ",
                                                 TextStyle {
@@ -242,6 +273,38 @@ pub fn spawn_settings_config_screen(
                                                     color: Color::rgb(0.9, 0.9, 0.9),
                                                 },
                                             ));
+                                            parent.spawn(TextBundle::from_section(
+                                                "Auto Lock (min, 0 = off): ",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
+                                            parent.spawn(TextBundle::from_section(
+                                                "Bus Selector: ",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
+                                            parent.spawn(TextBundle::from_section(
+                                                "Explorer: ",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
+                                            parent.spawn(TextBundle::from_section(
+                                                "Explorer URL Template: ",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
                                         });
                                 });
                             parent
@@ -249,7 +312,7 @@ pub fn spawn_settings_config_screen(
                                     NodeBundle {
                                         style: Style {
                                             flex_direction: FlexDirection::Column,
-                                            height: Val::Px(268.0),
+                                            height: Val::Px(620.0),
                                             width: Val::Px(351.0),
                                             align_items: AlignItems::Start,
                                             justify_content: JustifyContent::SpaceBetween,
@@ -260,6 +323,38 @@ pub fn spawn_settings_config_screen(
                                     Name::new("Config Input Field Values"),
                                 ))
                                 .with_children(|parent| {
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(250.0),
+                                                    height: Val::Px(40.0),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonConfigCluster(config.cluster),
+                                            Name::new("ButtonConfigCluster"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    config.cluster.to_string(),
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextConfigCluster,
+                                                Name::new("TextConfigCluster"),
+                                            ));
+                                        });
                                     parent
                                         .spawn((
                                             ButtonBundle {
@@ -316,6 +411,119 @@ pub fn spawn_settings_config_screen(
                                                 Name::new("TextCursor"),
                                             ));
                                         });
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(300.0),
+                                                    height: Val::Px(50.0),
+                                                    justify_content: JustifyContent::Start,
+                                                    align_items: AlignItems::Center,
+                                                    overflow: Overflow {
+                                                        x: OverflowAxis::Clip,
+                                                        y: OverflowAxis::Clip,
+                                                    },
+                                                    padding: UiRect::left(Val::Px(10.0)),
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonCaptureTextInput,
+                                            Name::new("ButtonCaptureText WS URL"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    "",
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextInput {
+                                                    hidden: false,
+                                                    numbers_only: false,
+                                                    text: ws_url_text,
+                                                },
+                                                TextConfigInputWsUrl,
+                                                Name::new("TextConfigWsUrl"),
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(10.0),
+                                                        height: Val::Px(15.0),
+                                                        ..default()
+                                                    },
+                                                    background_color: Color::WHITE.into(),
+                                                    ..default()
+                                                },
+                                                TextCursor,
+                                                Name::new("TextCursor"),
+                                            ));
+                                        });
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(300.0),
+                                                    height: Val::Px(50.0),
+                                                    justify_content: JustifyContent::Start,
+                                                    align_items: AlignItems::Center,
+                                                    overflow: Overflow {
+                                                        x: OverflowAxis::Clip,
+                                                        y: OverflowAxis::Clip,
+                                                    },
+                                                    padding: UiRect::left(Val::Px(10.0)),
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonCaptureTextInput,
+                                            Name::new("ButtonCaptureText Backup RPC URLs"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    "",
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextInput {
+                                                    hidden: false,
+                                                    numbers_only: false,
+                                                    // comma separated, in failover order
+                                                    text: config.backup_rpc_urls.join(","),
+                                                },
+                                                TextConfigInputBackupRpcUrls,
+                                                Name::new("TextConfigBackupRpcUrls"),
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(10.0),
+                                                        height: Val::Px(15.0),
+                                                        ..default()
+                                                    },
+                                                    background_color: Color::WHITE.into(),
+                                                    ..default()
+                                                },
+                                                TextCursor,
+                                                Name::new("TextCursor"),
+                                            ));
+                                        });
                                     parent
                                         .spawn((
                                             ButtonBundle {
@@ -466,6 +674,175 @@ pub fn spawn_settings_config_screen(
                                                 Name::new("TextCursor"),
                                             ));
                                         });
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(60.0),
+                                                    height: Val::Px(40.0),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonCaptureTextInput,
+                                            Name::new("ButtonCaptureText"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    "",
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_LARGE,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextInput {
+                                                    hidden: false,
+                                                    numbers_only: true,
+                                                    text: config.auto_lock_minutes.to_string(),
+                                                },
+                                                TextConfigInputAutoLock,
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(10.0),
+                                                        height: Val::Px(15.0),
+                                                        ..default()
+                                                    },
+                                                    background_color: Color::WHITE.into(),
+                                                    ..default()
+                                                },
+                                                TextCursor,
+                                                Name::new("TextCursor"),
+                                            ));
+                                        });
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(250.0),
+                                                    height: Val::Px(40.0),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonConfigBusSelector(config.bus_selector),
+                                            Name::new("ButtonConfigBusSelector"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    config.bus_selector.to_string(),
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextConfigBusSelector,
+                                                Name::new("TextConfigBusSelector"),
+                                            ));
+                                        });
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(250.0),
+                                                    height: Val::Px(40.0),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonConfigExplorer(config.explorer),
+                                            Name::new("ButtonConfigExplorer"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    config.explorer.to_string(),
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextConfigExplorer,
+                                                Name::new("TextConfigExplorer"),
+                                            ));
+                                        });
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(300.0),
+                                                    height: Val::Px(50.0),
+                                                    justify_content: JustifyContent::Start,
+                                                    align_items: AlignItems::Center,
+                                                    overflow: Overflow {
+                                                        x: OverflowAxis::Clip,
+                                                        y: OverflowAxis::Clip,
+                                                    },
+                                                    padding: UiRect::left(Val::Px(10.0)),
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonCaptureTextInput,
+                                            Name::new("ButtonCaptureText Explorer Template"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    "",
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextInput {
+                                                    hidden: false,
+                                                    numbers_only: false,
+                                                    text: config.explorer_custom_template.clone(),
+                                                },
+                                                TextConfigInputExplorerTemplate,
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(10.0),
+                                                        height: Val::Px(15.0),
+                                                        ..default()
+                                                    },
+                                                    background_color: Color::WHITE.into(),
+                                                    ..default()
+                                                },
+                                                TextCursor,
+                                                Name::new("TextCursor"),
+                                            ));
+                                        });
                                 });
                             parent
                                 .spawn((
@@ -473,12 +850,13 @@ pub fn spawn_settings_config_screen(
                                         style: Style {
                                             position_type: PositionType::Absolute,
                                             justify_content: JustifyContent::Center,
-                                            left: Val::Percent(30.0),
-                                            width: Val::Percent(40.0),
-                                            height: Val::Percent(10.0),
+                                            left: Val::Percent(10.0),
+                                            width: Val::Percent(80.0),
+                                            height: Val::Percent(15.0),
                                             align_items: AlignItems::Center,
                                             align_self: AlignSelf::End,
-                                            flex_direction: FlexDirection::Row,
+                                            flex_direction: FlexDirection::Column,
+                                            row_gap: Val::Px(5.0),
                                             ..default()
                                         },
                                         //background_color: Color::WHITE.into(),
@@ -489,22 +867,73 @@ pub fn spawn_settings_config_screen(
                                 ))
                                 .with_children(|parent| {
                                     parent.spawn((
-                                        ButtonBundle {
+                                        TextBundle::from_section(
+                                            "",
+                                            TextStyle {
+                                                font: asset_server.load(FONT_REGULAR),
+                                                font_size: FONT_SIZE_SMALL,
+                                                color: Color::rgb(0.9, 0.9, 0.9),
+                                            },
+                                        ),
+                                        TextConnectionTestResult,
+                                        Name::new("TextConnectionTestResult"),
+                                    ));
+                                    parent.spawn((
+                                        NodeBundle {
                                             style: Style {
-                                                width: Val::Px(150.0),
-                                                height: Val::Px(52.0),
-                                                justify_content: JustifyContent::Center,
+                                                flex_direction: FlexDirection::Row,
                                                 align_items: AlignItems::Center,
+                                                column_gap: Val::Px(20.0),
                                                 ..default()
                                             },
-                                            image: UiImage::new(
-                                                asset_server.load(BUTTON_SAVE_CONFIG),
-                                            ),
                                             ..default()
                                         },
-                                        ButtonSaveConfig,
-                                        Name::new("ButtonSaveConfig"),
-                                    ));
+                                        Name::new("Config Buttons Row"),
+                                    )).with_children(|parent| {
+                                        parent.spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(200.0),
+                                                    height: Val::Px(52.0),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonTestConnection,
+                                            Name::new("ButtonTestConnection"),
+                                        )).with_children(|parent| {
+                                            parent.spawn(TextBundle::from_section(
+                                                "Test Connection",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
+                                        });
+                                        parent.spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(150.0),
+                                                    height: Val::Px(52.0),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                image: UiImage::new(
+                                                    asset_server.load(BUTTON_SAVE_CONFIG),
+                                                ),
+                                                ..default()
+                                            },
+                                            ButtonSaveConfig,
+                                            Name::new("ButtonSaveConfig"),
+                                        ));
+                                    });
                                 });
                         });
                 });
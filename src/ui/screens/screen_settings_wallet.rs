@@ -1,11 +1,19 @@
 use bevy::prelude::*;
 
-use crate::ui::{
-    components::
-        SettingsWalletScreenNode
-    ,
-    styles::{
-        hex_dark_mode_text_white_2, FONT_REGULAR, FONT_SIZE_LARGE
+use crate::{
+    events::WalletExport,
+    ui::{
+        components::{
+            ButtonAddWallet, ButtonCaptureTextInput, ButtonChangePassword, ButtonExportWallet,
+            ButtonHideSeed, ButtonRenameWallet, ButtonRevealSeed, SettingsWalletList,
+            SettingsWalletScreenNode, TextConfirmPasswordInput, TextCurrentPasswordInput,
+            TextCursor, TextExportPasswordInput, TextInput, TextNewPasswordInput,
+            TextRevealPasswordInput, TextRevealedSeed, TextWalletNameInput,
+        },
+        styles::{
+            hex_dark_mode_text_gray, hex_dark_mode_text_white_2, CURRENT_TX_STATUS_BACKGROUND,
+            FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, NORMAL_BUTTON,
+        },
     },
 };
 
@@ -19,8 +27,10 @@ pub fn spawn_settings_wallet_screen(
                 style: Style {
                     width: Val::Percent(100.0),
                     height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
                     align_items: AlignItems::Center,
-                    justify_content: JustifyContent::Center,
+                    padding: UiRect::top(Val::Px(40.0)),
+                    row_gap: Val::Px(20.0),
                     ..default()
                 },
                 ..default()
@@ -40,6 +50,686 @@ pub fn spawn_settings_wallet_screen(
                 ),
                 Name::new("TextSETTINGSWALLETAPPSCREENBACKGROUND"),
             ));
+
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(700.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                SettingsWalletList,
+                Name::new("SettingsWalletList"),
+            ));
+
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(700.0),
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("Wallet Controls Node"),
+                ))
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(300.0),
+                                    height: Val::Px(50.0),
+                                    justify_content: JustifyContent::Start,
+                                    align_items: AlignItems::Center,
+                                    overflow: Overflow {
+                                        x: OverflowAxis::Clip,
+                                        y: OverflowAxis::Clip,
+                                    },
+                                    padding: UiRect::left(Val::Px(10.0)),
+                                    ..default()
+                                },
+                                image: UiImage::new(
+                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                ),
+                                ..default()
+                            },
+                            ButtonCaptureTextInput,
+                            Name::new("ButtonCaptureText Wallet Name"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                TextInput {
+                                    hidden: false,
+                                    numbers_only: false,
+                                    text: String::new(),
+                                },
+                                TextWalletNameInput,
+                                Name::new("TextWalletNameInput"),
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    visibility: Visibility::Hidden,
+                                    style: Style {
+                                        width: Val::Px(10.0),
+                                        height: Val::Px(15.0),
+                                        ..default()
+                                    },
+                                    background_color: Color::WHITE.into(),
+                                    ..default()
+                                },
+                                TextCursor,
+                                Name::new("TextCursor"),
+                            ));
+                        });
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(150.0),
+                                    height: Val::Px(50.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonAddWallet,
+                            Name::new("ButtonAddWallet"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Add New",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_MEDIUM,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(180.0),
+                                    height: Val::Px(50.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonRenameWallet,
+                            Name::new("ButtonRenameWallet"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Rename Active",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_MEDIUM,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                });
+
+            parent.spawn((
+                TextBundle::from_section(
+                    "Add New generates a keypair under the typed name. All wallets share the unlock password. Enter it to reveal the active wallet's seed phrase.",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_SMALL,
+                        color: hex_dark_mode_text_gray().into()
+                    },
+                ),
+                Name::new("TextWalletSettingsHint"),
+            ));
+
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(700.0),
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(8.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("Change Password Node"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Current",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_SMALL,
+                            color: hex_dark_mode_text_gray().into()
+                        },
+                    ));
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(130.0),
+                                    height: Val::Px(50.0),
+                                    justify_content: JustifyContent::Start,
+                                    align_items: AlignItems::Center,
+                                    overflow: Overflow {
+                                        x: OverflowAxis::Clip,
+                                        y: OverflowAxis::Clip,
+                                    },
+                                    padding: UiRect::left(Val::Px(10.0)),
+                                    ..default()
+                                },
+                                image: UiImage::new(
+                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                ),
+                                ..default()
+                            },
+                            ButtonCaptureTextInput,
+                            Name::new("ButtonCaptureText Current Password"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                TextInput {
+                                    hidden: true,
+                                    numbers_only: false,
+                                    text: String::new(),
+                                },
+                                TextCurrentPasswordInput,
+                                Name::new("TextCurrentPasswordInput"),
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    visibility: Visibility::Hidden,
+                                    style: Style {
+                                        width: Val::Px(10.0),
+                                        height: Val::Px(15.0),
+                                        ..default()
+                                    },
+                                    background_color: Color::WHITE.into(),
+                                    ..default()
+                                },
+                                TextCursor,
+                                Name::new("TextCursor"),
+                            ));
+                        });
+                    parent.spawn(TextBundle::from_section(
+                        "New",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_SMALL,
+                            color: hex_dark_mode_text_gray().into()
+                        },
+                    ));
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(130.0),
+                                    height: Val::Px(50.0),
+                                    justify_content: JustifyContent::Start,
+                                    align_items: AlignItems::Center,
+                                    overflow: Overflow {
+                                        x: OverflowAxis::Clip,
+                                        y: OverflowAxis::Clip,
+                                    },
+                                    padding: UiRect::left(Val::Px(10.0)),
+                                    ..default()
+                                },
+                                image: UiImage::new(
+                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                ),
+                                ..default()
+                            },
+                            ButtonCaptureTextInput,
+                            Name::new("ButtonCaptureText New Password"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                TextInput {
+                                    hidden: true,
+                                    numbers_only: false,
+                                    text: String::new(),
+                                },
+                                TextNewPasswordInput,
+                                Name::new("TextNewPasswordInput"),
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    visibility: Visibility::Hidden,
+                                    style: Style {
+                                        width: Val::Px(10.0),
+                                        height: Val::Px(15.0),
+                                        ..default()
+                                    },
+                                    background_color: Color::WHITE.into(),
+                                    ..default()
+                                },
+                                TextCursor,
+                                Name::new("TextCursor"),
+                            ));
+                        });
+                    parent.spawn(TextBundle::from_section(
+                        "Confirm",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_SMALL,
+                            color: hex_dark_mode_text_gray().into()
+                        },
+                    ));
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(130.0),
+                                    height: Val::Px(50.0),
+                                    justify_content: JustifyContent::Start,
+                                    align_items: AlignItems::Center,
+                                    overflow: Overflow {
+                                        x: OverflowAxis::Clip,
+                                        y: OverflowAxis::Clip,
+                                    },
+                                    padding: UiRect::left(Val::Px(10.0)),
+                                    ..default()
+                                },
+                                image: UiImage::new(
+                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                ),
+                                ..default()
+                            },
+                            ButtonCaptureTextInput,
+                            Name::new("ButtonCaptureText Confirm Password"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                TextInput {
+                                    hidden: true,
+                                    numbers_only: false,
+                                    text: String::new(),
+                                },
+                                TextConfirmPasswordInput,
+                                Name::new("TextConfirmPasswordInput"),
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    visibility: Visibility::Hidden,
+                                    style: Style {
+                                        width: Val::Px(10.0),
+                                        height: Val::Px(15.0),
+                                        ..default()
+                                    },
+                                    background_color: Color::WHITE.into(),
+                                    ..default()
+                                },
+                                TextCursor,
+                                Name::new("TextCursor"),
+                            ));
+                        });
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(110.0),
+                                    height: Val::Px(50.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonChangePassword,
+                            Name::new("ButtonChangePassword"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Change",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_MEDIUM,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                });
+
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(700.0),
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("Export Wallet Node"),
+                ))
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(300.0),
+                                    height: Val::Px(50.0),
+                                    justify_content: JustifyContent::Start,
+                                    align_items: AlignItems::Center,
+                                    overflow: Overflow {
+                                        x: OverflowAxis::Clip,
+                                        y: OverflowAxis::Clip,
+                                    },
+                                    padding: UiRect::left(Val::Px(10.0)),
+                                    ..default()
+                                },
+                                image: UiImage::new(
+                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                ),
+                                ..default()
+                            },
+                            ButtonCaptureTextInput,
+                            Name::new("ButtonCaptureText Export Password"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                TextInput {
+                                    hidden: true,
+                                    numbers_only: false,
+                                    text: String::new(),
+                                },
+                                TextExportPasswordInput,
+                                Name::new("TextExportPasswordInput"),
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    visibility: Visibility::Hidden,
+                                    style: Style {
+                                        width: Val::Px(10.0),
+                                        height: Val::Px(15.0),
+                                        ..default()
+                                    },
+                                    background_color: Color::WHITE.into(),
+                                    ..default()
+                                },
+                                TextCursor,
+                                Name::new("TextCursor"),
+                            ));
+                        });
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(180.0),
+                                    height: Val::Px(50.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonExportWallet {
+                                export: WalletExport::Keypair,
+                                armed: false,
+                            },
+                            Name::new("ButtonExportWallet Keypair"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Export Key",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_MEDIUM,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(180.0),
+                                    height: Val::Px(50.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonExportWallet {
+                                export: WalletExport::Backup,
+                                armed: false,
+                            },
+                            Name::new("ButtonExportWallet Backup"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Export Backup",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_MEDIUM,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                });
+
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(700.0),
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("Reveal Seed Node"),
+                ))
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(300.0),
+                                    height: Val::Px(50.0),
+                                    justify_content: JustifyContent::Start,
+                                    align_items: AlignItems::Center,
+                                    overflow: Overflow {
+                                        x: OverflowAxis::Clip,
+                                        y: OverflowAxis::Clip,
+                                    },
+                                    padding: UiRect::left(Val::Px(10.0)),
+                                    ..default()
+                                },
+                                image: UiImage::new(
+                                    asset_server.load(CURRENT_TX_STATUS_BACKGROUND),
+                                ),
+                                ..default()
+                            },
+                            ButtonCaptureTextInput,
+                            Name::new("ButtonCaptureText Reveal Password"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                TextInput {
+                                    hidden: true,
+                                    numbers_only: false,
+                                    text: String::new(),
+                                },
+                                TextRevealPasswordInput,
+                                Name::new("TextRevealPasswordInput"),
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    visibility: Visibility::Hidden,
+                                    style: Style {
+                                        width: Val::Px(10.0),
+                                        height: Val::Px(15.0),
+                                        ..default()
+                                    },
+                                    background_color: Color::WHITE.into(),
+                                    ..default()
+                                },
+                                TextCursor,
+                                Name::new("TextCursor"),
+                            ));
+                        });
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(180.0),
+                                    height: Val::Px(50.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonRevealSeed,
+                            Name::new("ButtonRevealSeed"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Reveal Seed",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_MEDIUM,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(100.0),
+                                    height: Val::Px(50.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonHideSeed,
+                            Name::new("ButtonHideSeed"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Hide",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_MEDIUM,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                });
+
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_white_2().into()
+                    },
+                )
+                .with_style(Style {
+                    max_width: Val::Px(700.0),
+                    ..default()
+                }),
+                TextRevealedSeed,
+                Name::new("TextRevealedSeed"),
+            ));
         });
 }
 
@@ -3,16 +3,26 @@ use std::sync::Arc;
 use bevy::prelude::*;
 use solana_sdk::signature::Keypair;
 
-use crate::ui::{
-    components::{
-        BaseScreenNode, ButtonCaptureTextInput, ButtonGenerateWallet, ButtonSaveGeneratedWallet,
-        InitialSetupScreenNode, TextCursor, TextGeneratedKeypair, TextInput, TextMnemonicLine1,
-        TextMnemonicLine2, TextMnemonicLine3, TextPasswordInput, TextPasswordLabel,
-        WalletSetupScreenNode,
-    },
-    styles::{
-        BUTTON_GENERATE, BUTTON_SAVE_WALLET, FONT_REGULAR, FONT_SIZE_MEDIUM, NORMAL_BUTTON,
-        TREASURY_BACKGROUND,
+use crate::{
+    seed_phrase::{DerivationScheme, PREVIEW_ACCOUNTS, VERIFY_WORDS},
+    vanity::VanityOption,
+    ui::{
+        components::{
+            BaseScreenNode, ButtonCaptureTextInput, ButtonDerivationPath, ButtonGenerateWallet,
+            ButtonImportDerivedAddress, ButtonSaveGeneratedWallet, GeneratedSeedPhrase,
+            InitialSetupScreenNode, SeedVerifyNode, TextCursor, TextDerivationPath, TextDerivedAddress, TextGeneratedKeypair, TextInput,
+            TextMnemonicInput, TextMnemonicLine1, TextMnemonicLine2, TextMnemonicLine3,
+            TextMnemonicPassphraseInput, TextMnemonicStatus, TextMnemonicSuggestions,
+            TextPasswordInput, TextPasswordLabel, TextSeedVerifyInput, TextSeedVerifyLabel,
+            TextSeedVerifyStatus, TextVanityOption, TextVanityPrefixInput, TextVanitySearchButton,
+            TextVanityStatus, TextVanitySuffixInput, ButtonVanityOption, ButtonVanitySearch,
+            WalletSetupScreenNode,
+        },
+        spawn_utils::spawn_watch_pubkey_input,
+        styles::{
+            hex_dark_mode_text_gray, BUTTON_GENERATE, BUTTON_SAVE_WALLET, FONT_REGULAR,
+            FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, NORMAL_BUTTON, TREASURY_BACKGROUND,
+        },
     },
 };
 
@@ -105,7 +115,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                             NodeBundle {
                                 style: Style {
                                     width: Val::Percent(100.0),
-                                    height: Val::Percent(40.0),
+                                    height: Val::Percent(30.0),
                                     flex_direction: FlexDirection::Row,
                                     align_items: AlignItems::Center,
                                     justify_content: JustifyContent::Center,
@@ -201,7 +211,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                                             ));
                                             parent.spawn((
                                                 TextBundle::from_section(
-                                                    "Click Generate to make a new key. OR Drag-&-Drop a `.json` key file to import it.",
+                                                    "Click Generate to make a new key. OR Drag-&-Drop a `.json` key file or an exported backup, or enter a seed phrase below to import it.",
                                                     TextStyle {
                                                         font: asset_server.load(FONT_REGULAR),
                                                         font_size: FONT_SIZE_MEDIUM,
@@ -210,6 +220,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                                                 ),
                                                 Name::new("Text Generated Pubkey Value"),
                                                 TextGeneratedKeypair(Arc::new(Keypair::new())),
+                                                GeneratedSeedPhrase::default(),
                                             ));
                                             // spawn_copyable_text(
                                             //     parent,
@@ -351,15 +362,398 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                                                         TextMnemonicLine3,
                                                     ));
                                                 });
+                                            parent
+                                                .spawn((
+                                                    NodeBundle {
+                                                        style: Style {
+                                                            display: Display::None,
+                                                            width: Val::Percent(100.0),
+                                                            flex_direction: FlexDirection::Column,
+                                                            align_items: AlignItems::Center,
+                                                            row_gap: Val::Px(6.0),
+                                                            ..default()
+                                                        },
+                                                        ..default()
+                                                    },
+                                                    SeedVerifyNode,
+                                                    Name::new("Seed Verify Node"),
+                                                ))
+                                                .with_children(|parent| {
+                                                    parent
+                                                        .spawn(NodeBundle {
+                                                            style: Style {
+                                                                flex_direction: FlexDirection::Row,
+                                                                align_items: AlignItems::Center,
+                                                                column_gap: Val::Px(8.0),
+                                                                ..default()
+                                                            },
+                                                            ..default()
+                                                        })
+                                                        .with_children(|parent| {
+                                                            for slot in 0..VERIFY_WORDS {
+                                                                parent.spawn((
+                                                                    TextBundle::from_section(
+                                                                        "#",
+                                                                        TextStyle {
+                                                                            font: asset_server.load(FONT_REGULAR),
+                                                                            font_size: FONT_SIZE_SMALL,
+                                                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                                                        },
+                                                                    ),
+                                                                    TextSeedVerifyLabel(slot),
+                                                                    Name::new("TextSeedVerifyLabel"),
+                                                                ));
+                                                                parent
+                                                                    .spawn((
+                                                                        ButtonBundle {
+                                                                            style: Style {
+                                                                                width: Val::Px(120.0),
+                                                                                height: Val::Px(36.0),
+                                                                                border: UiRect::all(Val::Px(2.0)),
+                                                                                padding: UiRect::horizontal(Val::Px(6.0)),
+                                                                                justify_content: JustifyContent::Start,
+                                                                                align_items: AlignItems::Center,
+                                                                                overflow: Overflow {
+                                                                                    x: OverflowAxis::Clip,
+                                                                                    y: OverflowAxis::Clip,
+                                                                                },
+                                                                                ..default()
+                                                                            },
+                                                                            border_color: BorderColor(Color::BLACK),
+                                                                            background_color: NORMAL_BUTTON.into(),
+                                                                            ..default()
+                                                                        },
+                                                                        ButtonCaptureTextInput,
+                                                                        Name::new("ButtonCaptureText Seed Verify"),
+                                                                    ))
+                                                                    .with_children(|parent| {
+                                                                        parent.spawn((
+                                                                            TextBundle::from_section(
+                                                                                "",
+                                                                                TextStyle {
+                                                                                    font: asset_server.load(FONT_REGULAR),
+                                                                                    font_size: FONT_SIZE_SMALL,
+                                                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                                                },
+                                                                            ),
+                                                                            TextInput {
+                                                                                hidden: false,
+                                                                                numbers_only: false,
+                                                                                text: "".to_string(),
+                                                                            },
+                                                                            TextSeedVerifyInput(slot),
+                                                                            Name::new("TextSeedVerifyInput"),
+                                                                        ));
+                                                                        parent.spawn((
+                                                                            NodeBundle {
+                                                                                visibility: Visibility::Hidden,
+                                                                                style: Style {
+                                                                                    width: Val::Px(8.0),
+                                                                                    height: Val::Px(16.0),
+                                                                                    ..default()
+                                                                                },
+                                                                                background_color: Color::WHITE.into(),
+                                                                                ..default()
+                                                                            },
+                                                                            TextCursor,
+                                                                            Name::new("TextCursor"),
+                                                                        ));
+                                                                    });
+                                                            }
+                                                        });
+                                                    parent.spawn((
+                                                        TextBundle::from_section(
+                                                            "",
+                                                            TextStyle {
+                                                                font: asset_server.load(FONT_REGULAR),
+                                                                font_size: FONT_SIZE_SMALL,
+                                                                color: hex_dark_mode_text_gray(),
+                                                            },
+                                                        ),
+                                                        TextSeedVerifyStatus,
+                                                        Name::new("TextSeedVerifyStatus"),
+                                                    ));
+                                                });
                                         });
                                 });
                         });
+                    // seed phrase import
                     parent
                         .spawn((
                             NodeBundle {
                                 style: Style {
                                     width: Val::Percent(100.0),
-                                    height: Val::Percent(30.0),
+                                    height: Val::Percent(35.0),
+                                    flex_direction: FlexDirection::Column,
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Center,
+                                    row_gap: Val::Px(6.0),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            Name::new("Import Seed Phrase Node"),
+                        ))
+                        .with_children(|parent| {
+                            parent
+                                .spawn(NodeBundle {
+                                    style: Style {
+                                        flex_direction: FlexDirection::Row,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    ..default()
+                                })
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(
+                                        "Seed Phrase: ",
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(700.0),
+                                                    min_height: Val::Px(50.0),
+                                                    border: UiRect::all(Val::Px(2.5)),
+                                                    padding: UiRect::horizontal(Val::Px(8.0)),
+                                                    justify_content: JustifyContent::Start,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                border_color: BorderColor(Color::BLACK),
+                                                background_color: NORMAL_BUTTON.into(),
+                                                ..default()
+                                            },
+                                            ButtonCaptureTextInput,
+                                            Name::new("ButtonCaptureText Seed Phrase"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    "",
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_SMALL,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                )
+                                                .with_style(Style {
+                                                    max_width: Val::Px(670.0),
+                                                    ..default()
+                                                }),
+                                                TextInput {
+                                                    hidden: false,
+                                                    numbers_only: false,
+                                                    text: "".to_string(),
+                                                },
+                                                TextMnemonicInput,
+                                                Name::new("TextMnemonicInput"),
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(8.0),
+                                                        height: Val::Px(18.0),
+                                                        ..default()
+                                                    },
+                                                    background_color: Color::WHITE.into(),
+                                                    ..default()
+                                                },
+                                                TextCursor,
+                                                Name::new("TextCursor"),
+                                            ));
+                                        });
+                                });
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "Type or right click to paste a 12 or 24 word seed phrase. Tab completes a word.",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_SMALL,
+                                        color: hex_dark_mode_text_gray(),
+                                    },
+                                ),
+                                TextMnemonicSuggestions,
+                                Name::new("TextMnemonicSuggestions"),
+                            ));
+                            parent
+                                .spawn(NodeBundle {
+                                    style: Style {
+                                        flex_direction: FlexDirection::Row,
+                                        align_items: AlignItems::Center,
+                                        column_gap: Val::Px(10.0),
+                                        ..default()
+                                    },
+                                    ..default()
+                                })
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(
+                                        "Passphrase (optional): ",
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(200.0),
+                                                    height: Val::Px(40.0),
+                                                    border: UiRect::all(Val::Px(2.5)),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    overflow: Overflow {
+                                                        x: OverflowAxis::Clip,
+                                                        y: OverflowAxis::Clip,
+                                                    },
+                                                    ..default()
+                                                },
+                                                border_color: BorderColor(Color::BLACK),
+                                                background_color: NORMAL_BUTTON.into(),
+                                                ..default()
+                                            },
+                                            ButtonCaptureTextInput,
+                                            Name::new("ButtonCaptureText Passphrase"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    "",
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextInput {
+                                                    hidden: true,
+                                                    numbers_only: false,
+                                                    text: "".to_string(),
+                                                },
+                                                TextMnemonicPassphraseInput,
+                                                Name::new("TextMnemonicPassphraseInput"),
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(8.0),
+                                                        height: Val::Px(18.0),
+                                                        ..default()
+                                                    },
+                                                    background_color: Color::WHITE.into(),
+                                                    ..default()
+                                                },
+                                                TextCursor,
+                                                Name::new("TextCursor"),
+                                            ));
+                                        });
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(220.0),
+                                                    height: Val::Px(40.0),
+                                                    border: UiRect::all(Val::Px(2.5)),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                border_color: BorderColor(Color::BLACK),
+                                                background_color: NORMAL_BUTTON.into(),
+                                                ..default()
+                                            },
+                                            ButtonDerivationPath(DerivationScheme::default()),
+                                            Name::new("ButtonDerivationPath"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    DerivationScheme::default().label(),
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextDerivationPath,
+                                            ));
+                                        });
+                                });
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_SMALL,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                TextMnemonicStatus,
+                                Name::new("TextMnemonicStatus"),
+                            ));
+                            parent
+                                .spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            flex_direction: FlexDirection::Row,
+                                            column_gap: Val::Px(10.0),
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Derived Address Preview Node"),
+                                ))
+                                .with_children(|parent| {
+                                    for account in 0..PREVIEW_ACCOUNTS {
+                                        parent
+                                            .spawn((
+                                                ButtonBundle {
+                                                    style: Style {
+                                                        display: Display::None,
+                                                        padding: UiRect::all(Val::Px(6.0)),
+                                                        border: UiRect::all(Val::Px(2.0)),
+                                                        justify_content: JustifyContent::Center,
+                                                        align_items: AlignItems::Center,
+                                                        ..default()
+                                                    },
+                                                    border_color: BorderColor(Color::BLACK),
+                                                    background_color: NORMAL_BUTTON.into(),
+                                                    ..default()
+                                                },
+                                                ButtonImportDerivedAddress(account),
+                                                Name::new("ButtonImportDerivedAddress"),
+                                            ))
+                                            .with_children(|parent| {
+                                                parent.spawn((
+                                                    TextBundle::from_section(
+                                                        "",
+                                                        TextStyle {
+                                                            font: asset_server.load(FONT_REGULAR),
+                                                            font_size: FONT_SIZE_SMALL,
+                                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                                        },
+                                                    ),
+                                                    TextDerivedAddress(account),
+                                                ));
+                                            });
+                                    }
+                                });
+                        });
+                    parent
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Percent(20.0),
                                     flex_direction: FlexDirection::Row,
                                     align_items: AlignItems::Center,
                                     justify_content: JustifyContent::Center,
@@ -457,7 +851,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                             NodeBundle {
                                 style: Style {
                                     width: Val::Percent(100.0),
-                                    height: Val::Percent(20.0),
+                                    height: Val::Percent(15.0),
                                     flex_direction: FlexDirection::Row,
                                     align_items: AlignItems::Center,
                                     justify_content: JustifyContent::Center,
@@ -485,6 +879,232 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                             ));
                         });
                 });
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            row_gap: Val::Px(6.0),
+                            margin: UiRect::bottom(Val::Px(10.0)),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("Vanity Address Node"),
+                ))
+                .with_children(|parent| {
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::Row,
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(10.0),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Vanity address, prefix: ",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_SMALL,
+                                    color: hex_dark_mode_text_gray(),
+                                },
+                            ));
+                            parent
+                                .spawn((
+                                    ButtonBundle {
+                                        style: Style {
+                                            width: Val::Px(120.0),
+                                            height: Val::Px(40.0),
+                                            border: UiRect::all(Val::Px(2.5)),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        border_color: BorderColor(Color::BLACK),
+                                        background_color: NORMAL_BUTTON.into(),
+                                        ..default()
+                                    },
+                                    ButtonCaptureTextInput,
+                                    Name::new("ButtonCaptureText Vanity Prefix"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        TextBundle::from_section(
+                                            "",
+                                            TextStyle {
+                                                font: asset_server.load(FONT_REGULAR),
+                                                font_size: FONT_SIZE_SMALL,
+                                                color: Color::rgb(0.9, 0.9, 0.9),
+                                            },
+                                        ),
+                                        TextInput {
+                                            hidden: false,
+                                            numbers_only: false,
+                                            text: "".to_string(),
+                                        },
+                                        TextVanityPrefixInput,
+                                        Name::new("TextVanityPrefixInput"),
+                                    ));
+                                    parent.spawn((
+                                        NodeBundle {
+                                            visibility: Visibility::Hidden,
+                                            style: Style {
+                                                width: Val::Px(8.0),
+                                                height: Val::Px(18.0),
+                                                ..default()
+                                            },
+                                            background_color: Color::WHITE.into(),
+                                            ..default()
+                                        },
+                                        TextCursor,
+                                        Name::new("TextCursor"),
+                                    ));
+                                });
+                            parent.spawn(TextBundle::from_section(
+                                "suffix: ",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_SMALL,
+                                    color: hex_dark_mode_text_gray(),
+                                },
+                            ));
+                            parent
+                                .spawn((
+                                    ButtonBundle {
+                                        style: Style {
+                                            width: Val::Px(120.0),
+                                            height: Val::Px(40.0),
+                                            border: UiRect::all(Val::Px(2.5)),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        border_color: BorderColor(Color::BLACK),
+                                        background_color: NORMAL_BUTTON.into(),
+                                        ..default()
+                                    },
+                                    ButtonCaptureTextInput,
+                                    Name::new("ButtonCaptureText Vanity Suffix"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        TextBundle::from_section(
+                                            "",
+                                            TextStyle {
+                                                font: asset_server.load(FONT_REGULAR),
+                                                font_size: FONT_SIZE_SMALL,
+                                                color: Color::rgb(0.9, 0.9, 0.9),
+                                            },
+                                        ),
+                                        TextInput {
+                                            hidden: false,
+                                            numbers_only: false,
+                                            text: "".to_string(),
+                                        },
+                                        TextVanitySuffixInput,
+                                        Name::new("TextVanitySuffixInput"),
+                                    ));
+                                    parent.spawn((
+                                        NodeBundle {
+                                            visibility: Visibility::Hidden,
+                                            style: Style {
+                                                width: Val::Px(8.0),
+                                                height: Val::Px(18.0),
+                                                ..default()
+                                            },
+                                            background_color: Color::WHITE.into(),
+                                            ..default()
+                                        },
+                                        TextCursor,
+                                        Name::new("TextCursor"),
+                                    ));
+                                });
+                            for option in [VanityOption::IgnoreCase, VanityOption::SeedPhrase] {
+                                parent
+                                    .spawn((
+                                        ButtonBundle {
+                                            style: Style {
+                                                width: Val::Px(160.0),
+                                                height: Val::Px(40.0),
+                                                border: UiRect::all(Val::Px(2.5)),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..default()
+                                            },
+                                            border_color: BorderColor(Color::BLACK),
+                                            background_color: NORMAL_BUTTON.into(),
+                                            ..default()
+                                        },
+                                        ButtonVanityOption {
+                                            option,
+                                            enabled: false,
+                                        },
+                                        Name::new("ButtonVanityOption"),
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            TextBundle::from_section(
+                                                option.label(false),
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_SMALL,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ),
+                                            TextVanityOption(option),
+                                        ));
+                                    });
+                            }
+                            parent
+                                .spawn((
+                                    ButtonBundle {
+                                        style: Style {
+                                            width: Val::Px(100.0),
+                                            height: Val::Px(40.0),
+                                            border: UiRect::all(Val::Px(2.5)),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        border_color: BorderColor(Color::BLACK),
+                                        background_color: NORMAL_BUTTON.into(),
+                                        ..default()
+                                    },
+                                    ButtonVanitySearch,
+                                    Name::new("ButtonVanitySearch"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        TextBundle::from_section(
+                                            "Search",
+                                            TextStyle {
+                                                font: asset_server.load(FONT_REGULAR),
+                                                font_size: FONT_SIZE_SMALL,
+                                                color: Color::rgb(0.9, 0.9, 0.9),
+                                            },
+                                        ),
+                                        TextVanitySearchButton,
+                                    ));
+                                });
+                        });
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE_SMALL,
+                                color: hex_dark_mode_text_gray(),
+                            },
+                        ),
+                        TextVanityStatus,
+                        Name::new("TextVanityStatus"),
+                    ));
+                });
+            spawn_watch_pubkey_input(parent, &asset_server);
         });
 }
 
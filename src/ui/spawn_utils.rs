@@ -1,4 +1,4 @@
-use crate::utils::shorten_string;
+use crate::{events::ToastSeverity, utils::shorten_string};
 use bevy::{
     a11y::{
         accesskit::{NodeBuilder, Role},
@@ -352,6 +352,28 @@ pub fn spawn_web_link_icon(
         });
 }
 
+pub fn spawn_account_link_icon(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    address: String,
+) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                width: Val::Px(14.0),
+                height: Val::Px(14.0),
+                margin: UiRect::left(Val::Px(8.0)),
+                align_self: AlignSelf::Center,
+                ..default()
+            },
+            image: UiImage::new(asset_server.load(SOLANA_ICON)),
+            ..default()
+        },
+        ButtonOpenWebAccountExplorer(address),
+        Name::new("ButtonOpenWebAccountExplorer"),
+    ));
+}
+
 pub fn spawn_fps_counter(mut commands: Commands) {
     // create our UI root node
     // this is the wrapper/container for the text
@@ -418,3 +440,296 @@ pub fn spawn_fps_counter(mut commands: Commands) {
         .id();
     commands.entity(root).push_children(&[text_fps]);
 }
+
+pub fn spawn_toast(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    toast_area: Entity,
+    severity: ToastSeverity,
+    message: String,
+) {
+    let toast = commands
+        .spawn((
+            ButtonBundle {
+                background_color: hex_dark_mode_nav_title().into(),
+                border_color: severity.color().into(),
+                style: Style {
+                    width: Val::Percent(100.0),
+                    min_height: Val::Px(40.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    border: UiRect::left(Val::Px(4.0)),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            Toast {
+                timer: Timer::new(severity.duration(), TimerMode::Once),
+            },
+            Name::new("Toast"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    message,
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_X_SMALL,
+                        color: hex_dark_mode_text_white().into(),
+                    },
+                ),
+                Name::new("TextToast"),
+            ));
+        })
+        .id();
+
+    commands.entity(toast_area).add_child(toast);
+}
+
+pub fn spawn_wallet_list_item(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    index: usize,
+    name: String,
+    pubkey: String,
+    active: bool,
+) {
+    parent
+        .spawn((
+            NodeBundle {
+                background_color: hex_dark_mode_nav_title().into(),
+                border_color: if active { Color::WHITE.into() } else { Color::NONE.into() },
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(50.0),
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    border: UiRect::left(Val::Px(4.0)),
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(20.0),
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("WalletListItem"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    name,
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_white().into(),
+                    },
+                )
+                .with_style(Style {
+                    width: Val::Px(220.0),
+                    ..default()
+                }),
+                Name::new("TextWalletName"),
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    shorten_string(pubkey, 10),
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_SMALL,
+                        color: hex_dark_mode_text_gray().into(),
+                    },
+                ),
+                Name::new("TextWalletPubkey"),
+            ));
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_grow: 1.0,
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::End,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    if active {
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "ACTIVE",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_SMALL,
+                                    color: hex_dark_mode_text_white_2().into(),
+                                },
+                            ),
+                            Name::new("TextWalletActive"),
+                        ));
+                        return;
+                    }
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(90.0),
+                                    height: Val::Px(36.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonSwitchWallet(index),
+                            Name::new("ButtonSwitchWallet"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Switch",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_SMALL,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(90.0),
+                                    height: Val::Px(36.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonRemoveWallet { index, armed: false },
+                            Name::new("ButtonRemoveWallet"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Remove",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_SMALL,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                });
+        });
+}
+
+/// Pubkey input and button for watch-only mode, on the unlock and setup screens.
+pub fn spawn_watch_pubkey_input(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
+    parent
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("Watch Pubkey Node"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Watch only: ",
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE_SMALL,
+                    color: hex_dark_mode_text_gray().into(),
+                },
+            ));
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(400.0),
+                            height: Val::Px(40.0),
+                            border: UiRect::all(Val::Px(2.5)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        border_color: BorderColor(Color::BLACK),
+                        background_color: NORMAL_BUTTON.into(),
+                        ..default()
+                    },
+                    ButtonCaptureTextInput,
+                    Name::new("ButtonCaptureText"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE_SMALL,
+                                color: Color::rgb(0.9, 0.9, 0.9),
+                            },
+                        ),
+                        TextInput {
+                            hidden: false,
+                            numbers_only: false,
+                            text: "".to_string(),
+                        },
+                        TextWatchPubkeyInput,
+                    ));
+                    parent.spawn((
+                        NodeBundle {
+                            visibility: Visibility::Hidden,
+                            style: Style {
+                                width: Val::Px(8.0),
+                                height: Val::Px(18.0),
+                                ..default()
+                            },
+                            background_color: Color::WHITE.into(),
+                            ..default()
+                        },
+                        TextCursor,
+                        Name::new("TextCursor"),
+                    ));
+                });
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(100.0),
+                            height: Val::Px(40.0),
+                            border: UiRect::all(Val::Px(2.5)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        border_color: BorderColor(Color::BLACK),
+                        background_color: NORMAL_BUTTON.into(),
+                        ..default()
+                    },
+                    ButtonWatchPubkey,
+                    Name::new("ButtonWatchPubkey"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Watch",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_SMALL,
+                            color: Color::rgb(0.9, 0.9, 0.9),
+                        },
+                    ));
+                });
+        });
+}
@@ -4,12 +4,13 @@ use bevy::prelude::*;
 use copypasta::{ClipboardContext, ClipboardProvider};
 
 use crate::{
-    AppConfig, EventClaimOreRewards, EventGenerateWallet, EventLock, EventRequestAirdrop, EventSaveConfig, EventSaveWallet, EventStakeOre, EventStartStopMining, EventUnlock, OreAppState
+    utils::derive_ws_url, AppConfig, AppScreenState, Explorer, ExplorerLink, EventClaimOreRewards, EventGenerateWallet, EventLock, EventRequestAirdrop, EventSaveConfig, EventSaveWallet, EventStakeOre, EventStartStopMining, EventTestConnection, EventToast, EventUnlock, EventAddWallet, EventImportMnemonic, EventRemoveWallet, EventRenameWallet, EventSwitchWallet, OreAppState, ToastHistoryResource, vault::WalletVaultResource, EventChangePassword, EventExportWallet, EventRevealSeed, EventWatchPubkey, EventStartVanitySearch, EventStopVanitySearch, WalletExport, seed_phrase::{derive_keypair, DerivationScheme, SeedBackup}, tasks::TaskVanitySearch, vanity::{VanityOption, VanitySource}
 };
+use bip39::{Language, Mnemonic, MnemonicType};
 
 use super::{
     components::{
-        AutoScrollCheckIcon, ButtonAutoScroll, ButtonCaptureTextInput, ButtonClaimOreRewards, ButtonCooldownSpinner, ButtonCopyText, ButtonGenerateWallet, ButtonLock, ButtonOpenWebTxExplorer, ButtonRequestAirdrop, ButtonSaveConfig, ButtonSaveGeneratedWallet, ButtonStakeOre, ButtonUnlock, CopyableText, TextConfigInputRpcFetchAccountsInterval, TextConfigInputRpcSendTxInterval, TextConfigInputRpcUrl, TextConfigInputThreads, TextInput, ToggleAutoMine
+        ButtonVanityOption, ButtonVanitySearch, TextVanityOption, TextVanityPrefixInput, TextVanitySuffixInput, AutoScrollCheckIcon, ButtonAddWallet, ButtonChangePassword, ButtonExportWallet, ButtonForgotPassword, ButtonHideSeed, ButtonWatchPubkey, TextWatchPubkeyInput, ButtonRevealSeed, GeneratedSeedPhrase, TextConfirmPasswordInput, TextCurrentPasswordInput, TextExportPasswordInput, TextNewPasswordInput, TextRevealPasswordInput, TextRevealedSeed, ButtonDerivationPath, ButtonImportDerivedAddress, TextDerivationPath, TextMnemonicInput, TextMnemonicPassphraseInput, ButtonRemoveWallet, ButtonRenameWallet, ButtonSwitchWallet, TextWalletNameInput, ButtonAutoScroll, ButtonCaptureTextInput, ButtonClaimOreRewards, ButtonConfigBusSelector, ButtonConfigCluster, ButtonCooldownSpinner, ButtonCopyText, ButtonGenerateWallet, ButtonLock, ButtonConfigExplorer, ButtonOpenWebAccountExplorer, ButtonOpenWebTxExplorer, ButtonRequestAirdrop, ButtonSaveConfig, ButtonSaveGeneratedWallet, ButtonStakeOre, ButtonTestConnection, ButtonToastHistory, ButtonUnlock, CopyableText, TextConfigBusSelector, TextConfigCluster, TextConfigExplorer, TextConfigInputAutoLock, TextConfigInputBackupRpcUrls, TextConfigInputExplorerTemplate, TextConfigInputRpcFetchAccountsInterval, TextConfigInputRpcSendTxInterval, TextConfigInputRpcUrl, TextConfigInputThreads, TextConfigInputWsUrl, TextConnectionTestResult, TextInput, Toast, ToastHistoryDrawer, ToggleAutoMine
     },
     styles::{hex_dark_mode_app_screen_background, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON},
 };
@@ -63,6 +64,7 @@ pub fn button_open_web_tx_explorer(
         (Changed<Interaction>, With<ButtonOpenWebTxExplorer>),
     >,
     text_query: Query<(&CopyableText, &Children)>,
+    app_state: Res<OreAppState>,
 ) {
     for (entity, interaction, mut color, mut border_color) in &mut interaction_query {
         match *interaction {
@@ -78,7 +80,7 @@ pub fn button_open_web_tx_explorer(
                     }
                 }
                 if let Some(text) = text {
-                    let url = format!("https://solscan.io/tx/{}", text);
+                    let url = app_state.config.explorer_url(ExplorerLink::Tx(&text));
                     if let Err(_) = open::that(url) {
                         error!("Failed to open web tx explorer with default web browser.");
                     }
@@ -96,6 +98,36 @@ pub fn button_open_web_tx_explorer(
     }
 }
 
+pub fn button_open_web_account_explorer(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &ButtonOpenWebAccountExplorer),
+        Changed<Interaction>,
+    >,
+    app_state: Res<OreAppState>,
+) {
+    for (interaction, mut color, address) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+
+                if address.0.is_empty() {
+                    continue;
+                }
+                let url = app_state.config.explorer_url(ExplorerLink::Account(&address.0));
+                if let Err(_) = open::that(url) {
+                    error!("Failed to open web account explorer with default web browser.");
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+            }
+        }
+    }
+}
+
 pub fn button_start_stop_mining(
     mut ev_start_stop_mining: EventWriter<EventStartStopMining>,
     mut interaction_query: Query<
@@ -262,6 +294,56 @@ pub fn button_unlock(
     }
 }
 
+pub fn button_forgot_password(
+    mut interaction_query: Query<
+        (&Interaction, &mut ButtonForgotPassword, &Children, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut query_text: Query<&mut Text>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for (interaction, mut button, children, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if button.armed {
+                    next_state.set(AppScreenState::WalletSetup);
+                    continue;
+                }
+                button.armed = true;
+                for child in children.iter() {
+                    if let Ok(mut text) = query_text.get_mut(*child) {
+                        text.sections[0].value = "Confirm".to_string();
+                    }
+                }
+                event_writer_toast.send(EventToast::warning(
+                    "Press Confirm to restore from a seed phrase or backup. Saving replaces save.data, the old file is kept as a .bak copy next to it.",
+                ));
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+
+                if button.armed {
+                    button.armed = false;
+                    for child in children.iter() {
+                        if let Ok(mut text) = query_text.get_mut(*child) {
+                            text.sections[0].value = "Forgot Password".to_string();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub fn button_capture_text(
     interaction_query: Query<
         (
@@ -294,7 +376,16 @@ pub fn button_save_config(
         Query<&TextInput, With<TextConfigInputThreads>>,
         Query<&TextInput, With<TextConfigInputRpcFetchAccountsInterval>>,
         Query<&TextInput, With<TextConfigInputRpcSendTxInterval>>,
+        Query<&ButtonConfigBusSelector>,
+        Query<&TextInput, With<TextConfigInputBackupRpcUrls>>,
+        Query<&TextInput, With<TextConfigInputWsUrl>>,
+        Query<&ButtonConfigCluster>,
     )>,
+    query_explorer: Query<&ButtonConfigExplorer>,
+    query_explorer_template: Query<&TextInput, With<TextConfigInputExplorerTemplate>>,
+    query_auto_lock: Query<&TextInput, With<TextConfigInputAutoLock>>,
+    app_state: Res<OreAppState>,
+    mut event_writer_toast: EventWriter<EventToast>,
 ) {
     for (_entity, interaction, mut ui_image, mut color) in &mut interaction_query {
         match *interaction {
@@ -312,11 +403,13 @@ pub fn button_save_config(
                 };
                 let threads = if let Ok(single) = set.p1().get_single() {
                     let threads = single.text.clone().parse::<u64>();
-                    if let Ok(threads) = threads {
-                        threads
-                    } else {
-                        error!("Failed to parse text_threads.");
-                        break;
+                    match threads {
+                        Ok(threads) if threads > 0 => threads,
+                        _ => {
+                            error!("Failed to parse text_threads.");
+                            event_writer_toast.send(EventToast::error("Threads must be a whole number of at least 1."));
+                            break;
+                        },
                     }
                 } else {
                     error!("Failed to get text_threads.");
@@ -328,6 +421,7 @@ pub fn button_save_config(
                         parsed
                     } else {
                         error!("Failed to parse text_rpc_fetch_interval.");
+                        event_writer_toast.send(EventToast::error("Fetch accounts interval must be a whole number of ms."));
                         break;
                     }
                 } else {
@@ -340,6 +434,7 @@ pub fn button_save_config(
                         parsed
                     } else {
                         error!("Failed to parse text_rpc_send_interval.");
+                        event_writer_toast.send(EventToast::error("Send tx interval must be a whole number of ms."));
                         break;
                     }
                 } else {
@@ -347,16 +442,96 @@ pub fn button_save_config(
                     break;
                 };
 
-                let ws_url =  "ws".to_string() + &text_rpc_url[4..];
+                let auto_lock_minutes = if let Ok(single) = query_auto_lock.get_single() {
+                    let parsed = single.text.clone().parse::<u64>();
+                    if let Ok(parsed) = parsed {
+                        parsed
+                    } else {
+                        error!("Failed to parse auto_lock_minutes.");
+                        event_writer_toast.send(EventToast::error("Auto lock must be a whole number of minutes."));
+                        break;
+                    }
+                } else {
+                    error!("Failed to get auto_lock_minutes.");
+                    break;
+                };
+
+                let bus_selector = if let Ok(single) = set.p4().get_single() {
+                    single.0
+                } else {
+                    error!("Failed to get bus_selector.");
+                    break;
+                };
+
+                let backup_rpc_urls: Vec<String> = if let Ok(single) = set.p5().get_single() {
+                    single
+                        .text
+                        .split(',')
+                        .map(|url| url.trim().to_string())
+                        .filter(|url| !url.is_empty())
+                        .collect()
+                } else {
+                    error!("Failed to get backup_rpc_urls.");
+                    break;
+                };
+
+                let text_ws_url = if let Ok(single) = set.p6().get_single() {
+                    single.text.trim().to_string()
+                } else {
+                    error!("Failed to get text_ws_url.");
+                    break;
+                };
+                let cluster = if let Ok(single) = set.p7().get_single() {
+                    single.0
+                } else {
+                    error!("Failed to get cluster.");
+                    break;
+                };
+                let explorer = if let Ok(single) = query_explorer.get_single() {
+                    single.0
+                } else {
+                    error!("Failed to get explorer.");
+                    break;
+                };
+                let explorer_custom_template = if let Ok(single) = query_explorer_template.get_single() {
+                    single.text.trim().to_string()
+                } else {
+                    error!("Failed to get explorer_custom_template.");
+                    break;
+                };
+
+                if explorer == Explorer::Custom && !Explorer::is_valid_custom_template(&explorer_custom_template) {
+                    event_writer_toast.send(EventToast::error("The custom explorer URL needs an {id} placeholder."));
+                    break;
+                }
+
+                // a blank ws url is derived from the rpc url
+                let ws_url = if text_ws_url.is_empty() {
+                    if let Some(ws_url) = derive_ws_url(&text_rpc_url) {
+                        ws_url
+                    } else {
+                        error!("Cannot derive ws url from rpc url: {}", text_rpc_url);
+                        event_writer_toast.send(EventToast::error("Enter a WS URL, it can't be derived from this RPC URL."));
+                        break;
+                    }
+                } else {
+                    text_ws_url
+                };
 
                 event_writer.send(EventSaveConfig(AppConfig {
                     rpc_url: text_rpc_url.clone(),
                     ws_url: ws_url.clone(),
-                    // TODO: fix for mainnet
-                    is_devnet: true,
+                    cluster,
                     threads,
                     ui_fetch_interval: text_rpc_fetch_interval,
                     tx_send_interval: text_rpc_send_interval,
+                    auto_lock_minutes,
+                    bus_selector,
+                    backup_rpc_urls,
+                    explorer,
+                    explorer_custom_template,
+                    // only set by editing config.toml
+                    vault_kdf_iterations: app_state.config.vault_kdf_iterations,
                     ..Default::default()
                 }));
             }
@@ -376,11 +551,180 @@ pub fn button_save_config(
     }
 }
 
+pub fn button_test_connection(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ButtonTestConnection>),
+    >,
+    mut event_writer: EventWriter<EventTestConnection>,
+    query_cluster: Query<&ButtonConfigCluster>,
+    mut set: ParamSet<(
+        Query<&TextInput, With<TextConfigInputRpcUrl>>,
+        Query<&TextInput, With<TextConfigInputWsUrl>>,
+        Query<&mut Text, With<TextConnectionTestResult>>,
+    )>,
+) {
+    for (interaction, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+
+                let rpc_url = if let Ok(single) = set.p0().get_single() {
+                    single.text.trim().to_string()
+                } else {
+                    error!("Failed to get text_rpc_url.");
+                    break;
+                };
+                let text_ws_url = if let Ok(single) = set.p1().get_single() {
+                    single.text.trim().to_string()
+                } else {
+                    error!("Failed to get text_ws_url.");
+                    break;
+                };
+                let ws_url = if text_ws_url.is_empty() {
+                    derive_ws_url(&rpc_url)
+                } else {
+                    Some(text_ws_url)
+                };
+                let cluster = if let Ok(single) = query_cluster.get_single() {
+                    single.0
+                } else {
+                    error!("Failed to get cluster.");
+                    break;
+                };
+
+                let status = if let Some(ws_url) = ws_url {
+                    event_writer.send(EventTestConnection {
+                        rpc_url,
+                        ws_url,
+                        cluster,
+                    });
+                    "Testing...".to_string()
+                } else {
+                    "Failed: rpc url must start with http:// or https://".to_string()
+                };
+                if let Ok(mut text) = set.p2().get_single_mut() {
+                    text.sections[0].value = status;
+                    text.sections[0].style.color = Color::rgb(0.9, 0.9, 0.9);
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+            }
+        }
+    }
+}
+
+pub fn button_config_cluster(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut ButtonConfigCluster, &Children),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut Text, With<TextConfigCluster>>,
+    mut set: ParamSet<(
+        Query<&mut TextInput, With<TextConfigInputRpcUrl>>,
+        Query<&mut TextInput, With<TextConfigInputWsUrl>>,
+    )>,
+) {
+    for (interaction, mut color, mut button_cluster, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+
+                button_cluster.0 = button_cluster.0.next();
+                for child in children {
+                    if let Ok(mut text) = text_query.get_mut(*child) {
+                        text.sections[0].value = button_cluster.0.to_string();
+                    }
+                }
+
+                // custom keeps whatever urls were entered
+                if let Some(rpc_url) = button_cluster.0.default_rpc_url() {
+                    if let Ok(mut text_input) = set.p0().get_single_mut() {
+                        text_input.text = rpc_url.to_string();
+                    }
+                    if let Ok(mut text_input) = set.p1().get_single_mut() {
+                        text_input.text = button_cluster.0.default_ws_url().unwrap_or_default().to_string();
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+            }
+        }
+    }
+}
+
+pub fn button_config_explorer(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut ButtonConfigExplorer, &Children),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut Text, With<TextConfigExplorer>>,
+) {
+    for (interaction, mut color, mut button_explorer, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+
+                button_explorer.0 = button_explorer.0.next();
+                for child in children {
+                    if let Ok(mut text) = text_query.get_mut(*child) {
+                        text.sections[0].value = button_explorer.0.to_string();
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+            }
+        }
+    }
+}
+
+pub fn button_config_bus_selector(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut ButtonConfigBusSelector, &Children),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut Text, With<TextConfigBusSelector>>,
+) {
+    for (interaction, mut color, mut button_bus_selector, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+
+                button_bus_selector.0 = button_bus_selector.0.next();
+                for child in children {
+                    if let Ok(mut text) = text_query.get_mut(*child) {
+                        text.sections[0].value = button_bus_selector.0.to_string();
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+            }
+        }
+    }
+}
+
 pub fn button_save_wallet(
     mut interaction_query: Query<
         (Entity, &Interaction, &mut UiImage, &mut BackgroundColor),
         (Changed<Interaction>, With<ButtonSaveGeneratedWallet>),
     >,
+    query_seed: Query<&GeneratedSeedPhrase>,
     mut event_writer: EventWriter<EventSaveWallet>,
 ) {
     for (_entity, interaction, mut ui_image, mut color) in &mut interaction_query {
@@ -400,7 +744,8 @@ pub fn button_save_wallet(
                 // }
             }
             Interaction::None => {
-                *color = Color::WHITE.into();
+                let verified = query_seed.get_single().map(|seed| seed.verified).unwrap_or(true);
+                *color = if verified { Color::WHITE.into() } else { Color::GRAY.into() };
                 // if ui_image.flip_y {
                 //     ui_image.flip_y = false;
                 // }
@@ -535,3 +880,564 @@ pub fn button_request_airdrop(
         }
     }
 }
+
+pub fn button_toast_history(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ButtonToastHistory>)>,
+    mut query_drawer: Query<&mut Style, With<ToastHistoryDrawer>>,
+    mut toast_history: ResMut<ToastHistoryResource>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            if let Ok(mut style) = query_drawer.get_single_mut() {
+                style.display = if style.display == Display::None {
+                    toast_history.unread = 0;
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+            }
+        }
+    }
+}
+
+pub fn button_dismiss_toast(
+    mut commands: Commands,
+    interaction_query: Query<(Entity, &Interaction), (Changed<Interaction>, With<Toast>)>,
+) {
+    for (entity, interaction) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn button_add_wallet(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonAddWallet>),
+    >,
+    mut query_name: Query<&mut TextInput, With<TextWalletNameInput>>,
+    mut event_writer: EventWriter<EventAddWallet>,
+) {
+    for (interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                let name = if let Ok(mut text_input) = query_name.get_single_mut() {
+                    std::mem::take(&mut text_input.text)
+                } else {
+                    String::new()
+                };
+                // derived from a fresh phrase so it can be revealed later
+                let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+                match derive_keypair(&mnemonic, "", DerivationScheme::default(), 0) {
+                    Ok(keypair) => {
+                        event_writer.send(EventAddWallet {
+                            name,
+                            keypair,
+                            seed: Some(SeedBackup::generated(mnemonic.into_phrase())),
+                        });
+                    },
+                    Err(e) => error!("{}", e),
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_rename_wallet(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonRenameWallet>),
+    >,
+    mut query_name: Query<&mut TextInput, With<TextWalletNameInput>>,
+    vault_res: Res<WalletVaultResource>,
+    mut event_writer: EventWriter<EventRenameWallet>,
+) {
+    for (interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if let (Some(vault), Ok(mut text_input)) = (&vault_res.vault, query_name.get_single_mut()) {
+                    event_writer.send(EventRenameWallet {
+                        index: vault.active,
+                        name: std::mem::take(&mut text_input.text),
+                    });
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_watch_pubkey(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonWatchPubkey>),
+    >,
+    query_pubkey: Query<&TextInput, With<TextWatchPubkeyInput>>,
+    mut event_writer: EventWriter<EventWatchPubkey>,
+) {
+    for (interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if let Ok(text_input) = query_pubkey.get_single() {
+                    event_writer.send(EventWatchPubkey(text_input.text.clone()));
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_switch_wallet(
+    mut interaction_query: Query<
+        (&Interaction, &ButtonSwitchWallet, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut event_writer: EventWriter<EventSwitchWallet>,
+) {
+    for (interaction, button, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventSwitchWallet(button.0));
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_remove_wallet(
+    mut interaction_query: Query<
+        (&Interaction, &mut ButtonRemoveWallet, &Children, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut query_text: Query<&mut Text>,
+    vault_res: Res<WalletVaultResource>,
+    mut event_writer: EventWriter<EventRemoveWallet>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for (interaction, mut button, children, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if button.armed {
+                    event_writer.send(EventRemoveWallet(button.index));
+                    continue;
+                }
+                button.armed = true;
+                for child in children.iter() {
+                    if let Ok(mut text) = query_text.get_mut(*child) {
+                        text.sections[0].value = "Confirm".to_string();
+                    }
+                }
+                let name = vault_res
+                    .vault
+                    .as_ref()
+                    .and_then(|vault| vault.wallets.get(button.index))
+                    .map(|wallet| wallet.name.clone())
+                    .unwrap_or_default();
+                event_writer_toast.send(EventToast::warning(format!(
+                    "Press Confirm to remove {}. Its keypair is lost unless you have a backup.",
+                    name
+                )));
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+
+                // moving off the button cancels the removal
+                if button.armed {
+                    button.armed = false;
+                    for child in children.iter() {
+                        if let Ok(mut text) = query_text.get_mut(*child) {
+                            text.sections[0].value = "Remove".to_string();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn button_derivation_path(
+    mut interaction_query: Query<
+        (&Interaction, &mut ButtonDerivationPath, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut query_text: Query<&mut Text, With<TextDerivationPath>>,
+) {
+    for (interaction, mut button, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                button.0 = button.0.next();
+                if let Ok(mut text) = query_text.get_single_mut() {
+                    text.sections[0].value = button.0.label().to_string();
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_import_derived_address(
+    mut interaction_query: Query<
+        (&Interaction, &ButtonImportDerivedAddress, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    query_phrase: Query<&TextInput, With<TextMnemonicInput>>,
+    query_passphrase: Query<&TextInput, With<TextMnemonicPassphraseInput>>,
+    query_scheme: Query<&ButtonDerivationPath>,
+    mut event_writer: EventWriter<EventImportMnemonic>,
+) {
+    for (interaction, button, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if let (Ok(phrase), Ok(passphrase), Ok(scheme)) = (
+                    query_phrase.get_single(),
+                    query_passphrase.get_single(),
+                    query_scheme.get_single(),
+                ) {
+                    event_writer.send(EventImportMnemonic {
+                        phrase: phrase.text.clone(),
+                        passphrase: passphrase.text.clone(),
+                        scheme: scheme.0,
+                        account: button.0,
+                    });
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_reveal_seed(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonRevealSeed>),
+    >,
+    mut query_password: Query<&mut TextInput, With<TextRevealPasswordInput>>,
+    vault_res: Res<WalletVaultResource>,
+    mut event_writer: EventWriter<EventRevealSeed>,
+) {
+    for (interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                let password = if let Ok(mut text_input) = query_password.get_single_mut() {
+                    std::mem::take(&mut text_input.text)
+                } else {
+                    String::new()
+                };
+                if let Some(vault) = &vault_res.vault {
+                    event_writer.send(EventRevealSeed {
+                        index: vault.active,
+                        password,
+                    });
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_change_password(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonChangePassword>),
+    >,
+    mut set: ParamSet<(
+        Query<&mut TextInput, With<TextCurrentPasswordInput>>,
+        Query<&mut TextInput, With<TextNewPasswordInput>>,
+        Query<&mut TextInput, With<TextConfirmPasswordInput>>,
+    )>,
+    mut event_writer: EventWriter<EventChangePassword>,
+) {
+    for (interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                let current = set
+                    .p0()
+                    .get_single_mut()
+                    .map(|mut input| std::mem::take(&mut input.text))
+                    .unwrap_or_default();
+                let new = set
+                    .p1()
+                    .get_single_mut()
+                    .map(|mut input| std::mem::take(&mut input.text))
+                    .unwrap_or_default();
+                let confirm = set
+                    .p2()
+                    .get_single_mut()
+                    .map(|mut input| std::mem::take(&mut input.text))
+                    .unwrap_or_default();
+                event_writer.send(EventChangePassword {
+                    current,
+                    new,
+                    confirm,
+                });
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_export_wallet(
+    mut interaction_query: Query<
+        (&Interaction, &mut ButtonExportWallet, &Children, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut query_text: Query<&mut Text>,
+    mut query_password: Query<&mut TextInput, With<TextExportPasswordInput>>,
+    mut event_writer: EventWriter<EventExportWallet>,
+    mut event_writer_toast: EventWriter<EventToast>,
+) {
+    for (interaction, mut button, children, mut color, mut border_color) in &mut interaction_query {
+        let label = match button.export {
+            WalletExport::Keypair => "Export Key",
+            WalletExport::Backup => "Export Backup",
+        };
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if button.armed {
+                    let password = if let Ok(mut text_input) = query_password.get_single_mut() {
+                        std::mem::take(&mut text_input.text)
+                    } else {
+                        String::new()
+                    };
+                    event_writer.send(EventExportWallet {
+                        export: button.export,
+                        password,
+                    });
+                    continue;
+                }
+                button.armed = true;
+                for child in children.iter() {
+                    if let Ok(mut text) = query_text.get_mut(*child) {
+                        text.sections[0].value = "Confirm".to_string();
+                    }
+                }
+                event_writer_toast.send(EventToast::warning(match button.export {
+                    WalletExport::Keypair => "The exported key file is not encrypted, anyone who reads it controls the wallet. Press Confirm to export.",
+                    WalletExport::Backup => "The backup holds every wallet and opens with the current password. Press Confirm to export.",
+                }));
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+
+                // moving off the button cancels the export
+                if button.armed {
+                    button.armed = false;
+                    for child in children.iter() {
+                        if let Ok(mut text) = query_text.get_mut(*child) {
+                            text.sections[0].value = label.to_string();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn button_hide_seed(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonHideSeed>),
+    >,
+    mut query_revealed: Query<&mut Text, With<TextRevealedSeed>>,
+) {
+    for (interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if let Ok(mut text) = query_revealed.get_single_mut() {
+                    text.sections[0].value = String::new();
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_vanity_option(
+    mut interaction_query: Query<
+        (&Interaction, &mut ButtonVanityOption, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut query_text: Query<(&mut Text, &TextVanityOption)>,
+) {
+    for (interaction, mut button, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                button.enabled = !button.enabled;
+                for (mut text, text_option) in query_text.iter_mut() {
+                    if text_option.0 == button.option {
+                        text.sections[0].value = button.option.label(button.enabled).to_string();
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_vanity_search(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonVanitySearch>),
+    >,
+    query_prefix: Query<&TextInput, With<TextVanityPrefixInput>>,
+    query_suffix: Query<&TextInput, With<TextVanitySuffixInput>>,
+    query_options: Query<&ButtonVanityOption>,
+    query_pending: Query<(), With<TaskVanitySearch>>,
+    mut event_writer_start: EventWriter<EventStartVanitySearch>,
+    mut event_writer_stop: EventWriter<EventStopVanitySearch>,
+) {
+    for (interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if !query_pending.is_empty() {
+                    event_writer_stop.send(EventStopVanitySearch);
+                    continue;
+                }
+
+                let option_enabled = |option: VanityOption| {
+                    query_options.iter().any(|button| button.option == option && button.enabled)
+                };
+                let source = if option_enabled(VanityOption::SeedPhrase) {
+                    VanitySource::SeedPhrase
+                } else {
+                    VanitySource::Keypair
+                };
+                event_writer_start.send(EventStartVanitySearch {
+                    prefix: query_prefix.get_single().map(|input| input.text.clone()).unwrap_or_default(),
+                    suffix: query_suffix.get_single().map(|input| input.text.clone()).unwrap_or_default(),
+                    ignore_case: option_enabled(VanityOption::IgnoreCase),
+                    source,
+                });
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
@@ -8,20 +8,54 @@ use solana_sdk::signer::Signer;
 
 use crate::ore_utils::get_ore_decimals;
 use crate::ore_utils::ORE_TOKEN_DECIMALS;
-use crate::utils::{get_unix_timestamp, human_bytes, shorten_string};
+use crate::utils::{get_unix_timestamp, human_bytes, human_duration, shorten_string};
 use crate::AppWallet;
+use crate::BusAnalyticsResource;
 use crate::BussesResource;
+use crate::Cluster;
 use crate::HashrateResource;
 use crate::MinerStatusResource;
 use crate::MiningProofsResource;
 use crate::OreAppState;
 use crate::ProofAccountResource;
+use crate::RpcConnection;
+use crate::ToastHistoryResource;
 use crate::TreasuryAccountResource;
+use crate::subscriptions::WsStatus;
+use crate::subscriptions::WsStatusResource;
+use crate::tasks::{TaskUnlockVault, TaskVanitySearch};
+use crate::vanity::VanitySearchResource;
+use crate::vault::{UnlockAttempts, WalletVaultResource};
+use crate::seed_phrase::{derive_keypair, parse_mnemonic, word_suggestions};
 
 use super::components::ButtonCaptureTextInput;
+use super::components::ButtonOpenWalletExplorer;
+use super::components::ButtonOpenWebAccountExplorer;
 use super::components::FpsRoot;
+use super::components::SettingsWalletList;
+use super::components::ButtonDerivationPath;
+use super::components::ButtonImportDerivedAddress;
+use super::components::ButtonSaveGeneratedWallet;
+use super::components::GeneratedSeedPhrase;
+use super::components::SeedVerifyNode;
+use super::components::TextDerivedAddress;
+use super::components::TextMnemonicInput;
+use super::components::TextMnemonicPassphraseInput;
+use super::components::TextMnemonicStatus;
+use super::components::TextMnemonicSuggestions;
+use super::components::TextSeedVerifyInput;
+use super::components::TextSeedVerifyLabel;
+use super::components::TextUnlockStatus;
+use super::components::TextVanitySearchButton;
+use super::components::TextVanityStatus;
+use super::components::ButtonClaimOreRewards;
+use super::components::ButtonRequestAirdrop;
+use super::components::ButtonStakeOre;
+use super::components::ToggleAutoMineParent;
+use super::components::TextSeedVerifyStatus;
 use super::components::FpsText;
 use super::components::ScrollingList;
+use super::components::TextActiveRpc;
 use super::components::TextActiveMinersLastEpoch;
 use super::components::TextActiveMinersThisEpoch;
 use super::components::TextBurnAmount;
@@ -33,6 +67,9 @@ use super::components::TextBus5;
 use super::components::TextBus6;
 use super::components::TextBus7;
 use super::components::TextBus8;
+use super::components::TextBusAnalytics;
+use super::components::TextBusSelector;
+use super::components::TextClusterBadge;
 use super::components::TextCurrentStake;
 use super::components::TextCurrentChallenge;
 use super::components::TextCursor;
@@ -53,7 +90,14 @@ use super::components::TextTreasuryRewardRate;
 use super::components::TextWalletOreBalance;
 use super::components::TextWalletPubkey;
 use super::components::TextWalletSolBalance;
+use super::components::TextToastHistoryButton;
+use super::components::TextWsStatus;
+use super::components::Toast;
+use super::components::ToastHistoryList;
+use super::spawn_utils::spawn_wallet_list_item;
 use super::styles::hex_dark_mode_text_gray;
+use super::styles::FONT_REGULAR;
+use super::styles::FONT_SIZE_X_SMALL;
 
 pub fn mouse_scroll(
     mut mouse_wheel_events: EventReader<MouseWheel>,
@@ -88,9 +132,21 @@ pub fn update_app_wallet_ui(
         Query<&mut Text, With<TextWalletSolBalance>>,
         Query<&mut Text, With<TextWalletOreBalance>>,
         Query<&mut Text, With<TextWalletPubkey>>,
+        Query<&mut ButtonOpenWebAccountExplorer, With<ButtonOpenWalletExplorer>>,
     )>,
 ) {
-    if let Some(wallet) = &app_wallet.wallet {
+    let wallet_address = if let Some(pubkey) = app_wallet.pubkey() {
+        pubkey.to_string()
+    } else {
+        String::new()
+    };
+    for mut button in set.p3().iter_mut() {
+        if button.0 != wallet_address {
+            button.0 = wallet_address.clone();
+        }
+    }
+
+    if let Some(wallet_pubkey) = app_wallet.pubkey() {
         let mut text_sol_balance_query = set.p0();
         let mut text_sol_balance = text_sol_balance_query.single_mut();
         text_sol_balance.sections[0].value = app_wallet.sol_balance.to_string() + " SOL";
@@ -102,7 +158,10 @@ pub fn update_app_wallet_ui(
         let mut text_wallet_pubkey_query = set.p2();
         let mut text_wallet_pubkey = text_wallet_pubkey_query.single_mut();
 
-        let pubkey = shorten_string(wallet.pubkey().to_string(), 10);
+        let mut pubkey = shorten_string(wallet_pubkey.to_string(), 10);
+        if app_wallet.wallet.is_none() {
+            pubkey += " (watch-only)";
+        }
         text_wallet_pubkey.sections[0].value = pubkey;
     } else {
         let mut text_sol_balance_query = set.p0();
@@ -134,143 +193,172 @@ pub fn update_busses_ui(
         Query<&mut Text, With<TextBus8>>,
     )>,
 ) {
-    if busses_res.busses.len() > 7 {
-        let mut text_bus_query = set.p0();
-        for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[0].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
-            let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
-                Color::GREEN
-            } else {
-                Color::ORANGE
-            };
-            
-            if busses_res.current_bus_id == 0 {
-                text_component.sections[0].style.color = selected_color.into();
-            } else {
-                text_component.sections[0].style.color = hex_dark_mode_text_gray();
-            }
-        }
-
-        let mut text_bus_query = set.p1();
-        for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[1].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
-            let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
-                Color::GREEN
-            } else {
-                Color::ORANGE
-            };
-            
-            if busses_res.current_bus_id == 1 {
-                text_component.sections[0].style.color = selected_color.into();
-            } else {
-                text_component.sections[0].style.color = hex_dark_mode_text_gray();
-            }
-        }
+    if busses_res.busses.is_empty() {
+        return;
+    }
+    let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
+        Color::GREEN
+    } else {
+        Color::ORANGE
+    };
 
+    for mut text_component in set.p0().iter_mut() {
+        sync_bus_text(&mut text_component, &busses_res, 0, selected_color);
+    }
+    for mut text_component in set.p1().iter_mut() {
+        sync_bus_text(&mut text_component, &busses_res, 1, selected_color);
+    }
+    for mut text_component in set.p2().iter_mut() {
+        sync_bus_text(&mut text_component, &busses_res, 2, selected_color);
+    }
+    for mut text_component in set.p3().iter_mut() {
+        sync_bus_text(&mut text_component, &busses_res, 3, selected_color);
+    }
+    for mut text_component in set.p4().iter_mut() {
+        sync_bus_text(&mut text_component, &busses_res, 4, selected_color);
+    }
+    for mut text_component in set.p5().iter_mut() {
+        sync_bus_text(&mut text_component, &busses_res, 5, selected_color);
+    }
+    for mut text_component in set.p6().iter_mut() {
+        sync_bus_text(&mut text_component, &busses_res, 6, selected_color);
+    }
+    for mut text_component in set.p7().iter_mut() {
+        sync_bus_text(&mut text_component, &busses_res, 7, selected_color);
+    }
+}
 
-        let mut text_bus_query = set.p2();
-        for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[2].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
+// busses that failed to decode are left out of the resource, so look them up by id
+fn sync_bus_text(text_component: &mut Text, busses_res: &BussesResource, bus_id: u64, selected_color: Color) {
+    match busses_res.busses.iter().position(|bus| bus.id == bus_id) {
+        Some(index) => {
+            let rewards = (busses_res.busses[index].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
             text_component.sections[0].value = format!("{}", rewards);
-            let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
-                Color::GREEN
-            } else {
-                Color::ORANGE
-            };
-            
-            if busses_res.current_bus_id == 2 {
-                text_component.sections[0].style.color = selected_color.into();
+            if busses_res.current_bus_id == index {
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
-        }
+        },
+        None => {
+            text_component.sections[0].value = "unavailable".to_string();
+            text_component.sections[0].style.color = hex_dark_mode_text_gray();
+        },
+    }
+}
 
-        let mut text_bus_query = set.p3();
-        for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[3].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
-            let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
-                Color::GREEN
+pub fn update_active_rpc_ui(
+    rpc_connection: Res<RpcConnection>,
+    mut query: Query<&mut Text, With<TextActiveRpc>>,
+) {
+    if let Ok(mut text_component) = query.get_single_mut() {
+        let value = if let Some(endpoint) = rpc_connection.endpoints.get(rpc_connection.active_endpoint) {
+            let latency = if let Some(latency_ms) = endpoint.latency_ms {
+                format!("{}ms", latency_ms)
             } else {
-                Color::ORANGE
+                "--".to_string()
             };
-            
-            if busses_res.current_bus_id == 3 {
-                text_component.sections[0].style.color = selected_color.into();
-            } else {
-                text_component.sections[0].style.color = hex_dark_mode_text_gray();
-            }
+            format!(
+                "RPC {}/{}: {} ({})",
+                rpc_connection.active_endpoint + 1,
+                rpc_connection.endpoints.len(),
+                endpoint.host(),
+                latency
+            )
+        } else {
+            "RPC: not connected".to_string()
+        };
+        if text_component.sections[0].value != value {
+            text_component.sections[0].value = value;
         }
+    }
+}
 
-        let mut text_bus_query = set.p4();
-        for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[4].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
-            let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
-                Color::GREEN
-            } else {
-                Color::ORANGE
-            };
-            
-            if busses_res.current_bus_id == 4 {
-                text_component.sections[0].style.color = selected_color.into();
-            } else {
-                text_component.sections[0].style.color = hex_dark_mode_text_gray();
-            }
+pub fn update_cluster_badge_ui(
+    app_state: Res<OreAppState>,
+    mut query: Query<&mut Text, With<TextClusterBadge>>,
+) {
+    if let Ok(mut text_component) = query.get_single_mut() {
+        let cluster = app_state.config.cluster;
+        let value = cluster.to_string().to_uppercase();
+        // mainnet is real funds, make it stand out
+        let color = match cluster {
+            Cluster::Mainnet => Color::RED,
+            Cluster::Devnet => Color::GREEN,
+            Cluster::Localnet | Cluster::Custom => Color::ORANGE,
+        };
+        if text_component.sections[0].value != value || text_component.sections[0].style.color != color {
+            text_component.sections[0].value = value;
+            text_component.sections[0].style.color = color;
         }
+    }
+}
 
-        let mut text_bus_query = set.p5();
-        for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[5].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
-            let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
-                Color::GREEN
-            } else {
-                Color::ORANGE
-            };
-            
-            if busses_res.current_bus_id == 5 {
-                text_component.sections[0].style.color = selected_color.into();
-            } else {
-                text_component.sections[0].style.color = hex_dark_mode_text_gray();
-            }
+pub fn update_ws_status_ui(
+    ws_status: Res<WsStatusResource>,
+    mut query: Query<&mut Text, With<TextWsStatus>>,
+) {
+    if let Ok(mut text_component) = query.get_single_mut() {
+        let ws_value = match ws_status.status {
+            WsStatus::Live => {
+                if let Some(last_update_at) = ws_status.last_update_at {
+                    format!("Live ({}s ago)", last_update_at.elapsed().as_secs())
+                } else {
+                    "Live (waiting)".to_string()
+                }
+            },
+            WsStatus::Connecting => "Connecting".to_string(),
+            WsStatus::Reconnecting => format!("Reconnecting (attempt {})", ws_status.reconnect_attempts),
+        };
+        let (value, color) = if ws_status.polling {
+            (format!("Polling RPC | WS: {}", ws_value), Color::ORANGE)
+        } else {
+            (format!("WS: {}", ws_value), Color::GREEN)
+        };
+        if text_component.sections[0].value != value {
+            text_component.sections[0].value = value;
+            text_component.sections[0].style.color = color;
         }
+    }
+}
 
-        let mut text_bus_query = set.p6();
-        for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[6].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
-            let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
-                Color::GREEN
+pub fn update_bus_analytics_ui(
+    busses_res: Res<BussesResource>,
+    bus_analytics: Res<BusAnalyticsResource>,
+    mut query: Query<(&mut Text, &TextBusAnalytics)>,
+) {
+    for (mut text_component, bus_index) in query.iter_mut() {
+        if let Some(bus) = busses_res.busses.iter().find(|bus| bus.id as usize == bus_index.0) {
+            let hits_per_minute = bus_analytics.hits_per_minute(bus.id);
+            let drained_per_minute = bus_analytics.rewards_drained_per_minute(bus.id)
+                / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
+            let depletion = if let Some(minutes) = bus_analytics.minutes_until_depleted(bus.id, bus.rewards) {
+                format!("empty ~{:.1}m", minutes)
             } else {
-                Color::ORANGE
+                "--".to_string()
             };
-            
-            if busses_res.current_bus_id == 6 {
-                text_component.sections[0].style.color = selected_color.into();
-            } else {
-                text_component.sections[0].style.color = hex_dark_mode_text_gray();
-            }
+            text_component.sections[0].value = format!(
+                "{:.1} hits/m | -{:.4}/m | {}",
+                hits_per_minute, drained_per_minute, depletion
+            );
         }
+    }
+}
 
-        let mut text_bus_query = set.p7();
-        for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[7].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
-            let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
-                Color::GREEN
-            } else {
-                Color::ORANGE
-            };
-            
-            if busses_res.current_bus_id == 7 {
-                text_component.sections[0].style.color = selected_color.into();
-            } else {
-                text_component.sections[0].style.color = hex_dark_mode_text_gray();
-            }
-        }
+pub fn update_bus_selector_ui(
+    app_state: Res<OreAppState>,
+    busses_res: Res<BussesResource>,
+    mut query: Query<&mut Text, With<TextBusSelector>>,
+) {
+    if let Ok(mut text_component) = query.get_single_mut() {
+        text_component.sections[0].value = format!(
+            "{} - Bus {}",
+            app_state.config.bus_selector.to_string(),
+            busses_res.busses
+                .get(busses_res.current_bus_id)
+                .map(|bus| (bus.id + 1).to_string())
+                .unwrap_or("--".to_string())
+        );
     }
 }
 
@@ -545,3 +633,317 @@ pub fn fps_counter_showhide(
         };
     }
 }
+
+pub fn update_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Toast)>,
+) {
+    for (entity, mut toast) in query.iter_mut() {
+        toast.timer.tick(time.delta());
+        if toast.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn update_toast_history_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    toast_history: Res<ToastHistoryResource>,
+    query_list: Query<Entity, With<ToastHistoryList>>,
+    mut query_button_text: Query<&mut Text, With<TextToastHistoryButton>>,
+) {
+    if !toast_history.is_changed() {
+        return;
+    }
+
+    if let Ok(mut text_component) = query_button_text.get_single_mut() {
+        text_component.sections[0].value = if toast_history.unread > 0 {
+            format!("Notifications ({})", toast_history.unread)
+        } else {
+            "Notifications".to_string()
+        };
+    }
+
+    if let Ok(list) = query_list.get_single() {
+        commands.entity(list).despawn_descendants();
+        commands.entity(list).with_children(|parent| {
+            for entry in toast_history.entries.iter().rev() {
+                let time = if let Some(dt) = DateTime::from_timestamp(entry.created_at as i64, 0) {
+                    dt.format("%H:%M:%S").to_string()
+                } else {
+                    "--".to_string()
+                };
+                parent.spawn((
+                    TextBundle::from_section(
+                        format!("{} {}", time, entry.message),
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_X_SMALL,
+                            color: entry.severity.color(),
+                        },
+                    ),
+                    Name::new("TextToastHistoryItem"),
+                ));
+            }
+        });
+    }
+}
+
+pub fn update_settings_wallet_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    vault_res: Res<WalletVaultResource>,
+    query_list: Query<(Entity, Ref<SettingsWalletList>)>,
+) {
+    let (list, list_ref) = if let Ok(list) = query_list.get_single() {
+        list
+    } else {
+        return;
+    };
+    if !vault_res.is_changed() && !list_ref.is_added() {
+        return;
+    }
+
+    commands.entity(list).despawn_descendants();
+    if let Some(vault) = &vault_res.vault {
+        commands.entity(list).with_children(|parent| {
+            for (index, wallet) in vault.wallets.iter().enumerate() {
+                spawn_wallet_list_item(
+                    parent,
+                    &asset_server,
+                    index,
+                    wallet.name.clone(),
+                    wallet.pubkey().to_string(),
+                    index == vault.active,
+                );
+            }
+        });
+    }
+}
+
+pub fn update_mnemonic_import_ui(
+    query_phrase: Query<Ref<TextInput>, With<TextMnemonicInput>>,
+    query_passphrase: Query<Ref<TextInput>, With<TextMnemonicPassphraseInput>>,
+    query_scheme: Query<Ref<ButtonDerivationPath>>,
+    mut query_preview: Query<(&mut Style, &ButtonImportDerivedAddress)>,
+    mut set: ParamSet<(
+        Query<&mut Text, With<TextMnemonicSuggestions>>,
+        Query<&mut Text, With<TextMnemonicStatus>>,
+        Query<(&mut Text, &TextDerivedAddress)>,
+    )>,
+) {
+    let (phrase, passphrase, scheme) = if let (Ok(phrase), Ok(passphrase), Ok(scheme)) = (
+        query_phrase.get_single(),
+        query_passphrase.get_single(),
+        query_scheme.get_single(),
+    ) {
+        (phrase, passphrase, scheme)
+    } else {
+        return;
+    };
+    if !phrase.is_changed() && !passphrase.is_changed() && !scheme.is_changed() {
+        return;
+    }
+
+    if let Ok(mut text) = set.p0().get_single_mut() {
+        text.sections[0].value = if phrase.text.is_empty() {
+            "Type or right click to paste a 12 or 24 word seed phrase. Tab completes a word.".to_string()
+        } else {
+            word_suggestions(&phrase.text).join("   ")
+        };
+    }
+
+    let mnemonic = if phrase.text.trim().is_empty() {
+        Err(String::new())
+    } else {
+        parse_mnemonic(&phrase.text)
+    };
+    if let Ok(mut text) = set.p1().get_single_mut() {
+        text.sections[0].value = match &mnemonic {
+            Ok(_) => "Valid seed phrase. Click an address to import it.".to_string(),
+            Err(e) => e.clone(),
+        };
+    }
+
+    let mut addresses = vec![];
+    if let Ok(mnemonic) = &mnemonic {
+        for account in 0..scheme.0.accounts() {
+            match derive_keypair(mnemonic, &passphrase.text, scheme.0, account) {
+                Ok(keypair) => addresses.push(keypair.pubkey().to_string()),
+                Err(e) => error!("{}", e),
+            }
+        }
+    }
+    for (mut style, button) in query_preview.iter_mut() {
+        style.display = if (button.0 as usize) < addresses.len() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    for (mut text, text_address) in set.p2().iter_mut() {
+        if let Some(address) = addresses.get(text_address.0 as usize) {
+            text.sections[0].value = format!("#{} {}", text_address.0, shorten_string(address.clone(), 8));
+        }
+    }
+}
+
+/// Hides the buttons that would sign while only a pubkey is watched.
+pub fn update_watch_only_ui(
+    app_wallet: Res<AppWallet>,
+    mut query: Query<
+        &mut Style,
+        Or<(
+            With<ButtonClaimOreRewards>,
+            With<ButtonStakeOre>,
+            With<ButtonRequestAirdrop>,
+            With<ToggleAutoMineParent>,
+        )>,
+    >,
+) {
+    let display = if app_wallet.wallet.is_some() {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut style in query.iter_mut() {
+        if style.display != display {
+            style.display = display;
+        }
+    }
+}
+
+pub fn update_unlock_status_ui(
+    attempts: Res<UnlockAttempts>,
+    query_pending: Query<(), With<TaskUnlockVault>>,
+    mut query: Query<&mut Text, With<TextUnlockStatus>>,
+) {
+    let (value, color) = if !query_pending.is_empty() {
+        ("Unlocking...".to_string(), Color::rgb(0.9, 0.9, 0.9))
+    } else if let Some(error) = &attempts.error {
+        (error.clone(), Color::RED)
+    } else if attempts.failed > 0 {
+        let retry_in = attempts.retry_in(get_unix_timestamp());
+        let value = if retry_in > 0 {
+            format!("Wrong password, {} failed attempts. Try again in {}s.", attempts.failed, retry_in)
+        } else {
+            format!("Wrong password, {} failed attempts.", attempts.failed)
+        };
+        (value, Color::RED)
+    } else {
+        (String::new(), Color::rgb(0.9, 0.9, 0.9))
+    };
+
+    for mut text in query.iter_mut() {
+        if text.sections[0].value != value {
+            text.sections[0].value = value.clone();
+            text.sections[0].style.color = color;
+        }
+    }
+}
+
+pub fn update_vanity_search_ui(
+    vanity_res: Res<VanitySearchResource>,
+    query_pending: Query<(), With<TaskVanitySearch>>,
+    mut set: ParamSet<(
+        Query<&mut Text, With<TextVanityStatus>>,
+        Query<&mut Text, With<TextVanitySearchButton>>,
+    )>,
+) {
+    let searching = !query_pending.is_empty();
+    let value = if searching {
+        let eta = match vanity_res.eta_secs() {
+            Some(eta) if eta > 0.0 => human_duration(eta),
+            Some(_) => "any moment".to_string(),
+            None => "...".to_string(),
+        };
+        format!(
+            "Checked {} keys at {:.0}/s. ETA {} (1 in {:.0} keys match)",
+            vanity_res.attempts(),
+            vanity_res.rate(),
+            eta,
+            vanity_res.expected_attempts
+        )
+    } else if vanity_res.finished_in.is_some() {
+        format!(
+            "Checked {} keys in {} at {:.0}/s.",
+            vanity_res.attempts(),
+            human_duration(vanity_res.elapsed().as_secs_f64()),
+            vanity_res.rate()
+        )
+    } else {
+        String::new()
+    };
+    let button_value = if searching { "Stop" } else { "Search" };
+
+    for mut text in set.p0().iter_mut() {
+        if text.sections[0].value != value {
+            text.sections[0].value = value.clone();
+        }
+    }
+    for mut text in set.p1().iter_mut() {
+        if text.sections[0].value != button_value {
+            text.sections[0].value = button_value.to_string();
+        }
+    }
+}
+
+pub fn update_seed_verification_ui(
+    mut query_seed: Query<&mut GeneratedSeedPhrase>,
+    query_inputs: Query<(Ref<TextInput>, &TextSeedVerifyInput)>,
+    mut query_node: Query<&mut Style, With<SeedVerifyNode>>,
+    mut query_save_button: Query<&mut BackgroundColor, With<ButtonSaveGeneratedWallet>>,
+    mut set: ParamSet<(
+        Query<(&mut Text, &TextSeedVerifyLabel)>,
+        Query<&mut Text, With<TextSeedVerifyStatus>>,
+    )>,
+) {
+    let mut seed_phrase = if let Ok(seed_phrase) = query_seed.get_single_mut() {
+        seed_phrase
+    } else {
+        return;
+    };
+    if !seed_phrase.is_changed() && !query_inputs.iter().any(|(input, _)| input.is_changed()) {
+        return;
+    }
+
+    let needs_verify = !seed_phrase.verify_indices.is_empty();
+    if needs_verify {
+        let verified = if let Some(backup) = &seed_phrase.backup {
+            let words = backup.words();
+            query_inputs.iter().all(|(input, slot)| {
+                match seed_phrase.verify_indices.get(slot.0) {
+                    Some(index) => input.text.trim().to_lowercase() == words[*index],
+                    None => true,
+                }
+            })
+        } else {
+            false
+        };
+        // only write on a change so the next frame doesn't run this again
+        if seed_phrase.verified != verified {
+            seed_phrase.verified = verified;
+        }
+    }
+
+    if let Ok(mut style) = query_node.get_single_mut() {
+        style.display = if needs_verify { Display::Flex } else { Display::None };
+    }
+    for (mut text, label) in set.p0().iter_mut() {
+        if let Some(index) = seed_phrase.verify_indices.get(label.0) {
+            text.sections[0].value = format!("#{}", index + 1);
+        }
+    }
+    if let Ok(mut text) = set.p1().get_single_mut() {
+        text.sections[0].value = if seed_phrase.verified {
+            "Seed phrase verified.".to_string()
+        } else {
+            "Write the seed phrase down, then type the requested words to confirm.".to_string()
+        };
+    }
+    if let Ok(mut color) = query_save_button.get_single_mut() {
+        *color = if seed_phrase.verified { Color::WHITE.into() } else { Color::GRAY.into() };
+    }
+}
@@ -1,4 +1,9 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use bevy::utils::HashMap;
+use rand::Rng;
+
+use crate::BusSelector;
 
 const SUFFIX: [&str; 9] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
 
@@ -22,6 +27,26 @@ pub fn human_bytes<T: Into<f64>>(bytes: T) -> String {
     [&result, SUFFIX[base.floor() as usize]].join(" ")
 }
 
+/// Converts seconds to the two largest units, "3m 20s" or "2d 5h"
+pub fn human_duration(secs: f64) -> String {
+    let secs = secs.max(0.0);
+    if secs >= 365.0 * 86400.0 {
+        return format!("{:.1} years", secs / (365.0 * 86400.0));
+    }
+
+    let secs = secs.round() as u64;
+    let (days, hours, minutes, seconds) = (secs / 86400, secs % 86400 / 3600, secs % 3600 / 60, secs % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
 pub fn shorten_string(text: String, max_len: usize) -> String {
     let len = text.len();
     if len > max_len {
@@ -35,6 +60,34 @@ pub fn shorten_string(text: String, max_len: usize) -> String {
     }
 }
 
+/// Derives the websocket url from an http(s) rpc url, keeping host, port and path.
+pub fn derive_ws_url(rpc_url: &str) -> Option<String> {
+    let rpc_url = rpc_url.trim();
+    if let Some(rest) = rpc_url.strip_prefix("https://") {
+        Some(format!("wss://{}", rest))
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        Some(format!("ws://{}", rest))
+    } else {
+        None
+    }
+}
+
+/// Percent-encodes everything but unreserved characters, for urls passed as query params.
+pub fn url_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char);
+            },
+            _ => {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    encoded
+}
+
 pub fn get_unix_timestamp() -> u64 {
     let time = SystemTime::now();
     time.duration_since(UNIX_EPOCH)
@@ -57,4 +110,75 @@ pub fn find_best_bus(busses: &Vec<ore_api::state::Bus>) -> usize {
     } else {
         0
     }
-}
\ No newline at end of file
+}
+
+pub fn select_bus(
+    selector: BusSelector,
+    busses: &Vec<ore_api::state::Bus>,
+    current_bus_id: usize,
+    last_hit_at: &HashMap<u64, Instant>,
+    min_rewards: u64,
+) -> usize {
+    match selector {
+        BusSelector::MaxRewards => find_best_bus(busses),
+        BusSelector::WeightedRandom => find_weighted_random_bus(busses),
+        BusSelector::LeastRecentlyHit => find_least_recently_hit_bus(busses, last_hit_at),
+        BusSelector::StickyUntilDepleted => {
+            if let Some(bus) = busses.get(current_bus_id) {
+                if bus.rewards > min_rewards {
+                    return current_bus_id;
+                }
+            }
+            find_best_bus(busses)
+        }
+    }
+}
+
+pub fn find_weighted_random_bus(busses: &Vec<ore_api::state::Bus>) -> usize {
+    let total_rewards: u128 = busses.iter().map(|bus| bus.rewards as u128).sum();
+    if total_rewards == 0 {
+        return find_best_bus(busses);
+    }
+
+    let mut pick = rand::thread_rng().gen_range(0..total_rewards);
+    for (i, bus) in busses.iter().enumerate() {
+        let rewards = bus.rewards as u128;
+        if pick < rewards {
+            return i;
+        }
+        pick -= rewards;
+    }
+
+    find_best_bus(busses)
+}
+
+/// Picks the bus that has gone the longest without a hit. Busses never seen
+/// being hit come first, ties go to the bus with more rewards.
+pub fn find_least_recently_hit_bus(
+    busses: &Vec<ore_api::state::Bus>,
+    last_hit_at: &HashMap<u64, Instant>,
+) -> usize {
+    let mut best_bus = 0;
+    for (i, bus) in busses.iter().enumerate() {
+        if i == 0 || bus.rewards == 0 {
+            continue;
+        }
+        let best = &busses[best_bus];
+        if best.rewards == 0 {
+            best_bus = i;
+            continue;
+        }
+        let hit_at = last_hit_at.get(&bus.id);
+        let best_hit_at = last_hit_at.get(&best.id);
+        let is_older = match (hit_at, best_hit_at) {
+            (None, Some(_)) => true,
+            (Some(_), None) => false,
+            (Some(a), Some(b)) => a < b || (a == b && bus.rewards > best.rewards),
+            (None, None) => bus.rewards > best.rewards,
+        };
+        if is_older {
+            best_bus = i;
+        }
+    }
+    best_bus
+}
@@ -0,0 +1,271 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use bevy::{log::error, prelude::Resource};
+use bip39::{Language, Mnemonic, MnemonicType};
+use crossbeam_channel::{Receiver, Sender};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::seed_phrase::{derive_keypair, DerivationScheme};
+
+pub const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// Anything longer takes years on a desktop cpu.
+pub const MAX_PATTERN_LEN: usize = 8;
+/// Attempts a search thread counts before adding them to the shared total.
+const ATTEMPTS_BATCH: u64 = 100;
+
+/// What the base58 pubkey has to start and/or end with.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VanityPattern {
+    pub prefix: String,
+    pub suffix: String,
+    pub ignore_case: bool,
+}
+
+impl VanityPattern {
+    pub fn new(prefix: &str, suffix: &str, ignore_case: bool) -> Result<Self, String> {
+        let prefix = prefix.trim();
+        let suffix = suffix.trim();
+        if prefix.is_empty() && suffix.is_empty() {
+            return Err("Enter a prefix or a suffix to search for.".to_string());
+        }
+        if prefix.len() + suffix.len() > MAX_PATTERN_LEN {
+            return Err(format!("Prefix and suffix are limited to {} characters together.", MAX_PATTERN_LEN));
+        }
+        let pattern = Self {
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+            ignore_case,
+        };
+        if let Some(c) = pattern.chars().find(|c| pattern.alphabet_matches(*c) == 0) {
+            return Err(format!("'{}' can never appear in an address.", c));
+        }
+        Ok(pattern)
+    }
+
+    fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.prefix.chars().chain(self.suffix.chars())
+    }
+
+    /// How many base58 characters `c` accepts, 'a' accepts 'a' and 'A' when
+    /// ignoring case but 'o' only has the lowercase letter.
+    fn alphabet_matches(&self, c: char) -> usize {
+        BASE58_ALPHABET
+            .chars()
+            .filter(|a| if self.ignore_case { a.eq_ignore_ascii_case(&c) } else { *a == c })
+            .count()
+    }
+
+    pub fn matches(&self, address: &str) -> bool {
+        let address = address.as_bytes();
+        let (prefix, suffix) = (self.prefix.as_bytes(), self.suffix.as_bytes());
+        if address.len() < prefix.len() + suffix.len() {
+            return false;
+        }
+        let start = &address[..prefix.len()];
+        let end = &address[address.len() - suffix.len()..];
+        if self.ignore_case {
+            start.eq_ignore_ascii_case(prefix) && end.eq_ignore_ascii_case(suffix)
+        } else {
+            start == prefix && end == suffix
+        }
+    }
+
+    /// Keys to try on average before a match. A rough figure, the first
+    /// character of a 44 character address is never above '5'.
+    pub fn expected_attempts(&self) -> f64 {
+        self.chars()
+            .map(|c| BASE58_ALPHABET.len() as f64 / self.alphabet_matches(c) as f64)
+            .product()
+    }
+}
+
+/// Where a candidate keypair comes from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VanitySource {
+    Keypair,
+    /// 12 word phrase derived like a generated wallet, around 100x slower.
+    SeedPhrase,
+}
+
+impl VanitySource {
+    fn generate(&self) -> Result<(Keypair, Option<String>), String> {
+        match self {
+            VanitySource::Keypair => Ok((Keypair::new(), None)),
+            VanitySource::SeedPhrase => {
+                let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+                let keypair = derive_keypair(&mnemonic, "", DerivationScheme::default(), 0)?;
+                Ok((keypair, Some(mnemonic.into_phrase())))
+            }
+        }
+    }
+}
+
+/// Settings toggled on the wallet setup screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VanityOption {
+    IgnoreCase,
+    SeedPhrase,
+}
+
+impl VanityOption {
+    pub fn label(&self, enabled: bool) -> &'static str {
+        match (self, enabled) {
+            (VanityOption::IgnoreCase, true) => "Case: Any",
+            (VanityOption::IgnoreCase, false) => "Case: Exact",
+            (VanityOption::SeedPhrase, true) => "Seed Phrase (slow)",
+            (VanityOption::SeedPhrase, false) => "Keypair Only",
+        }
+    }
+}
+
+pub struct VanityMatch {
+    pub keypair: Keypair,
+    pub phrase: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum VanitySearchMessage {
+    Stop,
+}
+
+/// Shared with the search threads, the ui reads the attempt count for the
+/// rate and eta.
+#[derive(Resource, Default)]
+pub struct VanitySearchResource {
+    pub receiver: Option<Receiver<VanitySearchMessage>>,
+    pub sender: Option<Sender<VanitySearchMessage>>,
+    pub attempts: Arc<AtomicU64>,
+    pub expected_attempts: f64,
+    pub started_at: Option<Instant>,
+    pub finished_in: Option<Duration>,
+}
+
+impl VanitySearchResource {
+    pub fn start(&mut self, pattern: &VanityPattern) {
+        self.attempts.store(0, Ordering::Relaxed);
+        self.expected_attempts = pattern.expected_attempts();
+        self.started_at = Some(Instant::now());
+        self.finished_in = None;
+    }
+
+    pub fn finish(&mut self) {
+        self.finished_in = self.started_at.map(|started_at| started_at.elapsed());
+    }
+
+    pub fn stop(&self) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.try_send(VanitySearchMessage::Stop);
+        }
+    }
+
+    pub fn attempts(&self) -> u64 {
+        self.attempts.load(Ordering::Relaxed)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        match (self.finished_in, self.started_at) {
+            (Some(finished_in), _) => finished_in,
+            (None, Some(started_at)) => started_at.elapsed(),
+            (None, None) => Duration::ZERO,
+        }
+    }
+
+    /// Keys per second.
+    pub fn rate(&self) -> f64 {
+        let secs = self.elapsed().as_secs_f64();
+        if secs > 0.0 {
+            self.attempts() as f64 / secs
+        } else {
+            0.0
+        }
+    }
+
+    /// Seconds until the expected number of attempts is reached, `None`
+    /// until there is a rate. Zero once a search has been unlucky.
+    pub fn eta_secs(&self) -> Option<f64> {
+        let rate = self.rate();
+        if rate > 0.0 {
+            Some((self.expected_attempts - self.attempts() as f64).max(0.0) / rate)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn find_vanity_par(
+    pattern: VanityPattern,
+    source: VanitySource,
+    threads: u64,
+    attempts: Arc<AtomicU64>,
+    vanity_messages_receiver: Receiver<VanitySearchMessage>,
+    vanity_messages_sender: Sender<VanitySearchMessage>,
+) -> Option<VanityMatch> {
+    let handles = (0..threads)
+        .map(|_| {
+            std::thread::spawn({
+                let pattern = pattern.clone();
+                let attempts = attempts.clone();
+                let message_receiver = vanity_messages_receiver.clone();
+                let message_sender = vanity_messages_sender.clone();
+                move || {
+                    let mut unreported: u64 = 0;
+                    let found = loop {
+                        let (keypair, phrase) = match source.generate() {
+                            Ok(generated) => generated,
+                            Err(e) => {
+                                error!("Vanity search: {}", e);
+                                let _ = message_sender.try_send(VanitySearchMessage::Stop);
+                                break None;
+                            }
+                        };
+                        unreported += 1;
+
+                        if pattern.matches(&keypair.pubkey().to_string()) {
+                            // stop all other threads, one match is enough
+                            let _ = message_sender.try_send(VanitySearchMessage::Stop);
+                            break Some(VanityMatch { keypair, phrase });
+                        }
+
+                        if let Ok(message) = message_receiver.try_recv() {
+                            match message {
+                                VanitySearchMessage::Stop => {
+                                    // messages are only received by one receiver.
+                                    // try to send another message for any remaining receivers.
+                                    let _ = message_sender.try_send(VanitySearchMessage::Stop);
+                                    break None;
+                                }
+                            }
+                        }
+
+                        if unreported == ATTEMPTS_BATCH {
+                            attempts.fetch_add(unreported, Ordering::Relaxed);
+                            unreported = 0;
+                        }
+                    };
+                    attempts.fetch_add(unreported, Ordering::Relaxed);
+                    found
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut found = None;
+    for h in handles {
+        match h.join() {
+            Ok(Some(vanity_match)) => {
+                if found.is_none() {
+                    found = Some(vanity_match);
+                }
+            }
+            Ok(None) => {}
+            Err(_) => error!("Failed to join a thread handle!"),
+        }
+    }
+    found
+}
@@ -0,0 +1,714 @@
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+use bincode::Options;
+use cocoon::Cocoon;
+use serde::{Deserialize, Serialize};
+use hmac::Hmac;
+use pbkdf2::pbkdf2;
+use rand::RngCore;
+use sha2::Sha256;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{seed_phrase::SeedBackup, utils::get_unix_timestamp};
+
+pub const VAULT_PATH: &str = "save.data";
+pub const WALLET_NAME_MAX_LEN: usize = 24;
+pub const EXPORT_DIR: &str = "exports";
+
+/// Starts every file written since the format got a header. Files without
+/// it are bare cocoon containers keyed with the password itself.
+pub const VAULT_MAGIC: &[u8; 8] = b"OREVAULT";
+pub const BACKUP_MAGIC: &[u8; 8] = b"OREBACKP";
+pub const VAULT_FORMAT_VERSION: u16 = 1;
+pub const DEFAULT_KDF_ITERATIONS: u32 = 600_000;
+pub const MIN_KDF_ITERATIONS: u32 = 100_000;
+
+pub const UNLOCK_ATTEMPTS_EXTENSION: &str = "attempts";
+/// Failed unlocks before the delay starts.
+pub const UNLOCK_FREE_ATTEMPTS: u32 = 3;
+pub const UNLOCK_BASE_DELAY_SECS: u64 = 5;
+pub const UNLOCK_MAX_DELAY_SECS: u64 = 300;
+const HEADER_MAX_LEN: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VaultError {
+    /// Wrong password or the file isn't a cocoon container.
+    Decrypt,
+    Corrupted(String),
+    Io(String),
+}
+
+impl fmt::Display for VaultError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VaultError::Decrypt => write!(f, "Failed to decrypt wallet file"),
+            VaultError::Corrupted(e) => write!(f, "Wallet file is corrupted: {}", e),
+            VaultError::Io(e) => write!(f, "Wallet file io error: {}", e),
+        }
+    }
+}
+
+/// How the cocoon key is derived from the password.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Kdf {
+    Pbkdf2Sha256 { iterations: u32, salt: [u8; 16] },
+}
+
+impl Kdf {
+    pub fn pbkdf2(iterations: u32) -> Self {
+        let mut salt = [0; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        Kdf::Pbkdf2Sha256 {
+            iterations: iterations.max(MIN_KDF_ITERATIONS),
+            salt,
+        }
+    }
+
+    pub fn iterations(&self) -> u32 {
+        match self {
+            Kdf::Pbkdf2Sha256 { iterations, .. } => *iterations,
+        }
+    }
+
+    fn derive_key(&self, password: &str) -> Zeroizing<[u8; 32]> {
+        let mut key = Zeroizing::new([0; 32]);
+        match self {
+            Kdf::Pbkdf2Sha256 { iterations, salt } => {
+                pbkdf2::<Hmac<Sha256>>(password.as_bytes(), salt, *iterations, &mut key[..]);
+            },
+        }
+        key
+    }
+}
+
+/// A password stretched by `kdf`. Kept while the vault is unlocked so
+/// changes are written back without running the kdf again.
+pub struct VaultKey {
+    kdf: Kdf,
+    key: Zeroizing<[u8; 32]>,
+}
+
+impl VaultKey {
+    /// Runs the full kdf, keep it off the main thread.
+    pub fn derive(password: &str, kdf: Kdf) -> Self {
+        let key = kdf.derive_key(password);
+        Self { kdf, key }
+    }
+
+    pub fn kdf(&self) -> &Kdf {
+        &self.kdf
+    }
+}
+
+/// Unencrypted header in front of the cocoon container.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VaultHeader {
+    pub version: u16,
+    pub kdf: Kdf,
+    /// Active wallet when the file was written, shown while locked.
+    pub pubkey_hint: Option<String>,
+}
+
+impl VaultHeader {
+    /// `None` for files in the headerless format.
+    pub fn read(path: &Path) -> Result<Option<Self>, VaultError> {
+        let bytes = fs::read(path).map_err(|e| VaultError::Io(e.to_string()))?;
+        Ok(split_header(VAULT_MAGIC, &bytes)?.0)
+    }
+}
+
+/// Splits a file into its header and the cocoon container, files without
+/// `magic` are returned whole.
+fn split_header<'a>(
+    magic: &[u8; 8],
+    bytes: &'a [u8],
+) -> Result<(Option<VaultHeader>, &'a [u8]), VaultError> {
+    let rest = match bytes.strip_prefix(magic.as_slice()) {
+        Some(rest) => rest,
+        None => return Ok((None, bytes)),
+    };
+    if rest.len() < 4 {
+        return Err(VaultError::Corrupted("truncated header".to_string()));
+    }
+    let (len, rest) = rest.split_at(4);
+    let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+    if len > HEADER_MAX_LEN || len > rest.len() {
+        return Err(VaultError::Corrupted("truncated header".to_string()));
+    }
+    let (header, container) = rest.split_at(len);
+    let header: VaultHeader = bincode_options()
+        .deserialize(header)
+        .map_err(|e| VaultError::Corrupted(format!("unreadable header: {}", e)))?;
+    if header.version > VAULT_FORMAT_VERSION {
+        return Err(VaultError::Corrupted(format!(
+            "format version {} needs a newer app",
+            header.version
+        )));
+    }
+    Ok((Some(header), container))
+}
+
+/// `key` is the stretched password, or the password itself for headerless files.
+fn decrypt(mut container: &[u8], key: &[u8]) -> Result<Zeroizing<Vec<u8>>, VaultError> {
+    Cocoon::new(key)
+        .parse(&mut container)
+        .map(Zeroizing::new)
+        .map_err(|_| VaultError::Decrypt)
+}
+
+fn encrypt(
+    magic: &[u8; 8],
+    pubkey_hint: Option<String>,
+    mut bytes: Zeroizing<Vec<u8>>,
+    key: &VaultKey,
+) -> Result<Vec<u8>, String> {
+    let header = VaultHeader {
+        version: VAULT_FORMAT_VERSION,
+        kdf: key.kdf.clone(),
+        pubkey_hint,
+    };
+    let header_bytes = bincode_options().serialize(&header).unwrap();
+    let mut file_bytes = magic.to_vec();
+    file_bytes.extend_from_slice(&(header_bytes.len() as u32).to_le_bytes());
+    file_bytes.extend_from_slice(&header_bytes);
+    // the password is already stretched, cocoon's own kdf would only add time
+    let cocoon = Cocoon::new(&key.key[..]).with_weak_kdf();
+    // dump encrypts the buffer in place, no plaintext copy is left behind
+    cocoon
+        .dump(std::mem::take(&mut *bytes), &mut file_bytes)
+        .map_err(|e| format!("Failed to encrypt wallet file: {:?}", e))?;
+    Ok(file_bytes)
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VaultWallet {
+    pub name: String,
+    keypair: Vec<u8>,
+    /// Only wallets generated or restored from a seed phrase have one.
+    pub seed: Option<SeedBackup>,
+    /// Not stored, filled in from `keypair` when the vault is loaded.
+    #[serde(skip)]
+    pubkey: Pubkey,
+}
+
+impl Drop for VaultWallet {
+    fn drop(&mut self) {
+        self.keypair.zeroize();
+    }
+}
+
+impl VaultWallet {
+    fn new(name: String, keypair: &Keypair, seed: Option<SeedBackup>) -> Self {
+        Self {
+            name,
+            keypair: keypair.to_bytes().to_vec(),
+            seed,
+            pubkey: keypair.pubkey(),
+        }
+    }
+
+    pub fn keypair(&self) -> Keypair {
+        // checked when the vault is loaded or the wallet is added
+        Keypair::from_bytes(&self.keypair).unwrap()
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+}
+
+/// Named keypairs stored together in `save.data` under one password.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct WalletVault {
+    pub active: usize,
+    pub wallets: Vec<VaultWallet>,
+}
+
+impl WalletVault {
+    pub fn new(name: &str, keypair: &Keypair) -> Self {
+        Self {
+            active: 0,
+            wallets: vec![VaultWallet::new(name.to_string(), keypair, None)],
+        }
+    }
+
+    pub fn with_seed(mut self, seed: Option<SeedBackup>) -> Self {
+        self.wallets[0].seed = seed;
+        self
+    }
+
+    /// Decrypted contents of `save.data`. Files written before the vault
+    /// hold a single keypair, those open as a vault with one wallet.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VaultError> {
+        if bytes.len() == 64 {
+            return match Keypair::from_bytes(bytes) {
+                Ok(keypair) => Ok(Self::new("Wallet 1", &keypair)),
+                Err(e) => Err(VaultError::Corrupted(e.to_string())),
+            };
+        }
+
+        let mut vault = match bincode_options().deserialize::<WalletVault>(bytes) {
+            Ok(vault) => vault,
+            // vaults saved before seed phrases were stored
+            Err(e) => match bincode_options().deserialize::<VaultWithoutSeeds>(bytes) {
+                Ok(vault) => vault.into(),
+                Err(_) => return Err(VaultError::Corrupted(e.to_string())),
+            },
+        };
+        if vault.wallets.is_empty() || vault.active >= vault.wallets.len() {
+            return Err(VaultError::Corrupted("no active wallet".to_string()));
+        }
+        for wallet in &mut vault.wallets {
+            match Keypair::from_bytes(&wallet.keypair) {
+                Ok(keypair) => wallet.pubkey = keypair.pubkey(),
+                Err(e) => return Err(VaultError::Corrupted(format!("{}: {}", wallet.name, e))),
+            }
+        }
+        Ok(vault)
+    }
+
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(bincode_options().serialize(self).unwrap())
+    }
+
+    pub fn load(path: &Path, password: &str) -> Result<Self, VaultError> {
+        Self::load_with_key(path, password).map(|(vault, _)| vault)
+    }
+
+    /// Also returns the file's key, `None` when the file still needs upgrading.
+    pub fn load_with_key(
+        path: &Path,
+        password: &str,
+    ) -> Result<(Self, Option<VaultKey>), VaultError> {
+        let file_bytes = fs::read(path).map_err(|e| VaultError::Io(e.to_string()))?;
+        Self::decrypt_file(&file_bytes, password)
+    }
+
+    /// Opens the contents of a `save.data` file.
+    pub fn decrypt_file(
+        file_bytes: &[u8],
+        password: &str,
+    ) -> Result<(Self, Option<VaultKey>), VaultError> {
+        let (header, container) = split_header(VAULT_MAGIC, file_bytes)?;
+        match header {
+            Some(header) => {
+                let key = VaultKey::derive(password, header.kdf);
+                let bytes = decrypt(container, &key.key[..])?;
+                Ok((Self::from_bytes(&bytes)?, Some(key)))
+            },
+            None => {
+                let bytes = decrypt(container, password.as_bytes())?;
+                Ok((Self::from_bytes(&bytes)?, None))
+            },
+        }
+    }
+
+    pub fn save(&self, path: &Path, password: &str) -> Result<(), String> {
+        self.save_with_kdf(path, password, Kdf::pbkdf2(DEFAULT_KDF_ITERATIONS))
+    }
+
+    pub fn save_with_kdf(&self, path: &Path, password: &str, kdf: Kdf) -> Result<(), String> {
+        self.save_with_key(path, &VaultKey::derive(password, kdf))
+    }
+
+    pub fn save_with_key(&self, path: &Path, key: &VaultKey) -> Result<(), String> {
+        let pubkey_hint = Some(self.active_wallet().pubkey().to_string());
+        write_file_atomic(path, &encrypt(VAULT_MAGIC, pubkey_hint, self.to_bytes(), key)?)
+    }
+
+    pub fn active_wallet(&self) -> &VaultWallet {
+        &self.wallets[self.active]
+    }
+
+    pub fn default_name(&self) -> String {
+        format!("Wallet {}", self.wallets.len() + 1)
+    }
+
+    pub fn add(&mut self, name: &str, keypair: &Keypair, seed: Option<SeedBackup>) -> Result<usize, String> {
+        let name = validate_name(name)?;
+        if self.wallets.iter().any(|wallet| wallet.pubkey() == keypair.pubkey()) {
+            return Err("Wallet is already in the vault.".to_string());
+        }
+        self.wallets.push(VaultWallet::new(name, keypair, seed));
+        Ok(self.wallets.len() - 1)
+    }
+
+    pub fn rename(&mut self, index: usize, name: &str) -> Result<(), String> {
+        let name = validate_name(name)?;
+        let wallet = self.wallets.get_mut(index).ok_or("Wallet does not exist.".to_string())?;
+        wallet.name = name;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, index: usize) -> Result<VaultWallet, String> {
+        if index >= self.wallets.len() {
+            return Err("Wallet does not exist.".to_string());
+        }
+        if index == self.active {
+            return Err("Switch to another wallet before removing this one.".to_string());
+        }
+        let removed = self.wallets.remove(index);
+        if index < self.active {
+            self.active -= 1;
+        }
+        Ok(removed)
+    }
+
+    pub fn switch(&mut self, index: usize) -> Result<(), String> {
+        if index >= self.wallets.len() {
+            return Err("Wallet does not exist.".to_string());
+        }
+        self.active = index;
+        Ok(())
+    }
+}
+
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+    path.with_file_name(file_name)
+}
+
+/// Writes to a temp file next to `path` and renames it over the old file
+/// once synced, a crash leaves either the old or the new file.
+pub fn write_file_atomic(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    let tmp_path = tmp_path(path);
+    let result = File::create(&tmp_path)
+        .and_then(|mut file| {
+            file.write_all(bytes)?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&tmp_path, path));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(format!("Failed to write {}: {}", path.display(), e));
+    }
+    sync_parent_dir(path);
+    Ok(())
+}
+
+/// Copies the file at `path` to `<name>.bak-<unix ts>` next to it before it
+/// gets replaced by another vault. `None` when there is no file.
+pub fn copy_to_backup(path: &Path) -> Result<Option<PathBuf>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let stamp = get_unix_timestamp();
+    let backup_path = (0..)
+        .map(|n| {
+            let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+            match n {
+                0 => file_name.push(format!(".bak-{}", stamp)),
+                n => file_name.push(format!(".bak-{}-{}", stamp, n)),
+            }
+            path.with_file_name(file_name)
+        })
+        .find(|backup_path| !backup_path.exists())
+        .unwrap();
+    fs::copy(path, &backup_path)
+        .and_then(|_| File::open(&backup_path)?.sync_all())
+        .map_err(|e| format!("Failed to copy {} to {}: {}", path.display(), backup_path.display(), e))?;
+    sync_parent_dir(&backup_path);
+    Ok(Some(backup_path))
+}
+
+/// Makes the rename itself durable. Not possible on windows, where
+/// directories can't be opened as files.
+fn sync_parent_dir(path: &Path) {
+    if !cfg!(unix) {
+        return;
+    }
+    if let Some(dir) = path.parent() {
+        let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+        if let Err(e) = File::open(dir).and_then(|dir| dir.sync_all()) {
+            warn!("Failed to sync wallet file directory: {}", e);
+        }
+    }
+}
+
+/// Fixed int encoding like `bincode::serialize`, but strict about trailing
+/// bytes so an older layout can't be misread as the current one.
+fn bincode_options() -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .reject_trailing_bytes()
+}
+
+#[derive(Deserialize)]
+struct VaultWithoutSeeds {
+    active: usize,
+    wallets: Vec<VaultWalletWithoutSeed>,
+}
+
+#[derive(Deserialize)]
+struct VaultWalletWithoutSeed {
+    name: String,
+    keypair: Vec<u8>,
+}
+
+impl From<VaultWithoutSeeds> for WalletVault {
+    fn from(vault: VaultWithoutSeeds) -> Self {
+        Self {
+            active: vault.active,
+            wallets: vault
+                .wallets
+                .into_iter()
+                .map(|wallet| VaultWallet {
+                    name: wallet.name,
+                    keypair: wallet.keypair,
+                    seed: None,
+                    // set once the keypair is checked in `from_bytes`
+                    pubkey: Pubkey::default(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Wallet name can't be empty.".to_string());
+    }
+    if name.chars().count() > WALLET_NAME_MAX_LEN {
+        return Err(format!("Wallet name can be at most {} characters.", WALLET_NAME_MAX_LEN));
+    }
+    Ok(name.to_string())
+}
+
+/// `save.data` and `config.toml` in one file for moving the app to another
+/// machine, encrypted with the vault password.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BackupBundle {
+    pub vault_file: Vec<u8>,
+    pub config: Option<String>,
+}
+
+impl BackupBundle {
+    pub fn is_backup(path: &Path) -> bool {
+        let mut magic = [0; 8];
+        File::open(path)
+            .and_then(|mut file| file.read_exact(&mut magic))
+            .map(|_| &magic == BACKUP_MAGIC)
+            .unwrap_or(false)
+    }
+
+    pub fn save(&self, path: &Path, password: &str, kdf: Kdf) -> Result<(), String> {
+        let pubkey_hint = split_header(VAULT_MAGIC, &self.vault_file)
+            .ok()
+            .and_then(|(header, _)| header)
+            .and_then(|header| header.pubkey_hint);
+        let bytes = Zeroizing::new(bincode_options().serialize(self).unwrap());
+        let key = VaultKey::derive(password, kdf);
+        write_file_atomic(path, &encrypt(BACKUP_MAGIC, pubkey_hint, bytes, &key)?)
+    }
+
+    pub fn load(path: &Path, password: &str) -> Result<Self, VaultError> {
+        let file_bytes = fs::read(path).map_err(|e| VaultError::Io(e.to_string()))?;
+        let (header, container) = split_header(BACKUP_MAGIC, &file_bytes)?;
+        let header = header.ok_or_else(|| VaultError::Corrupted("not a backup file".to_string()))?;
+        let key = VaultKey::derive(password, header.kdf);
+        let bytes = decrypt(container, &key.key[..])?;
+        bincode_options()
+            .deserialize(&bytes)
+            .map_err(|e| VaultError::Corrupted(e.to_string()))
+    }
+
+    /// Writes the bundled vault to `vault_path` once it opens with `password`.
+    /// Also returns where the replaced file was copied, see `copy_to_backup`.
+    pub fn restore_vault(
+        &self,
+        vault_path: &Path,
+        password: &str,
+    ) -> Result<(WalletVault, Option<PathBuf>), VaultError> {
+        let (vault, _) = WalletVault::decrypt_file(&self.vault_file, password)?;
+        let replaced = copy_to_backup(vault_path).map_err(VaultError::Io)?;
+        write_file_atomic(vault_path, &self.vault_file).map_err(VaultError::Io)?;
+        Ok((vault, replaced))
+    }
+}
+
+/// Failed unlocks, saved next to the vault so a restart doesn't reset the
+/// delay before the next try.
+#[derive(Resource, Serialize, Deserialize, Default, Debug)]
+pub struct UnlockAttempts {
+    pub failed: u32,
+    pub last_failed_at: u64,
+    /// Shown on the unlock screen, errors other than a wrong password.
+    #[serde(skip)]
+    pub error: Option<String>,
+}
+
+impl UnlockAttempts {
+    /// A missing or unreadable file counts as no failed attempts.
+    pub fn load(path: &Path) -> Self {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(_) => return Self::default(),
+        };
+        toml::from_str(&text).unwrap_or_else(|e| {
+            error!("Failed to parse {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(self).map_err(|e| e.to_string())?;
+        write_file_atomic(path, text.as_bytes())
+    }
+
+    pub fn reset(&mut self, path: &Path) {
+        *self = Self::default();
+        if path.exists() {
+            if let Err(e) = fs::remove_file(path) {
+                error!("Failed to remove {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    pub fn record_failure(&mut self, now: u64) {
+        self.failed += 1;
+        self.last_failed_at = now;
+    }
+
+    /// Doubles with every failure past `UNLOCK_FREE_ATTEMPTS`.
+    pub fn delay_secs(&self) -> u64 {
+        if self.failed < UNLOCK_FREE_ATTEMPTS {
+            return 0;
+        }
+        let doublings = (self.failed - UNLOCK_FREE_ATTEMPTS).min(16);
+        (UNLOCK_BASE_DELAY_SECS << doublings).min(UNLOCK_MAX_DELAY_SECS)
+    }
+
+    /// Seconds until the next attempt is allowed, 0 once it is.
+    pub fn retry_in(&self, now: u64) -> u64 {
+        (self.last_failed_at + self.delay_secs()).saturating_sub(now)
+    }
+}
+
+/// The unlocked vault. The key derived from the password is kept so changes
+/// can be written back without running the kdf, both are cleared on lock.
+#[derive(Resource)]
+pub struct WalletVaultResource {
+    pub path: PathBuf,
+    pub vault: Option<WalletVault>,
+    /// Used whenever the vault is written, files with fewer are upgraded on unlock.
+    pub kdf_iterations: u32,
+    /// Set while a password change rewrites the file off the main thread,
+    /// other changes would be written under the old key.
+    pub password_change_pending: bool,
+    key: Option<VaultKey>,
+}
+
+impl Default for WalletVaultResource {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from(VAULT_PATH),
+            vault: None,
+            kdf_iterations: DEFAULT_KDF_ITERATIONS,
+            password_change_pending: false,
+            key: None,
+        }
+    }
+}
+
+impl WalletVaultResource {
+    /// Opens the vault and returns the active keypair.
+    pub fn unlock(&mut self, password: &str) -> Result<Keypair, VaultError> {
+        let (vault, key) = Self::load_and_upgrade(&self.path, password, self.kdf_iterations)?;
+        Ok(self.set_unlocked(vault, key))
+    }
+
+    /// The slow half of `unlock`, safe to run off the main thread. Files
+    /// without a header or with fewer than `kdf_iterations` are rewritten.
+    pub fn load_and_upgrade(
+        path: &Path,
+        password: &str,
+        kdf_iterations: u32,
+    ) -> Result<(WalletVault, VaultKey), VaultError> {
+        let (vault, key) = WalletVault::load_with_key(path, password)?;
+        let key = match key {
+            Some(key) if key.kdf().iterations() >= kdf_iterations => key,
+            _ => {
+                let key = VaultKey::derive(password, Kdf::pbkdf2(kdf_iterations));
+                // not fatal, the old file still opens next time
+                match vault.save_with_key(path, &key) {
+                    Ok(()) => info!("Upgraded wallet file to format version {}", VAULT_FORMAT_VERSION),
+                    Err(e) => warn!("Failed to upgrade wallet file: {}", e),
+                }
+                key
+            },
+        };
+        Ok((vault, key))
+    }
+
+    /// Keeps a vault opened by `load_and_upgrade` and returns the active keypair.
+    pub fn set_unlocked(&mut self, vault: WalletVault, key: VaultKey) -> Keypair {
+        let keypair = vault.active_wallet().keypair();
+        self.vault = Some(vault);
+        self.key = Some(key);
+        keypair
+    }
+
+    /// Failed unlocks are counted in a file next to the vault.
+    pub fn attempts_path(&self) -> PathBuf {
+        self.path.with_extension(UNLOCK_ATTEMPTS_EXTENSION)
+    }
+
+    /// `None` keeps `DEFAULT_KDF_ITERATIONS`, lower than `MIN_KDF_ITERATIONS` is raised.
+    pub fn with_kdf_iterations(mut self, iterations: Option<u32>) -> Self {
+        self.kdf_iterations = iterations.unwrap_or(DEFAULT_KDF_ITERATIONS).max(MIN_KDF_ITERATIONS);
+        self
+    }
+
+    /// Dropping the vault zeroizes every keypair it held.
+    pub fn lock(&mut self) {
+        self.vault = None;
+        self.key = None;
+    }
+
+    /// Checks `current` against the file on disk, then rewrites the vault
+    /// under `new`. Runs the kdf twice, keep it off the main thread.
+    pub fn change_password(
+        path: &Path,
+        current: &str,
+        new: &str,
+        kdf_iterations: u32,
+    ) -> Result<(WalletVault, VaultKey), String> {
+        let vault = match WalletVault::load(path, current) {
+            Ok(vault) => vault,
+            Err(VaultError::Decrypt) => return Err("Current password is incorrect.".to_string()),
+            Err(e) => return Err(e.to_string()),
+        };
+        let key = VaultKey::derive(new, Kdf::pbkdf2(kdf_iterations));
+        vault.save_with_key(path, &key)?;
+        Ok((vault, key))
+    }
+
+    /// Applies `change` to a copy of the vault and only keeps it once it has
+    /// been written to disk.
+    pub fn update<T>(
+        &mut self,
+        change: impl FnOnce(&mut WalletVault) -> Result<T, String>,
+    ) -> Result<T, String> {
+        if self.password_change_pending {
+            return Err("Wait for the password change to finish.".to_string());
+        }
+        let (vault, key) = match (&self.vault, &self.key) {
+            (Some(vault), Some(key)) => (vault, key),
+            _ => return Err("Wallet vault is locked.".to_string()),
+        };
+        let mut new_vault = vault.clone();
+        let result = change(&mut new_vault)?;
+        new_vault.save_with_key(&self.path, key)?;
+        self.vault = Some(new_vault);
+        Ok(result)
+    }
+}